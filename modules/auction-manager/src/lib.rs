@@ -24,12 +24,20 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!   - `surplus auction`: sell excessive surplus for getting native token to burn
+//!   - `debit auction`: sell native token for getting stable currency to eliminate the system's bad
+//!     debit by auction
+//!
+//! Collateral auctions can run in one of two modes, selected per collateral type:
+//!   - `English`: forward/reverse bidding, the auction ends after no better bid arrives in time.
+//!   - `Dutch`: the price starts above the oracle price and decays every block, any bidder can take
+//!     part of the lot instantly at the current price.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, transactional};
+use frame_support::{log, pallet_prelude::*, traits::StorageVersion, transactional};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
@@ -38,21 +46,25 @@ use orml_traits::{Auction, AuctionHandler, Change, MultiCurrency, OnNewBidResult
 use orml_utilities::OffchainErr;
 use primitives::{AuctionId, Balance, CurrencyId};
 use scale_info::TypeInfo;
+use sp_core::U256;
 use sp_runtime::{
 	offchain::{
 		storage::StorageValueRef,
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{CheckedDiv, Saturating, Zero},
+	traits::{CheckedDiv, One, Saturating, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
-use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, PriceProvider, Rate};
+use sp_std::{convert::TryInto, prelude::*};
+use support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, Price, PriceProvider, Rate, Ratio,
+};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -66,6 +78,21 @@ pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/auction-manager/max-it
 pub const LOCK_DURATION: u64 = 100;
 pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
 
+/// The mode of a collateral auction
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum CollateralAuctionMode {
+	/// Forward/reverse bidding, the highest bidder wins when the auction ends
+	English,
+	/// Descending price, any bidder can take part of the lot at the current price
+	Dutch,
+}
+
+impl Default for CollateralAuctionMode {
+	fn default() -> Self {
+		CollateralAuctionMode::English
+	}
+}
+
 /// Information of an collateral auction
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
@@ -88,6 +115,8 @@ pub struct CollateralAuctionItem<AccountId, BlockNumber> {
 	target: Balance,
 	/// Auction start time
 	start_time: BlockNumber,
+	/// Auction mode
+	mode: CollateralAuctionMode,
 }
 
 impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
@@ -124,6 +153,48 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of a debit auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct DebitAuctionItem<BlockNumber> {
+	/// Initial amount of native currency for sale
+	#[codec(compact)]
+	initial_amount: Balance,
+	/// Current amount of native currency for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Fix amount of debit value(stable currency) which want to get by this
+	/// auction
+	#[codec(compact)]
+	fix: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
+impl<BlockNumber> DebitAuctionItem<BlockNumber> {
+	/// Return amount for sale at specific last bid price and new bid price
+	fn amount_for_sale(&self, last_bid_price: Balance, new_bid_price: Balance) -> Balance {
+		if new_bid_price > last_bid_price && new_bid_price > self.fix {
+			Rate::checked_from_rational(sp_std::cmp::max(last_bid_price, self.fix), new_bid_price)
+				.and_then(|n| n.checked_mul_int(self.amount))
+				.unwrap_or(self.amount)
+		} else {
+			self.amount
+		}
+	}
+}
+
+/// Information of a surplus auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SurplusAuctionItem<BlockNumber> {
+	/// Fixed amount of surplus(stable currency) for sale
+	#[codec(compact)]
+	amount: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -180,6 +251,27 @@ pub mod module {
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The origin which may update the collateral auction mode
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The premium over the oracle price that a Dutch auction starts at
+		#[pallet::constant]
+		type DutchAuctionStartingPremium: Get<Rate>;
+
+		/// The decrease of the Dutch auction price ratio per block
+		#[pallet::constant]
+		type DutchAuctionPriceDecayPerBlock: Get<Rate>;
+
+		/// The lowest ratio of the oracle price that a Dutch auction can
+		/// decay to
+		#[pallet::constant]
+		type DutchAuctionMinimumPriceRatio: Get<Ratio>;
+
+		/// The native currency id, burned by surplus auctions and minted by
+		/// debit auctions
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -198,6 +290,12 @@ pub mod module {
 		InvalidBidPrice,
 		/// Invalid input amount
 		InvalidAmount,
+		/// The operation is not supported in the mode of the auction
+		InvalidAuctionMode,
+		/// The payment exceeds the max payment of the taker
+		ExceedMaxPayment,
+		/// Emergency shutdown has been triggered
+		AlreadyShutdown,
 	}
 
 	#[pallet::event]
@@ -214,6 +312,26 @@ pub mod module {
 		/// Dex take collateral auction. \[auction_id, collateral_type,
 		/// collateral_amount, turnover\]
 		DEXTakeCollateralAuction(AuctionId, CurrencyId, Balance, Balance),
+		/// The auction mode of collateral type updated. \[collateral_type,
+		/// new_mode\]
+		CollateralAuctionModeUpdated(CurrencyId, CollateralAuctionMode),
+		/// Part of Dutch collateral auction taken. \[auction_id,
+		/// collateral_type, collateral_amount, taker, payment_amount\]
+		CollateralAuctionTaken(AuctionId, CurrencyId, Balance, T::AccountId, Balance),
+		/// Dutch collateral auction finished. \[auction_id, collateral_type,
+		/// refund_collateral_amount\]
+		DutchAuctionFinished(AuctionId, CurrencyId, Balance),
+		/// Debit auction created. \[auction_id, initial_supply_amount,
+		/// fix_payment_amount\]
+		NewDebitAuction(AuctionId, Balance, Balance),
+		/// Surplus auction created. \[auction_id, fix_surplus_amount\]
+		NewSurplusAuction(AuctionId, Balance),
+		/// Debit auction dealt. \[auction_id, debit_currency_amount, winner,
+		/// payment_amount\]
+		DebitAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
+		/// Surplus auction dealt. \[auction_id, surplus_amount, winner,
+		/// payment_amount\]
+		SurplusAuctionDealt(AuctionId, Balance, T::AccountId, Balance),
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -239,7 +357,50 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to debit auction info
+	///
+	/// DebitAuctions: map AuctionId => Option<DebitAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auctions)]
+	pub type DebitAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, DebitAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from auction id to surplus auction info
+	///
+	/// SurplusAuctions: map AuctionId => Option<SurplusAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auctions)]
+	pub type SurplusAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, SurplusAuctionItem<T::BlockNumber>, OptionQuery>;
+
+	/// Record of the total fix amount of all active debit auctions
+	///
+	/// TotalDebitInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_debit_in_auction)]
+	pub type TotalDebitInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Record of total surplus amount of all active surplus auctions
+	///
+	/// TotalSurplusInAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_surplus_in_auction)]
+	pub type TotalSurplusInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Mapping from collateral type to the mode of its new collateral
+	/// auctions
+	///
+	/// CollateralAuctionModes: map CurrencyId => CollateralAuctionMode
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_auction_mode)]
+	pub type CollateralAuctionModes<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CollateralAuctionMode, ValueQuery>;
+
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -285,6 +446,48 @@ pub mod module {
 			Self::deposit_event(Event::CancelAuction(id));
 			Ok(())
 		}
+
+		/// Update the mode of collateral auctions created for a collateral
+		/// type. Running auctions keep their mode.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `mode`: the new collateral auction mode.
+		#[pallet::weight((T::WeightInfo::set_collateral_auction_mode(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_collateral_auction_mode(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			mode: CollateralAuctionMode,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			CollateralAuctionModes::<T>::insert(currency_id, mode);
+			Self::deposit_event(Event::CollateralAuctionModeUpdated(currency_id, mode));
+			Ok(())
+		}
+
+		/// Take part of the lot of a Dutch collateral auction at the
+		/// current auction price.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `id`: the Dutch collateral auction id.
+		/// - `amount`: the collateral amount to take, capped by the rest of the lot and the
+		///   remaining target.
+		/// - `max_payment`: the max stable currency amount to pay.
+		#[pallet::weight(T::WeightInfo::take_collateral_auction())]
+		#[transactional]
+		pub fn take_collateral_auction(
+			origin: OriginFor<T>,
+			id: AuctionId,
+			#[pallet::compact] amount: Balance,
+			#[pallet::compact] max_payment: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			Self::take_dutch_auction(&who, id, amount, max_payment)
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -303,7 +506,9 @@ pub mod module {
 							return InvalidTransaction::Stale.into();
 						}
 					}
-				} else {
+				} else if !DebitAuctions::<T>::contains_key(auction_id)
+					&& !SurplusAuctions::<T>::contains_key(auction_id)
+				{
 					return InvalidTransaction::Stale.into();
 				}
 
@@ -360,6 +565,17 @@ impl<T: Config> Pallet<T> {
 			max_iterations
 		);
 
+		// cancel all debit and surplus auctions, they are created by the CDP treasury
+		// and their count is capped by `MaxAuctionsCount` per block.
+		for debit_auction_id in <DebitAuctions<T>>::iter_keys() {
+			Self::submit_cancel_auction_tx(debit_auction_id);
+			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+		}
+		for surplus_auction_id in <SurplusAuctions<T>>::iter_keys() {
+			Self::submit_cancel_auction_tx(surplus_auction_id);
+			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+		}
+
 		// start iterations to cancel collateral auctions
 		let mut iterator = match start_key {
 			Some(key) => <CollateralAuctions<T>>::iter_from(key),
@@ -464,6 +680,36 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn cancel_debit_auction(id: AuctionId, debit_auction: DebitAuctionItem<T::BlockNumber>) -> DispatchResult {
+		if let Some((bidder, _)) = Self::get_last_bid(id) {
+			// refund stable token to the bidder
+			T::CDPTreasury::issue_debit(&bidder, debit_auction.fix, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// decrease total debit in auction
+		TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+
+		Ok(())
+	}
+
+	fn cancel_surplus_auction(id: AuctionId, surplus_auction: SurplusAuctionItem<T::BlockNumber>) -> DispatchResult {
+		if let Some((bidder, bid_price)) = Self::get_last_bid(id) {
+			// refund the burned native token to the bidder
+			T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, bid_price)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// decrease total surplus in auction
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+
+		Ok(())
+	}
+
 	/// Return `true` if price increment rate is greater than or equal to
 	/// minimum.
 	///
@@ -523,6 +769,10 @@ impl<T: Config> Pallet<T> {
 			id,
 			|collateral_auction| -> sp_std::result::Result<T::BlockNumber, DispatchError> {
 				let mut collateral_auction = collateral_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				ensure!(
+					collateral_auction.mode == CollateralAuctionMode::English,
+					Error::<T>::InvalidAuctionMode
+				);
 				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
 
 				// ensure new bid price is valid
@@ -583,6 +833,176 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Handles debit auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn debit_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		<DebitAuctions<T>>::try_mutate_exists(
+			id,
+			|debit_auction| -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+				let mut debit_auction = debit_auction.as_mut().ok_or(Error::<T>::AuctionNotExists)?;
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+				// ensure new bid price is valid, the bid must cover the fix payment
+				ensure!(
+					new_bid_price >= debit_auction.fix
+						&& Self::check_minimum_increment(
+							new_bid_price,
+							last_bid_price,
+							debit_auction.fix,
+							Self::get_minimum_increment_size(now, debit_auction.start_time),
+						),
+					Error::<T>::InvalidBidPrice
+				);
+
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					// there's bid before, transfer the fix payment from new bidder to last bidder
+					T::Currency::transfer(
+						T::GetStableCurrencyId::get(),
+						&new_bidder,
+						last_bidder,
+						debit_auction.fix,
+					)?;
+				} else {
+					// there's no bid before, transfer the fix payment to CDP treasury
+					T::CDPTreasury::deposit_surplus(&new_bidder, debit_auction.fix)?;
+				}
+
+				Self::swap_bidders(&new_bidder, last_bidder);
+
+				// the more the bid price, the less native currency for sale
+				debit_auction.amount = debit_auction.amount_for_sale(last_bid_price, new_bid_price);
+
+				Ok(now + Self::get_auction_time_to_close(now, debit_auction.start_time))
+			},
+		)
+	}
+
+	/// Handles surplus auction new bid. Returns `Ok(new_auction_end_time)`
+	/// if bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn surplus_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		let surplus_auction = Self::surplus_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+
+		// ensure new bid price is valid
+		ensure!(
+			Self::check_minimum_increment(
+				new_bid_price,
+				last_bid_price,
+				Zero::zero(),
+				Self::get_minimum_increment_size(now, surplus_auction.start_time),
+			),
+			Error::<T>::InvalidBidPrice
+		);
+
+		let native_currency_id = T::GetNativeCurrencyId::get();
+		let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+		let mut burn_native_amount = new_bid_price;
+
+		// if there's bid before, return native token from new bidder to last bidder
+		if let Some(last_bidder) = last_bidder {
+			T::Currency::transfer(native_currency_id, &new_bidder, last_bidder, last_bid_price)?;
+			burn_native_amount = burn_native_amount
+				.checked_sub(last_bid_price)
+				// This should never fail because new bid price are always greater than last bid price.
+				.ok_or(Error::<T>::InvalidBidPrice)?;
+		}
+
+		// burn remain native token from new bidder
+		T::Currency::withdraw(native_currency_id, &new_bidder, burn_native_amount)?;
+
+		Self::swap_bidders(&new_bidder, last_bidder);
+
+		Ok(now + Self::get_auction_time_to_close(now, surplus_auction.start_time))
+	}
+
+	fn debit_auction_end_handler(
+		auction_id: AuctionId,
+		debit_auction: DebitAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, _)) = winner {
+			// issue native token to winner, it shouldn't fail and affect the process.
+			// but even it failed, just the winner did not get the amount. it can be fixed
+			// by treasury council.
+			let res = T::Currency::deposit(T::GetNativeCurrencyId::get(), &bidder, debit_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"deposit: failed to issue native token {:?} to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					debit_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::DebitAuctionDealt(
+				auction_id,
+				debit_auction.amount,
+				bidder,
+				debit_auction.fix,
+			));
+		}
+
+		// the CDP treasury creates a new debit auction if the debit is still
+		// not covered.
+		TotalDebitInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(debit_auction.fix));
+	}
+
+	fn surplus_auction_end_handler(
+		auction_id: AuctionId,
+		surplus_auction: SurplusAuctionItem<T::BlockNumber>,
+		winner: Option<(T::AccountId, Balance)>,
+	) {
+		if let Some((bidder, bid_price)) = winner {
+			// transfer stable token to winner, it shouldn't fail and affect the process.
+			// but even it failed, just the winner did not get the amount. it can be fixed
+			// by treasury council.
+			let res = T::CDPTreasury::withdraw_surplus(&bidder, surplus_auction.amount);
+			if let Err(e) = res {
+				log::warn!(
+					target: "auction-manager",
+					"withdraw_surplus: failed to withdraw {:?} surplus from CDP treasury to {:?}: {:?}. \
+					This is unexpected but should be safe",
+					surplus_auction.amount, bidder, e
+				);
+				debug_assert!(false);
+			}
+
+			Self::deposit_event(Event::SurplusAuctionDealt(
+				auction_id,
+				surplus_auction.amount,
+				bidder,
+				bid_price,
+			));
+		}
+
+		TotalSurplusInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(surplus_auction.amount));
+	}
+
 	fn collateral_auction_end_handler(
 		auction_id: AuctionId,
 		collateral_auction: CollateralAuctionItem<T::AccountId, T::BlockNumber>,
@@ -717,6 +1137,120 @@ impl<T: Config> Pallet<T> {
 		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
 	}
 
+	/// Return the current price of collateral in stable currency for a Dutch
+	/// collateral auction.
+	///
+	/// Formula: oracle_price * max(1 + starting_premium - decay_per_block *
+	///     elapsed_blocks, minimum_price_ratio)
+	pub fn get_dutch_auction_price(
+		collateral_auction: &CollateralAuctionItem<T::AccountId, T::BlockNumber>,
+	) -> Option<Price> {
+		let elapsed: u128 = <frame_system::Pallet<T>>::block_number()
+			.saturating_sub(collateral_auction.start_time)
+			.unique_saturated_into();
+		let decay = T::DutchAuctionPriceDecayPerBlock::get().saturating_mul(Rate::saturating_from_integer(elapsed));
+		let price_ratio = Rate::one()
+			.saturating_add(T::DutchAuctionStartingPremium::get())
+			.saturating_sub(decay)
+			.max(T::DutchAuctionMinimumPriceRatio::get());
+		T::PriceSource::get_relative_price(collateral_auction.currency_id, T::GetStableCurrencyId::get())
+			.map(|oracle_price| oracle_price.saturating_mul(price_ratio))
+	}
+
+	// the payment of taking `amount` collateral at `price`, rounded up so that the taker never
+	// pays less than the collateral worth.
+	fn dutch_auction_payment(price: Price, amount: Balance) -> Option<Balance> {
+		let (quotient, remainder) = U256::from(price.into_inner())
+			.checked_mul(U256::from(amount))?
+			.div_mod(U256::from(Price::accuracy()));
+		let payment = if remainder.is_zero() {
+			quotient
+		} else {
+			quotient.checked_add(U256::one())?
+		};
+		TryInto::<Balance>::try_into(payment).ok()
+	}
+
+	fn take_dutch_auction(who: &T::AccountId, id: AuctionId, amount: Balance, max_payment: Balance) -> DispatchResult {
+		let mut collateral_auction = Self::collateral_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		ensure!(
+			collateral_auction.mode == CollateralAuctionMode::Dutch,
+			Error::<T>::InvalidAuctionMode
+		);
+		let price = Self::get_dutch_auction_price(&collateral_auction).ok_or(Error::<T>::InvalidFeedPrice)?;
+
+		let mut take_amount = sp_std::cmp::min(amount, collateral_auction.amount);
+		let mut payment = Self::dutch_auction_payment(price, take_amount).ok_or(Error::<T>::InvalidAmount)?;
+		ensure!(!payment.is_zero(), Error::<T>::InvalidAmount);
+
+		// the payment reaches the target, only pay the target and take the
+		// collateral worth it, rounded up.
+		let target_reached = !collateral_auction.always_forward() && payment >= collateral_auction.target;
+		if target_reached {
+			payment = collateral_auction.target;
+			take_amount = Ratio::checked_from_rational(payment, price.into_inner())
+				.map(|n| n.into_inner())
+				.map(|n| {
+					if price.saturating_mul_int(n) < payment {
+						n.saturating_add(1)
+					} else {
+						n
+					}
+				})
+				.map_or(take_amount, |n| sp_std::cmp::min(n, take_amount));
+		}
+		ensure!(payment <= max_payment, Error::<T>::ExceedMaxPayment);
+
+		T::CDPTreasury::deposit_surplus(who, payment)?;
+		T::CDPTreasury::withdraw_collateral(who, collateral_auction.currency_id, take_amount)?;
+
+		let target_reduction = sp_std::cmp::min(payment, collateral_auction.target);
+		TotalCollateralInAuction::<T>::mutate(collateral_auction.currency_id, |balance| {
+			*balance = balance.saturating_sub(take_amount)
+		});
+		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(target_reduction));
+		collateral_auction.amount = collateral_auction.amount.saturating_sub(take_amount);
+		collateral_auction.target = collateral_auction.target.saturating_sub(target_reduction);
+
+		Self::deposit_event(Event::CollateralAuctionTaken(
+			id,
+			collateral_auction.currency_id,
+			take_amount,
+			who.clone(),
+			payment,
+		));
+
+		if target_reached || collateral_auction.amount.is_zero() {
+			// refund remain collateral to refund recipient from CDP treasury
+			let refund_amount = collateral_auction.amount;
+			T::CDPTreasury::withdraw_collateral(
+				&collateral_auction.refund_recipient,
+				collateral_auction.currency_id,
+				refund_amount,
+			)?;
+			TotalCollateralInAuction::<T>::mutate(collateral_auction.currency_id, |balance| {
+				*balance = balance.saturating_sub(refund_amount)
+			});
+			TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
+
+			// decrease account ref of refund recipient
+			frame_system::Pallet::<T>::dec_consumers(&collateral_auction.refund_recipient);
+
+			<CollateralAuctions<T>>::remove(id);
+			T::Auction::remove_auction(id);
+
+			Self::deposit_event(Event::DutchAuctionFinished(
+				id,
+				collateral_auction.currency_id,
+				refund_amount,
+			));
+		} else {
+			<CollateralAuctions<T>>::insert(id, collateral_auction);
+		}
+
+		Ok(())
+	}
+
 	/// increment `new_bidder` reference and decrement `last_bidder`
 	/// reference if any
 	fn swap_bidders(new_bidder: &T::AccountId, last_bidder: Option<&T::AccountId>) {
@@ -745,7 +1279,15 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 		new_bid: (T::AccountId, Balance),
 		last_bid: Option<(T::AccountId, Balance)>,
 	) -> OnNewBidResult<T::BlockNumber> {
-		let bid_result = Self::collateral_auction_bid_handler(now, id, new_bid, last_bid);
+		let bid_result = if <CollateralAuctions<T>>::contains_key(id) {
+			Self::collateral_auction_bid_handler(now, id, new_bid, last_bid)
+		} else if <DebitAuctions<T>>::contains_key(id) {
+			Self::debit_auction_bid_handler(now, id, new_bid, last_bid)
+		} else if <SurplusAuctions<T>>::contains_key(id) {
+			Self::surplus_auction_bid_handler(now, id, new_bid, last_bid)
+		} else {
+			Err(Error::<T>::AuctionNotExists.into())
+		};
 
 		match bid_result {
 			Ok(new_auction_end_time) => OnNewBidResult {
@@ -762,6 +1304,10 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::debit_auction_end_handler(id, debit_auction, winner.clone());
+		} else if let Some(surplus_auction) = <SurplusAuctions<T>>::take(id) {
+			Self::surplus_auction_end_handler(id, surplus_auction, winner.clone());
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -797,9 +1343,14 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		}
 
 		let start_time = <frame_system::Pallet<T>>::block_number();
-		// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
-		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
-		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+		let mode = Self::collateral_auction_mode(currency_id);
+		let end_time = match mode {
+			// use start_time + AuctionDurationSoftCap as the initial end-time of collateral auction.
+			CollateralAuctionMode::English => Some(start_time.saturating_add(T::AuctionDurationSoftCap::get())),
+			// Dutch auction only finishes when the lot is taken or the target is reached.
+			CollateralAuctionMode::Dutch => None,
+		};
+		let auction_id = T::Auction::new_auction(start_time, end_time)?;
 
 		<CollateralAuctions<T>>::insert(
 			auction_id,
@@ -810,6 +1361,7 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 				amount,
 				target,
 				start_time,
+				mode,
 			},
 		);
 
@@ -830,9 +1382,58 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		Ok(())
 	}
 
+	fn new_debit_auction(amount: Self::Balance, fix: Self::Balance) -> DispatchResult {
+		ensure!(!amount.is_zero() && !fix.is_zero(), Error::<T>::InvalidAmount);
+		TotalDebitInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(fix).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<DebitAuctions<T>>::insert(
+			auction_id,
+			DebitAuctionItem {
+				initial_amount: amount,
+				amount,
+				fix,
+				start_time,
+			},
+		);
+
+		Self::deposit_event(Event::NewDebitAuction(auction_id, amount, fix));
+		Ok(())
+	}
+
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+		TotalSurplusInAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(amount).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<SurplusAuctions<T>>::insert(auction_id, SurplusAuctionItem { amount, start_time });
+
+		Self::deposit_event(Event::NewSurplusAuction(auction_id, amount));
+		Ok(())
+	}
+
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
-		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
-		Self::cancel_collateral_auction(id, collateral_auction)?;
+		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
+			Self::cancel_collateral_auction(id, collateral_auction)?;
+		} else if let Some(debit_auction) = <DebitAuctions<T>>::take(id) {
+			Self::cancel_debit_auction(id, debit_auction)?;
+		} else if let Some(surplus_auction) = <SurplusAuctions<T>>::take(id) {
+			Self::cancel_surplus_auction(id, surplus_auction)?;
+		} else {
+			return Err(Error::<T>::AuctionNotExists.into());
+		}
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
@@ -844,4 +1445,12 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	fn get_total_target_in_auction() -> Self::Balance {
 		Self::total_target_in_auction()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Self::total_debit_in_auction()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Self::total_surplus_in_auction()
+	}
 }
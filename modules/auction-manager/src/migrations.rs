@@ -0,0 +1,90 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CollateralAuctionItem, CollateralAuctionMode, CollateralAuctions, Config, Pallet, Weight};
+use codec::{Decode, Encode};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, StorageVersion},
+};
+use primitives::{Balance, CurrencyId};
+
+pub mod v1 {
+	use super::*;
+
+	/// `CollateralAuctionItem` before `mode` was added.
+	#[derive(Encode, Decode)]
+	struct OldCollateralAuctionItem<AccountId, BlockNumber> {
+		refund_recipient: AccountId,
+		currency_id: CurrencyId,
+		#[codec(compact)]
+		initial_amount: Balance,
+		#[codec(compact)]
+		amount: Balance,
+		#[codec(compact)]
+		target: Balance,
+		start_time: BlockNumber,
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"auction-manager already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"auction-manager storage version not set."
+		);
+		assert!(
+			CollateralAuctions::<T>::iter_values().all(|auction| auction.mode == CollateralAuctionMode::English),
+			"collateral auction mode has been set."
+		);
+		Ok(())
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "auction-manager", "Migrating auction-manager v1");
+
+		let mut translated: u64 = 0;
+		CollateralAuctions::<T>::translate::<OldCollateralAuctionItem<T::AccountId, T::BlockNumber>, _>(|_, old| {
+			translated += 1;
+			Some(CollateralAuctionItem {
+				refund_recipient: old.refund_recipient,
+				currency_id: old.currency_id,
+				initial_amount: old.initial_amount,
+				amount: old.amount,
+				target: old.target,
+				start_time: old.start_time,
+				mode: CollateralAuctionMode::English,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "auction-manager", "Completed auction-manager migration to v1");
+
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+}
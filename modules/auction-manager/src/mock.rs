@@ -45,6 +45,7 @@ pub type Amount = i64;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CAROL: AccountId = 3;
+pub const ACA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -130,6 +131,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -156,7 +158,11 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub DEXProtocolFeeShare: support::Ratio = Default::default();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10;
+	pub const MaxPriceObservations: u32 = 10;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 10;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(DOT, BTC).unwrap(),
@@ -168,12 +174,18 @@ impl module_dex::Config for Runtime {
 	type Event = Event;
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = TreasuryAccount;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 thread_local! {
@@ -196,6 +208,10 @@ parameter_types! {
 	pub const AuctionTimeToClose: u64 = 100;
 	pub const AuctionDurationSoftCap: u64 = 2000;
 	pub const UnsignedPriority: u64 = 1 << 20;
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(20, 100);
+	pub DutchAuctionPriceDecayPerBlock: Rate = Rate::saturating_from_rational(1, 100);
+	pub DutchAuctionMinimumPriceRatio: Ratio = Ratio::saturating_from_rational(50, 100);
+	pub const GetNativeCurrencyId: CurrencyId = ACA;
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
 		vec![DOT, AUSD],
@@ -216,6 +232,11 @@ impl Config for Runtime {
 	type UnsignedPriority = UnsignedPriority;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecayPerBlock = DutchAuctionPriceDecayPerBlock;
+	type DutchAuctionMinimumPriceRatio = DutchAuctionMinimumPriceRatio;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type WeightInfo = ();
 }
 
@@ -264,6 +285,9 @@ impl Default for ExtBuilder {
 				(ALICE, DOT, 1000),
 				(BOB, DOT, 1000),
 				(CAROL, DOT, 1000),
+				(ALICE, ACA, 1000),
+				(BOB, ACA, 1000),
+				(CAROL, ACA, 1000),
 			],
 		}
 	}
@@ -25,7 +25,7 @@ use frame_support::{assert_noop, assert_ok};
 use mock::{Call as MockCall, Event, *};
 use sp_core::offchain::{testing, DbExternalities, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt};
 use sp_io::offchain;
-use sp_runtime::traits::One;
+use sp_runtime::traits::{BadOrigin, One};
 
 fn run_to_block_offchain(n: u64) {
 	while System::block_number() < n {
@@ -594,3 +594,534 @@ fn offchain_default_max_iterator_works() {
 		assert_eq!(pool_state.write().transactions.len(), 1001);
 	});
 }
+
+#[test]
+fn set_collateral_auction_mode_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			AuctionManagerModule::collateral_auction_mode(BTC),
+			CollateralAuctionMode::English
+		);
+		assert_noop!(
+			AuctionManagerModule::set_collateral_auction_mode(Origin::signed(BOB), BTC, CollateralAuctionMode::Dutch),
+			BadOrigin,
+		);
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionModeUpdated(
+			BTC,
+			CollateralAuctionMode::Dutch,
+		)));
+		assert_eq!(
+			AuctionManagerModule::collateral_auction_mode(BTC),
+			CollateralAuctionMode::Dutch
+		);
+
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, DOT, 10, 100));
+		assert_eq!(
+			AuctionManagerModule::collateral_auctions(0).unwrap().mode,
+			CollateralAuctionMode::Dutch
+		);
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: None
+			})
+		);
+		assert_eq!(
+			AuctionManagerModule::collateral_auctions(1).unwrap().mode,
+			CollateralAuctionMode::English
+		);
+		assert_noop!(
+			AuctionManagerModule::collateral_auction_bid_handler(1, 0, (BOB, 10), None),
+			Error::<Runtime>::InvalidAuctionMode,
+		);
+	});
+}
+
+#[test]
+fn get_dutch_auction_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		let collateral_auction = AuctionManagerModule::collateral_auctions(0).unwrap();
+
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(&collateral_auction),
+			Some(Price::saturating_from_rational(120, 100))
+		);
+		System::set_block_number(11);
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(&collateral_auction),
+			Some(Price::saturating_from_rational(110, 100))
+		);
+		System::set_block_number(101);
+		assert_eq!(
+			AuctionManagerModule::get_dutch_auction_price(&collateral_auction),
+			Some(Price::saturating_from_rational(50, 100))
+		);
+
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(AuctionManagerModule::get_dutch_auction_price(&collateral_auction), None);
+	});
+}
+
+#[test]
+fn take_collateral_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		assert_noop!(
+			AuctionManagerModule::take_collateral_auction(Origin::signed(BOB), 0, 10, 100),
+			Error::<Runtime>::InvalidAuctionMode,
+		);
+		assert_noop!(
+			AuctionManagerModule::take_collateral_auction(Origin::signed(BOB), 2, 10, 100),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 90, 100));
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 100);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 200);
+		let alice_ref_count_0 = System::consumers(&ALICE);
+
+		// price is 1.1 at block 11
+		System::set_block_number(11);
+		assert_noop!(
+			AuctionManagerModule::take_collateral_auction(Origin::signed(BOB), 1, 50, 54),
+			Error::<Runtime>::ExceedMaxPayment,
+		);
+		assert_ok!(AuctionManagerModule::take_collateral_auction(
+			Origin::signed(BOB),
+			1,
+			50,
+			55
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionTaken(
+			1, BTC, 50, BOB, 55,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1050);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 945);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 55);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 50);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 50);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 145);
+		let collateral_auction = AuctionManagerModule::collateral_auctions(1).unwrap();
+		assert_eq!(collateral_auction.amount, 40);
+		assert_eq!(collateral_auction.target, 45);
+
+		// price is 1.0 at block 21, only take the collateral worth the rest target
+		System::set_block_number(21);
+		assert_ok!(AuctionManagerModule::take_collateral_auction(
+			Origin::signed(CAROL),
+			1,
+			40,
+			100
+		));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionTaken(
+			1, BTC, 40, CAROL, 40,
+		)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchAuctionFinished(
+			1, BTC, 0,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &CAROL), 940);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 960);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 95);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 10);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 100);
+		assert_eq!(AuctionManagerModule::collateral_auctions(1), None);
+		assert_eq!(AuctionModule::auction_info(1), None);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn take_collateral_auction_round_payment_up() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 200));
+
+		// price is 1.1 at block 11, 5 collateral worth 5.5
+		System::set_block_number(11);
+		assert_noop!(
+			AuctionManagerModule::take_collateral_auction(Origin::signed(BOB), 0, 5, 5),
+			Error::<Runtime>::ExceedMaxPayment,
+		);
+		assert_ok!(AuctionManagerModule::take_collateral_auction(
+			Origin::signed(BOB),
+			0,
+			5,
+			6
+		));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionTaken(
+			0, BTC, 5, BOB, 6,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1005);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 994);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 6);
+		let collateral_auction = AuctionManagerModule::collateral_auctions(0).unwrap();
+		assert_eq!(collateral_auction.amount, 95);
+		assert_eq!(collateral_auction.target, 194);
+	});
+}
+
+#[test]
+fn take_collateral_auction_reach_target_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 100));
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 100, 45));
+
+		// price is 1.0 at block 21
+		System::set_block_number(21);
+		assert_ok!(AuctionManagerModule::take_collateral_auction(
+			Origin::signed(BOB),
+			0,
+			100,
+			100
+		));
+		System::assert_has_event(Event::AuctionManagerModule(crate::Event::CollateralAuctionTaken(
+			0, BTC, 45, BOB, 45,
+		)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DutchAuctionFinished(
+			0, BTC, 55,
+		)));
+		assert_eq!(Tokens::free_balance(BTC, &BOB), 1045);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 955);
+		assert_eq!(Tokens::free_balance(BTC, &ALICE), 1055);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 45);
+		assert_eq!(CDPTreasuryModule::total_collaterals(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_collateral_in_auction(BTC), 0);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+	});
+}
+
+#[test]
+fn take_collateral_auction_after_shutdown_failed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::deposit_collateral(&CAROL, BTC, 10));
+		assert_ok!(AuctionManagerModule::set_collateral_auction_mode(
+			Origin::signed(ALICE),
+			BTC,
+			CollateralAuctionMode::Dutch
+		));
+		assert_ok!(AuctionManagerModule::new_collateral_auction(&ALICE, BTC, 10, 100));
+		mock_shutdown();
+		assert_noop!(
+			AuctionManagerModule::take_collateral_auction(Origin::signed(BOB), 0, 10, 100),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		assert_eq!(AuctionManagerModule::collateral_auctions(0), None);
+	});
+}
+
+#[test]
+fn debit_auction_methods() {
+	ExtBuilder::default().build().execute_with(|| {
+		let debit_auction = DebitAuctionItem {
+			initial_amount: 200,
+			amount: 200,
+			fix: 100,
+			start_time: 0,
+		};
+		assert_eq!(debit_auction.amount_for_sale(0, 100), 200);
+		assert_eq!(debit_auction.amount_for_sale(100, 200), 100);
+		assert_eq!(debit_auction.amount_for_sale(200, 250), 160);
+	});
+}
+
+#[test]
+fn new_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(0, 100),
+			Error::<Runtime>::InvalidAmount,
+		);
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(200, 0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewDebitAuction(0, 200, 100)));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(2001)
+			})
+		);
+
+		assert_noop!(
+			AuctionManagerModule::new_debit_auction(200, Balance::max_value()),
+			Error::<Runtime>::InvalidAmount,
+		);
+	});
+}
+
+#[test]
+fn new_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(0),
+			Error::<Runtime>::InvalidAmount,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewSurplusAuction(0, 100)));
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 100);
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(2001)
+			})
+		);
+
+		assert_noop!(
+			AuctionManagerModule::new_surplus_auction(Balance::max_value()),
+			Error::<Runtime>::InvalidAmount,
+		);
+	});
+}
+
+#[test]
+fn debit_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		assert_noop!(
+			AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 99), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_ok!(AuctionManagerModule::debit_auction_bid_handler(1, 0, (BOB, 100), None));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 200);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 + 1);
+
+		let carol_ref_count_0 = System::consumers(&CAROL);
+		assert_ok!(AuctionManagerModule::debit_auction_bid_handler(
+			2,
+			0,
+			(CAROL, 200),
+			Some((BOB, 100))
+		));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::debit_auctions(0).unwrap().amount, 100);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0);
+		assert_eq!(System::consumers(&CAROL), carol_ref_count_0 + 1);
+	});
+}
+
+#[test]
+fn surplus_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_eq!(Tokens::total_issuance(ACA), 3000);
+
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 0), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_ok!(AuctionManagerModule::surplus_auction_bid_handler(1, 0, (BOB, 50), None));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		assert_eq!(Tokens::total_issuance(ACA), 2950);
+
+		assert_noop!(
+			AuctionManagerModule::surplus_auction_bid_handler(2, 0, (CAROL, 51), Some((BOB, 50))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_ok!(AuctionManagerModule::surplus_auction_bid_handler(
+			2,
+			0,
+			(CAROL, 60),
+			Some((BOB, 50))
+		));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(ACA, &CAROL), 940);
+		assert_eq!(Tokens::total_issuance(ACA), 2940);
+	});
+}
+
+#[test]
+fn debit_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 200);
+
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 100));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 100)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::DebitAuctionDealt(
+			0, 200, BOB, 100,
+		)));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1200);
+		assert_eq!(AuctionManagerModule::debit_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 100);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+
+		// no bid, nothing is minted
+		AuctionManagerModule::on_auction_ended(1, None);
+		assert_eq!(Tokens::total_issuance(ACA), 3200);
+		assert_eq!(AuctionManagerModule::debit_auctions(1), None);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+	});
+}
+
+#[test]
+fn surplus_auction_end_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 100);
+
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 50));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 50)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::SurplusAuctionDealt(
+			0, 100, BOB, 50,
+		)));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(AuctionManagerModule::surplus_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_debit_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 100));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 0);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::debit_auctions(0), None);
+		assert_eq!(AuctionModule::auction_info(0), None);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn cancel_surplus_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPTreasuryModule::on_system_surplus(100));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 50));
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 950);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction(0)));
+
+		assert_eq!(Tokens::free_balance(ACA, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::surplus_auctions(0), None);
+		assert_eq!(AuctionModule::auction_info(0), None);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
+
+#[test]
+fn offchain_worker_cancels_surplus_and_debit_auctions_in_shutdown() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AuctionManagerModule::new_debit_auction(200, 100));
+		assert_ok!(AuctionManagerModule::new_surplus_auction(100));
+		mock_shutdown();
+
+		run_to_block_offchain(2);
+		let txs = std::mem::take(&mut pool_state.write().transactions);
+		assert_eq!(txs.len(), 2);
+		for tx in txs {
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			if let MockCall::AuctionManagerModule(crate::Call::cancel { id: auction_id }) = tx.call {
+				assert_ok!(AuctionManagerModule::cancel(Origin::none(), auction_id));
+			}
+		}
+
+		// auctions are canceled
+		assert!(AuctionManagerModule::debit_auctions(0).is_none());
+		assert!(AuctionManagerModule::surplus_auctions(1).is_none());
+		assert_eq!(AuctionManagerModule::total_debit_in_auction(), 0);
+		assert_eq!(AuctionManagerModule::total_surplus_in_auction(), 0);
+	});
+}
@@ -48,6 +48,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for module_auction_manager.
 pub trait WeightInfo {
 	fn cancel_collateral_auction() -> Weight;
+	fn set_collateral_auction_mode() -> Weight;
+	fn take_collateral_auction() -> Weight;
 }
 
 /// Weights for module_auction_manager using the Acala node and recommended hardware.
@@ -58,6 +60,15 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn set_collateral_auction_mode() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn take_collateral_auction() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -67,4 +78,13 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn set_collateral_auction_mode() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn take_collateral_auction() -> Weight {
+		(112_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
 }
@@ -0,0 +1,125 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for cdp engine module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::CurrencyId;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+use support::{Price, Ratio};
+
+/// The status of a CDP.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum PositionStatus {
+	Safe,
+	Unsafe,
+	/// The status cannot be checked, e.g. the price of collateral is not available.
+	ChecksFailed,
+}
+
+/// The health of a CDP.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct PositionHealth<AccountId, Balance> {
+	pub owner: AccountId,
+	pub currency_id: CurrencyId,
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub collateral: Balance,
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub debit: Balance,
+	/// The debit value in stable currency by the current debit exchange rate.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub debit_value: Balance,
+	/// The collateral ratio of this position, `None` if the price of collateral is not
+	/// available.
+	pub collateral_ratio: Option<Ratio>,
+	pub liquidation_ratio: Ratio,
+	/// The relative price of collateral to stable currency at which this position reaches
+	/// the liquidation ratio, `None` if this position has no collateral.
+	pub liquidation_price: Option<Price>,
+	/// The stability fee accrued since the last interest accumulation, it's not included in
+	/// `debit_value` yet.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub accrued_stability_fee: Balance,
+	/// Whether the owner uses cross-collateral position, if so `status` is the status of
+	/// all positions of the owner as a whole.
+	pub cross_collateral: bool,
+	pub status: PositionStatus,
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait CdpEngineApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		fn get_position_health(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<PositionHealth<AccountId, Balance>>;
+
+		fn get_account_positions_health(
+			account: AccountId,
+		) -> Vec<PositionHealth<AccountId, Balance>>;
+
+		fn get_collateral_positions_health(
+			currency_id: CurrencyId,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<PositionHealth<AccountId, Balance>>;
+
+		fn get_positions_nearest_to_liquidation(
+			currency_id: CurrencyId,
+			start: u32,
+			limit: u32,
+		) -> Vec<PositionHealth<AccountId, Balance>>;
+	}
+}
@@ -0,0 +1,188 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the cdp engine module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_cdp_engine_rpc_runtime_api::PositionHealth;
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::sync::Arc;
+
+pub use self::gen_client::Client as CdpEngineClient;
+pub use module_cdp_engine_rpc_runtime_api::CdpEngineApi as CdpEngineRuntimeApi;
+
+#[rpc]
+pub trait CdpEngineApi<BlockHash, AccountId, Balance> {
+	#[rpc(name = "cdpEngine_getPositionHealth")]
+	fn get_position_health(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<BlockHash>,
+	) -> Result<Option<PositionHealth<AccountId, Balance>>>;
+
+	#[rpc(name = "cdpEngine_getAccountPositionsHealth")]
+	fn get_account_positions_health(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>>;
+
+	#[rpc(name = "cdpEngine_getCollateralPositionsHealth")]
+	fn get_collateral_positions_health(
+		&self,
+		currency_id: CurrencyId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>>;
+
+	#[rpc(name = "cdpEngine_getPositionsNearestToLiquidation")]
+	fn get_positions_nearest_to_liquidation(
+		&self,
+		currency_id: CurrencyId,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>>;
+}
+
+/// A struct that implements the [`CdpEngineApi`].
+pub struct CdpEngine<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> CdpEngine<C, B> {
+	/// Create new `CdpEngine` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		CdpEngine {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, AccountId, Balance> CdpEngineApi<<Block as BlockT>::Hash, AccountId, Balance> for CdpEngine<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: CdpEngineRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr,
+{
+	fn get_position_health(
+		&self,
+		account: AccountId,
+		currency_id: CurrencyId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<PositionHealth<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_position_health(&at, account, currency_id)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get position health.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_account_positions_health(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_account_positions_health(&at, account).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get account positions health.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_collateral_positions_health(
+		&self,
+		currency_id: CurrencyId,
+		start: Option<AccountId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_collateral_positions_health(&at, currency_id, start, limit)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get collateral positions health.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_positions_nearest_to_liquidation(
+		&self,
+		currency_id: CurrencyId,
+		start: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<PositionHealth<AccountId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_positions_nearest_to_liquidation(&at, currency_id, start, limit)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get positions nearest to liquidation.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+}
@@ -23,20 +23,40 @@
 //! The core module of Honzon protocol. CDP engine is responsible for handle
 //! internal processes about CDPs, including liquidation, settlement and risk
 //! management.
+//!
+//! The positions of the account which uses cross-collateral position are
+//! managed as a whole: the collateral ratio is blended by the risk management
+//! params of each collateral type, and the collaterals are seized in the
+//! governance-defined priority order when liquidation occurs.
+//!
+//! The global interest rate can be adjusted automatically by the optional
+//! stability fee controller, which raises the rate when the market price of
+//! stable currency is below its peg and lowers the rate when it's above, within
+//! the governance-set bounds.
+//!
+//! Besides the hard cap of total debit value, the debit value issued for each
+//! collateral type can be limited per block and per epoch, and the debit value
+//! of the position of each account can be capped to contain oracle incidents.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{StorageVersion, UnixTime},
+	transactional,
+};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
 };
 use loans::Position;
+use module_cdp_engine_rpc_runtime_api::{PositionHealth, PositionStatus};
 use orml_traits::Change;
 use orml_utilities::OffchainErr;
-use primitives::{Amount, Balance, CurrencyId};
+use primitives::{evm::EvmAddress, Amount, Balance, CurrencyId};
 use rand_chacha::{
 	rand_core::{RngCore, SeedableRng},
 	ChaChaRng,
@@ -48,7 +68,7 @@ use sp_runtime::{
 		storage_lock::{StorageLock, Time},
 		Duration,
 	},
-	traits::{Bounded, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	traits::{Bounded, CheckedDiv, CheckedSub, Convert, One, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
 	},
@@ -56,15 +76,20 @@ use sp_runtime::{
 };
 use sp_std::prelude::*;
 use support::{
-	CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, ExchangeRate, Price, PriceProvider, Rate, Ratio, RiskManager,
+	AddressMapping, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, ExchangeRate, InvokeContext,
+	LiquidationEvmBridge, LiquidationStrategy as LiquidationStrategyTrait, Price, PriceProvider, Rate, Ratio,
+	RiskManager, LIQUIDATION_CONTRACT_GAS_LIMIT,
 };
 
 mod debit_exchange_rate_convertor;
+mod liquidation;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
 
 pub use debit_exchange_rate_convertor::DebitExchangeRateConvertor;
+pub use liquidation::{LiquidateViaAuction, LiquidateViaContracts, LiquidateViaDex};
 pub use module::*;
 pub use weights::WeightInfo;
 
@@ -101,6 +126,87 @@ pub struct RiskManagementParams {
 	/// of CDP so that the current collateral ratio is lower than the
 	/// required collateral ratio. `None` value means not set
 	pub required_collateral_ratio: Option<Ratio>,
+
+	/// Partial liquidation target ratio, if it's set, the liquidation of
+	/// unsafe CDP under this collateral type only closes enough debit to
+	/// restore its collateral ratio to the target ratio, and leaves the
+	/// remaining position to the owner. `None` value means to liquidate the
+	/// entire CDP
+	pub partial_liquidation_target_ratio: Option<Ratio>,
+}
+
+/// Params of the stability fee controller, which adjusts the global interest
+/// rate per sec by the deviation of the stable currency price from its peg.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StabilityFeeControllerParams {
+	/// The market price of stable currency is its TWAP in the reference
+	/// currency on DEX multiplied by the price of the reference currency.
+	pub reference_currency_id: CurrencyId,
+
+	/// The window in blocks of the TWAP on DEX.
+	pub twap_window: u32,
+
+	/// The deviation from the peg within which the rate will not be adjusted.
+	pub tolerance: Ratio,
+
+	/// The rate adjustment per unit of deviation.
+	pub sensitivity: Rate,
+
+	/// The maximum rate adjustment in one epoch.
+	pub max_adjustment: Rate,
+
+	/// The lower bound of global interest rate per sec.
+	pub min_rate: Rate,
+
+	/// The upper bound of global interest rate per sec.
+	pub max_rate: Rate,
+}
+
+/// Params of the reward paid to the keeper who triggers the liquidation of
+/// unsafe CDP by signed transaction.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct KeeperRewardParams {
+	/// The fixed reward in stable currency for each liquidation.
+	pub fixed: Balance,
+
+	/// The reward rate of the liquidated debit value.
+	pub percentage: Rate,
+
+	/// The maximum share of the collected liquidation penalty paid as reward,
+	/// it must be less than 100% so that liquidating own CDP by another
+	/// account is never profitable.
+	pub max_penalty_share: Ratio,
+}
+
+/// The limits of the debit value issued by increasing the debit of CDPs, `None`
+/// means no limit.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo)]
+pub struct IssuanceLimits {
+	/// The maximum debit value issued in a block.
+	pub per_block: Option<Balance>,
+
+	/// The maximum debit value issued in an epoch of `IssuanceEpochPeriod`
+	/// blocks.
+	pub per_epoch: Option<Balance>,
+
+	/// The maximum debit value of the position of an account.
+	pub per_account: Option<Balance>,
+}
+
+/// The debit value issued in the latest block and epoch.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, TypeInfo)]
+pub struct IssuanceRecord<BlockNumber> {
+	/// The latest block in which debit is issued.
+	pub block: BlockNumber,
+
+	/// The debit value issued in `block`.
+	pub block_issued: Balance,
+
+	/// The index of the latest epoch in which debit is issued.
+	pub epoch: BlockNumber,
+
+	/// The debit value issued in `epoch`.
+	pub epoch_issued: Balance,
 }
 
 // typedef to help polkadot.js disambiguate Change with different generic
@@ -108,6 +214,7 @@ pub struct RiskManagementParams {
 type ChangeOptionRate = Change<Option<Rate>>;
 type ChangeOptionRatio = Change<Option<Ratio>>;
 type ChangeBalance = Change<Balance>;
+type ChangeOptionBalance = Change<Option<Balance>>;
 
 /// Liquidation strategy available
 #[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
@@ -116,6 +223,8 @@ pub enum LiquidationStrategy {
 	Auction,
 	/// Liquidation CDP's collateral by swap with DEX
 	Exchange,
+	/// Liquidation CDP's collateral by the liquidation contracts in EVM
+	Contract,
 }
 
 /// Status of CDP
@@ -172,6 +281,19 @@ pub mod module {
 		/// The price source of all types of currencies related to CDP
 		type PriceSource: PriceProvider<CurrencyId>;
 
+		/// DEX to get the TWAP of stable currency
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// The period in blocks the stability fee controller adjusts the global
+		/// interest rate per sec.
+		#[pallet::constant]
+		type StabilityFeeAdjustmentPeriod: Get<Self::BlockNumber>;
+
+		/// The period in blocks of the epoch in which the debit value issued is
+		/// limited by `IssuanceLimits::per_epoch`.
+		#[pallet::constant]
+		type IssuanceEpochPeriod: Get<Self::BlockNumber>;
+
 		/// A configuration for base priority of unsigned transactions.
 		///
 		/// This is exposed so that it can be tuned for particular runtime, when
@@ -194,6 +316,24 @@ pub mod module {
 		#[pallet::constant]
 		type DefaultSwapParitalPathList: Get<Vec<Vec<CurrencyId>>>;
 
+		/// The liquidation contracts in EVM
+		type LiquidationEvmBridge: LiquidationEvmBridge<Balance>;
+
+		/// Mapping between substrate account and EVM address
+		type EvmAddressMapping: AddressMapping<Self::AccountId>;
+
+		/// The dedicated account to call the liquidation contracts, it receives
+		/// the repayment and pays the storage of the contracts.
+		#[pallet::constant]
+		type LiquidationContractsAccount: Get<Self::AccountId>;
+
+		/// Convert the gas of calling the liquidation contracts to weight.
+		type GasToWeight: Convert<u64, Weight>;
+
+		/// The maximum number of registered liquidation contracts
+		#[pallet::constant]
+		type MaxLiquidationContracts: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -225,6 +365,34 @@ pub mod module {
 		MustAfterShutdown,
 		/// Failed to swap debit by default path list
 		SwapDebitFailed,
+		/// The collateral type is duplicated
+		DuplicatedCollateralType,
+		/// The params of stability fee controller are invalid
+		InvalidStabilityFeeControllerParams,
+		/// The liquidation strategy is duplicated
+		DuplicatedLiquidationStrategy,
+		/// The liquidation contract has already been registered
+		LiquidationContractAlreadyRegistered,
+		/// The liquidation contract is not registered
+		LiquidationContractNotRegistered,
+		/// The number of liquidation contracts exceeds the limit
+		TooManyLiquidationContracts,
+		/// All liquidation strategies failed to liquidate the collateral
+		LiquidationFailed,
+		/// The liquidation strategies must end with `Auction` as the last resort
+		AuctionNotLastResort,
+		/// The keeper reward is not set, liquidation by keeper is disabled
+		KeeperRewardDisabled,
+		/// The keeper cannot liquidate its own CDP
+		CannotLiquidateSelf,
+		/// The params of keeper reward are invalid
+		InvalidKeeperRewardParams,
+		/// The debit value issued in this block exceeds the limit
+		ExceedBlockIssuanceLimit,
+		/// The debit value issued in this epoch exceeds the limit
+		ExceedEpochIssuanceLimit,
+		/// The debit value of the position exceeds the ceiling per account
+		ExceedAccountDebitCeiling,
 	}
 
 	#[pallet::event]
@@ -257,6 +425,49 @@ pub mod module {
 		/// The global interest rate per sec for all types of collateral
 		/// updated. \[new_global_interest_rate_per_sec\]
 		GlobalInterestRatePerSecUpdated(Rate),
+		/// Liquidate the collateral of unsafe cross-collateral CDP.
+		/// \[owner, collateral_type, collateral_amount, target_stable_amount,
+		/// liquidation_strategy\]
+		LiquidateCrossCollateralCDP(T::AccountId, CurrencyId, Balance, Balance, LiquidationStrategy),
+		/// The liquidation priority of collateral types for cross-collateral
+		/// CDP updated. \[new_priority\]
+		CrossCollateralLiquidationPriorityUpdated(Vec<CurrencyId>),
+		/// The stability fee controller updated. \[new_params\]
+		StabilityFeeControllerUpdated(Option<StabilityFeeControllerParams>),
+		/// The global interest rate per sec adjusted by the stability fee
+		/// controller. \[stable_currency_market_price,
+		/// old_global_interest_rate_per_sec, new_global_interest_rate_per_sec\]
+		GlobalInterestRatePerSecAdjusted(Price, Rate, Rate),
+		/// The partial liquidation target ratio for specific collateral type
+		/// updated. \[collateral_type, new_partial_liquidation_target_ratio\]
+		PartialLiquidationTargetRatioUpdated(CurrencyId, Option<Ratio>),
+		/// The liquidation strategies for specific collateral type updated.
+		/// \[collateral_type, new_liquidation_strategies\]
+		LiquidationStrategiesUpdated(CurrencyId, Vec<LiquidationStrategy>),
+		/// The liquidation contract registered. \[contract\]
+		LiquidationContractRegistered(EvmAddress),
+		/// The liquidation contract deregistered. \[contract\]
+		LiquidationContractDeregistered(EvmAddress),
+		/// Collateral liquidated by the liquidation contract.
+		/// \[collateral_type, contract, collateral_amount, repayment\]
+		LiquidateByContract(CurrencyId, EvmAddress, Balance, Balance),
+		/// The params of keeper reward updated. \[new_params\]
+		KeeperRewardParamsUpdated(Option<KeeperRewardParams>),
+		/// The keeper is rewarded for liquidating the unsafe CDP.
+		/// \[keeper, owner, reward\]
+		KeeperRewarded(T::AccountId, T::AccountId, Balance),
+		/// The debit issuance limits for specific collateral type updated.
+		/// \[collateral_type, new_limits\]
+		DebitIssuanceLimitsUpdated(CurrencyId, IssuanceLimits),
+		/// The debit value issued in this block reached the limit.
+		/// \[collateral_type\]
+		BlockIssuanceLimitReached(CurrencyId),
+		/// The debit value issued in this epoch reached the limit.
+		/// \[collateral_type\]
+		EpochIssuanceLimitReached(CurrencyId),
+		/// The debit value of the position reached the ceiling per account.
+		/// \[collateral_type, owner\]
+		AccountDebitCeilingReached(CurrencyId, T::AccountId),
 	}
 
 	/// Mapping from collateral type to its exchange rate of debit units and
@@ -288,6 +499,66 @@ pub mod module {
 	#[pallet::getter(fn last_accumulation_secs)]
 	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// The collateral types in the order of priority to be seized when
+	/// liquidate the cross-collateral CDP
+	///
+	/// CrossCollateralLiquidationPriority: Vec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn cross_collateral_liquidation_priority)]
+	pub type CrossCollateralLiquidationPriority<T: Config> = StorageValue<_, Vec<CurrencyId>, ValueQuery>;
+
+	/// The params of stability fee controller, `None` means the controller is
+	/// disabled.
+	///
+	/// StabilityFeeController: Option<StabilityFeeControllerParams>
+	#[pallet::storage]
+	#[pallet::getter(fn stability_fee_controller)]
+	pub type StabilityFeeController<T: Config> = StorageValue<_, StabilityFeeControllerParams, OptionQuery>;
+
+	/// Mapping from collateral type to the liquidation strategies tried in
+	/// order when liquidate its collateral, empty means the default strategies
+	/// `[Exchange, Auction]`.
+	///
+	/// LiquidationStrategies: CurrencyId => Vec<LiquidationStrategy>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_strategies)]
+	pub type LiquidationStrategies<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Vec<LiquidationStrategy>, ValueQuery>;
+
+	/// The registered liquidation contracts in EVM, they are asked to
+	/// liquidate collateral in order.
+	///
+	/// LiquidationContracts: Vec<EvmAddress>
+	#[pallet::storage]
+	#[pallet::getter(fn liquidation_contracts)]
+	pub type LiquidationContracts<T: Config> = StorageValue<_, Vec<EvmAddress>, ValueQuery>;
+
+	/// The params of the reward paid to keepers, `None` means liquidation by
+	/// keeper is disabled.
+	///
+	/// KeeperReward: Option<KeeperRewardParams>
+	#[pallet::storage]
+	#[pallet::getter(fn keeper_reward)]
+	pub type KeeperReward<T: Config> = StorageValue<_, KeeperRewardParams, OptionQuery>;
+
+	/// Mapping from collateral type to the limits of debit value issued by
+	/// increasing the debit of its CDPs
+	///
+	/// DebitIssuanceLimits: CurrencyId => IssuanceLimits
+	#[pallet::storage]
+	#[pallet::getter(fn debit_issuance_limits)]
+	pub type DebitIssuanceLimits<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, IssuanceLimits, ValueQuery>;
+
+	/// Mapping from collateral type to the debit value issued in the latest
+	/// block and epoch, only recorded when the per block or per epoch limit is
+	/// set.
+	///
+	/// DebitIssuance: CurrencyId => IssuanceRecord
+	#[pallet::storage]
+	#[pallet::getter(fn debit_issuance)]
+	pub type DebitIssuance<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, IssuanceRecord<T::BlockNumber>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		#[allow(clippy::type_complexity)]
@@ -332,6 +603,7 @@ pub mod module {
 							liquidation_ratio: *liquidation_ratio,
 							liquidation_penalty: *liquidation_penalty,
 							required_collateral_ratio: *required_collateral_ratio,
+							partial_liquidation_target_ratio: None,
 						},
 					);
 				},
@@ -340,7 +612,11 @@ pub mod module {
 		}
 	}
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -356,10 +632,20 @@ pub mod module {
 			} else {
 				Default::default()
 			};
-			<T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
+			let mut weight = <T as Config>::WeightInfo::on_initialize(Self::accumulate_interest(
 				now_as_secs,
 				Self::last_accumulation_secs(),
-			))
+			));
+
+			// adjust the global interest rate after the interest of last interval accumulated
+			let period = T::StabilityFeeAdjustmentPeriod::get();
+			if !period.is_zero() && (now % period).is_zero() {
+				if let Some(params) = Self::stability_fee_controller() {
+					Self::adjust_global_interest_rate(&params);
+					weight = weight.saturating_add(<T as Config>::WeightInfo::adjust_global_interest_rate());
+				}
+			}
+			weight
 		}
 
 		/// Runs after every block. Start offchain worker to check CDP and
@@ -394,20 +680,76 @@ pub mod module {
 		///
 		/// The dispatch origin of this call must be _None_.
 		///
-		/// - `currency_id`: CDP's collateral type.
+		/// - `currency_id`: CDP's collateral type, it's ignored if `who` uses cross-collateral
+		///   position, all positions of `who` will be liquidated as a whole.
 		/// - `who`: CDP's owner.
-		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_dex())]
+		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_dex()
+			.saturating_add(Pallet::<T>::liquidation_contracts_weight())
+			.saturating_mul(T::CollateralCurrencyIds::get().len().max(1) as Weight))]
 		#[transactional]
 		pub fn liquidate(
 			origin: OriginFor<T>,
 			currency_id: CurrencyId,
 			who: <T::Lookup as StaticLookup>::Source,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
 			let who = T::Lookup::lookup(who)?;
 			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
-			Self::liquidate_unsafe_cdp(who, currency_id)?;
-			Ok(())
+			let (liquidated_count, _, _) = Self::do_liquidate(who, currency_id)?;
+			let actual_weight = <T as Config>::WeightInfo::liquidate_by_dex()
+				.saturating_add(Self::liquidation_contracts_weight())
+				.saturating_mul(liquidated_count.max(1) as Weight);
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Liquidate unsafe CDP by keeper, the keeper will be rewarded with
+		/// stable currency from the liquidation penalty collected by this
+		/// liquidation, no reward for the collateral sold by auctions.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `currency_id`: CDP's collateral type, it's ignored if `who` uses cross-collateral
+		///   position, all positions of `who` will be liquidated as a whole.
+		/// - `who`: CDP's owner.
+		#[pallet::weight(<T as Config>::WeightInfo::liquidate_by_keeper()
+			.saturating_add(Pallet::<T>::liquidation_contracts_weight())
+			.saturating_mul(T::CollateralCurrencyIds::get().len().max(1) as Weight))]
+		#[transactional]
+		pub fn liquidate_by_keeper(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			who: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let keeper = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			let params = Self::keeper_reward().ok_or(Error::<T>::KeeperRewardDisabled)?;
+			ensure!(keeper != who, Error::<T>::CannotLiquidateSelf);
+
+			let surplus_before = <T as Config>::CDPTreasury::get_surplus_pool();
+			let (liquidated_count, liquidated_debit_value, penalty) = Self::do_liquidate(who.clone(), currency_id)?;
+
+			// the reward is paid from the liquidation penalty collected to cdp treasury by this
+			// liquidation, the collateral in auction has not been sold so its penalty is not
+			// collected yet.
+			let collected_penalty = <T as Config>::CDPTreasury::get_surplus_pool()
+				.saturating_sub(surplus_before)
+				.saturating_sub(liquidated_debit_value)
+				.min(penalty);
+			let reward = params
+				.percentage
+				.saturating_mul_int(liquidated_debit_value)
+				.saturating_add(params.fixed)
+				.min(params.max_penalty_share.saturating_mul_int(collected_penalty));
+			if !reward.is_zero() {
+				<T as Config>::CDPTreasury::withdraw_surplus(&keeper, reward)?;
+			}
+
+			Self::deposit_event(Event::KeeperRewarded(keeper, who, reward));
+			let actual_weight = <T as Config>::WeightInfo::liquidate_by_keeper()
+				.saturating_add(Self::liquidation_contracts_weight())
+				.saturating_mul(liquidated_count.max(1) as Weight);
+			Ok(Some(actual_weight).into())
 		}
 
 		/// Settle CDP has debit after system shutdown
@@ -459,6 +801,8 @@ pub mod module {
 		/// - `required_collateral_ratio`: required collateral ratio, `None` means do not update,
 		///   `Some(None)` means update it to `None`.
 		/// - `maximum_total_debit_value`: maximum total debit value.
+		/// - `partial_liquidation_target_ratio`: partial liquidation target ratio, `None` means do
+		///   not update, `Some(None)` means update it to `None`.
 		#[pallet::weight((<T as Config>::WeightInfo::set_collateral_params(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn set_collateral_params(
@@ -469,6 +813,7 @@ pub mod module {
 			liquidation_penalty: ChangeOptionRate,
 			required_collateral_ratio: ChangeOptionRatio,
 			maximum_total_debit_value: ChangeBalance,
+			partial_liquidation_target_ratio: ChangeOptionRatio,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 			ensure!(
@@ -497,9 +842,213 @@ pub mod module {
 				collateral_params.maximum_total_debit_value = val;
 				Self::deposit_event(Event::MaximumTotalDebitValueUpdated(currency_id, val));
 			}
+			if let Change::NewValue(update) = partial_liquidation_target_ratio {
+				collateral_params.partial_liquidation_target_ratio = update;
+				Self::deposit_event(Event::PartialLiquidationTargetRatioUpdated(currency_id, update));
+			}
 			CollateralParams::<T>::insert(currency_id, collateral_params);
 			Ok(())
 		}
+
+		/// Update the priority order of collateral types to be seized when
+		/// liquidate the cross-collateral CDP
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `priority`: collateral types in priority order, the collateral types not in the list
+		///   will be seized after them, in the order of `CollateralCurrencyIds`.
+		#[pallet::weight((<T as Config>::WeightInfo::set_cross_collateral_liquidation_priority(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_cross_collateral_liquidation_priority(
+			origin: OriginFor<T>,
+			priority: Vec<CurrencyId>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let collateral_currency_ids = T::CollateralCurrencyIds::get();
+			for (index, currency_id) in priority.iter().enumerate() {
+				ensure!(
+					collateral_currency_ids.contains(currency_id),
+					Error::<T>::InvalidCollateralType,
+				);
+				ensure!(
+					!priority[..index].contains(currency_id),
+					Error::<T>::DuplicatedCollateralType
+				);
+			}
+
+			CrossCollateralLiquidationPriority::<T>::put(&priority);
+			Self::deposit_event(Event::CrossCollateralLiquidationPriorityUpdated(priority));
+			Ok(())
+		}
+
+		/// Update the params of stability fee controller
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the params of stability fee controller, `None` means to disable the
+		///   controller.
+		#[pallet::weight((<T as Config>::WeightInfo::set_stability_fee_controller(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_stability_fee_controller(
+			origin: OriginFor<T>,
+			params: Option<StabilityFeeControllerParams>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(params) = &params {
+				ensure!(
+					params.reference_currency_id != T::GetStableCurrencyId::get()
+						&& !params.twap_window.is_zero()
+						&& params.min_rate <= params.max_rate,
+					Error::<T>::InvalidStabilityFeeControllerParams
+				);
+			}
+
+			StabilityFeeController::<T>::set(params.clone());
+			Self::deposit_event(Event::StabilityFeeControllerUpdated(params));
+			Ok(())
+		}
+
+		/// Update the params of keeper reward
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `params`: the params of keeper reward, `None` means to disable liquidation by keeper.
+		#[pallet::weight((<T as Config>::WeightInfo::set_keeper_reward_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_keeper_reward_params(origin: OriginFor<T>, params: Option<KeeperRewardParams>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(params) = &params {
+				ensure!(
+					params.max_penalty_share < Ratio::one(),
+					Error::<T>::InvalidKeeperRewardParams
+				);
+			}
+			KeeperReward::<T>::set(params.clone());
+			Self::deposit_event(Event::KeeperRewardParamsUpdated(params));
+			Ok(())
+		}
+
+		/// Update the limits of debit value issued by increasing the debit of
+		/// CDPs of specific collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `per_block`: the maximum debit value issued in a block, `None` means no limit.
+		/// - `per_epoch`: the maximum debit value issued in an epoch, `None` means no limit.
+		/// - `per_account`: the maximum debit value of the position of an account, `None` means no
+		///   limit.
+		#[pallet::weight((<T as Config>::WeightInfo::set_debit_issuance_limits(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_debit_issuance_limits(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			per_block: ChangeOptionBalance,
+			per_epoch: ChangeOptionBalance,
+			per_account: ChangeOptionBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+
+			let mut limits = Self::debit_issuance_limits(currency_id);
+			if let Change::NewValue(update) = per_block {
+				limits.per_block = update;
+			}
+			if let Change::NewValue(update) = per_epoch {
+				limits.per_epoch = update;
+			}
+			if let Change::NewValue(update) = per_account {
+				limits.per_account = update;
+			}
+
+			DebitIssuanceLimits::<T>::insert(currency_id, &limits);
+			Self::deposit_event(Event::DebitIssuanceLimitsUpdated(currency_id, limits));
+			Ok(())
+		}
+
+		/// Update the liquidation strategies of specific collateral type
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `currency_id`: collateral type.
+		/// - `strategies`: liquidation strategies to be tried in order, must end with `Auction` as
+		///   the last resort, empty means to use the default strategies `[Exchange, Auction]`.
+		#[pallet::weight((<T as Config>::WeightInfo::set_liquidation_strategies(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_liquidation_strategies(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			strategies: Vec<LiquidationStrategy>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				T::CollateralCurrencyIds::get().contains(&currency_id),
+				Error::<T>::InvalidCollateralType,
+			);
+			for (index, strategy) in strategies.iter().enumerate() {
+				ensure!(
+					!strategies[..index].contains(strategy),
+					Error::<T>::DuplicatedLiquidationStrategy
+				);
+			}
+			ensure!(
+				strategies.is_empty() || strategies.last() == Some(&LiquidationStrategy::Auction),
+				Error::<T>::AuctionNotLastResort
+			);
+
+			LiquidationStrategies::<T>::insert(currency_id, &strategies);
+			Self::deposit_event(Event::LiquidationStrategiesUpdated(currency_id, strategies));
+			Ok(())
+		}
+
+		/// Register the liquidation contract in EVM
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `address`: the address of liquidation contract.
+		#[pallet::weight((<T as Config>::WeightInfo::register_liquidation_contract(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn register_liquidation_contract(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			LiquidationContracts::<T>::try_mutate(|contracts| -> DispatchResult {
+				ensure!(
+					!contracts.contains(&address),
+					Error::<T>::LiquidationContractAlreadyRegistered
+				);
+				ensure!(
+					(contracts.len() as u32) < T::MaxLiquidationContracts::get(),
+					Error::<T>::TooManyLiquidationContracts
+				);
+				contracts.push(address);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::LiquidationContractRegistered(address));
+			Ok(())
+		}
+
+		/// Deregister the liquidation contract in EVM
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `address`: the address of liquidation contract.
+		#[pallet::weight((<T as Config>::WeightInfo::deregister_liquidation_contract(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn deregister_liquidation_contract(origin: OriginFor<T>, address: EvmAddress) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			LiquidationContracts::<T>::try_mutate(|contracts| -> DispatchResult {
+				let index = contracts
+					.iter()
+					.position(|contract| *contract == address)
+					.ok_or(Error::<T>::LiquidationContractNotRegistered)?;
+				contracts.remove(index);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::LiquidationContractDeregistered(address));
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -510,11 +1059,8 @@ pub mod module {
 			match call {
 				Call::liquidate { currency_id, who } => {
 					let account = T::Lookup::lookup(who.clone())?;
-					let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &account);
-					if !matches!(
-						Self::check_cdp_status(*currency_id, collateral, debit),
-						CDPStatus::Unsafe
-					) || T::EmergencyShutdown::is_shutdown()
+					if !matches!(Self::check_position_status(&account, *currency_id), CDPStatus::Unsafe)
+						|| T::EmergencyShutdown::is_shutdown()
 					{
 						return InvalidTransaction::Stale.into();
 					}
@@ -673,11 +1219,13 @@ impl<T: Config> Pallet<T> {
 
 		#[allow(clippy::while_let_on_iterator)]
 		while let Some((who, Position { collateral, debit })) = map_iterator.next() {
-			if !is_shutdown
-				&& matches!(
-					Self::check_cdp_status(currency_id, collateral, debit),
-					CDPStatus::Unsafe
-				) {
+			let cdp_status = if <LoansOf<T>>::is_cross_collateral(&who) {
+				Self::check_cross_collateral_cdp_status(&who)
+			} else {
+				Self::check_cdp_status(currency_id, collateral, debit)
+			};
+
+			if !is_shutdown && matches!(cdp_status, CDPStatus::Unsafe) {
 				// liquidate unsafe CDPs before emergency shutdown occurs
 				Self::submit_unsigned_liquidation_tx(currency_id, who);
 			} else if is_shutdown && !debit.is_zero() {
@@ -726,6 +1274,70 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Get the market price of stable currency, which is the TWAP of stable
+	/// currency in the reference currency on DEX multiplied by the price of the
+	/// reference currency.
+	pub fn get_stable_currency_market_price(reference_currency_id: CurrencyId, twap_window: u32) -> Option<Price> {
+		let twap = T::DEX::get_twap(T::GetStableCurrencyId::get(), reference_currency_id, twap_window)?;
+		let reference_price = T::PriceSource::get_price(reference_currency_id)?;
+		twap.checked_mul(&reference_price)
+	}
+
+	/// Adjust the global interest rate per sec by the deviation of the market
+	/// price of stable currency from its peg: raise the rate if the price is
+	/// below the peg, otherwise lower it. The new rate is bounded by the params.
+	pub fn adjust_global_interest_rate(params: &StabilityFeeControllerParams) {
+		if T::EmergencyShutdown::is_shutdown() {
+			return;
+		}
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let (market_price, peg_price) = match (
+			Self::get_stable_currency_market_price(params.reference_currency_id, params.twap_window),
+			T::PriceSource::get_price(stable_currency_id),
+		) {
+			(Some(market_price), Some(peg_price)) if !peg_price.is_zero() => (market_price, peg_price),
+			_ => {
+				log::warn!(
+					target: "cdp-engine",
+					"adjust_global_interest_rate: failed to get the price of stable currency {:?}. \
+					This is unexpected but should be safe",
+					stable_currency_id
+				);
+				return;
+			}
+		};
+
+		let old_rate = Self::global_interest_rate_per_sec();
+		let price_ratio: Ratio = market_price.checked_div(&peg_price).unwrap_or_else(Ratio::max_value);
+		let new_rate = if price_ratio < Ratio::one() {
+			let deviation = Ratio::one().saturating_sub(price_ratio);
+			if deviation > params.tolerance {
+				old_rate.saturating_add(params.sensitivity.saturating_mul(deviation).min(params.max_adjustment))
+			} else {
+				old_rate
+			}
+		} else {
+			let deviation = price_ratio.saturating_sub(Ratio::one());
+			if deviation > params.tolerance {
+				old_rate.saturating_sub(params.sensitivity.saturating_mul(deviation).min(params.max_adjustment))
+			} else {
+				old_rate
+			}
+		}
+		.max(params.min_rate)
+		.min(params.max_rate);
+
+		if new_rate != old_rate {
+			GlobalInterestRatePerSec::<T>::put(new_rate);
+			Self::deposit_event(Event::GlobalInterestRatePerSecAdjusted(
+				market_price,
+				old_rate,
+				new_rate,
+			));
+		}
+	}
+
 	pub fn check_cdp_status(currency_id: CurrencyId, collateral_amount: Balance, debit_amount: Balance) -> CDPStatus {
 		let stable_currency_id = T::GetStableCurrencyId::get();
 		if let Some(feed_price) = T::PriceSource::get_relative_price(currency_id, stable_currency_id) {
@@ -741,6 +1353,82 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub fn check_cross_collateral_cdp_status(who: &T::AccountId) -> CDPStatus {
+		match Self::get_cross_collateral_debit_limits(who) {
+			Ok((total_debit_value, liquidation_debit_limit, _)) => {
+				if liquidation_debit_limit < total_debit_value {
+					CDPStatus::Unsafe
+				} else {
+					CDPStatus::Safe
+				}
+			}
+			Err(e) => CDPStatus::ChecksFailed(e),
+		}
+	}
+
+	/// Check the status of the CDP of `who` under `currency_id`, all positions
+	/// are checked as a whole if `who` uses cross-collateral position.
+	pub fn check_position_status(who: &T::AccountId, currency_id: CurrencyId) -> CDPStatus {
+		if <LoansOf<T>>::is_cross_collateral(who) {
+			Self::check_cross_collateral_cdp_status(who)
+		} else {
+			let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+			Self::check_cdp_status(currency_id, collateral, debit)
+		}
+	}
+
+	/// Get the total debit value of the cross-collateral position of `who` and
+	/// the maximum debit values its collaterals can back, returns
+	/// `(total_debit_value, liquidation_debit_limit, required_debit_limit)`.
+	///
+	/// The limits are the sum of the collateral value of each collateral type
+	/// divided by its liquidation ratio and required collateral ratio
+	/// respectively, the required collateral ratio falls back to the
+	/// liquidation ratio if it's not set.
+	pub fn get_cross_collateral_debit_limits(who: &T::AccountId) -> Result<(Balance, Balance, Balance), DispatchError> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let mut total_debit_value: Balance = Zero::zero();
+		let mut liquidation_debit_limit: Balance = Zero::zero();
+		let mut required_debit_limit: Balance = Zero::zero();
+
+		for currency_id in T::CollateralCurrencyIds::get() {
+			let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+			total_debit_value = total_debit_value.saturating_add(Self::get_debit_value(currency_id, debit));
+
+			if !collateral.is_zero() {
+				let feed_price = T::PriceSource::get_relative_price(currency_id, stable_currency_id)
+					.ok_or(Error::<T>::InvalidFeedPrice)?;
+				let collateral_value = feed_price.saturating_mul_int(collateral);
+				let liquidation_ratio = Self::get_liquidation_ratio(currency_id);
+				let required_collateral_ratio =
+					Self::required_collateral_ratio(currency_id).unwrap_or(liquidation_ratio);
+
+				liquidation_debit_limit = liquidation_debit_limit
+					.saturating_add(Self::calculate_max_debit_value(collateral_value, liquidation_ratio));
+				required_debit_limit = required_debit_limit.saturating_add(Self::calculate_max_debit_value(
+					collateral_value,
+					required_collateral_ratio,
+				));
+			}
+		}
+
+		Ok((total_debit_value, liquidation_debit_limit, required_debit_limit))
+	}
+
+	/// The order of collateral types to be seized when liquidate the
+	/// cross-collateral CDP.
+	pub fn get_cross_collateral_liquidation_order() -> Vec<CurrencyId> {
+		let collateral_currency_ids = T::CollateralCurrencyIds::get();
+		let mut order = Self::cross_collateral_liquidation_priority();
+		order.retain(|currency_id| collateral_currency_ids.contains(currency_id));
+		for currency_id in collateral_currency_ids {
+			if !order.contains(&currency_id) {
+				order.push(currency_id);
+			}
+		}
+		order
+	}
+
 	pub fn maximum_total_debit_value(currency_id: CurrencyId) -> Balance {
 		Self::collateral_params(currency_id).maximum_total_debit_value
 	}
@@ -795,6 +1483,169 @@ impl<T: Config> Pallet<T> {
 		Ratio::checked_from_rational(locked_collateral_value, debit_value).unwrap_or_else(Ratio::max_value)
 	}
 
+	/// Calculate the maximum debit value that `collateral_value` can back
+	/// under `collateral_ratio`.
+	pub fn calculate_max_debit_value(collateral_value: Balance, collateral_ratio: Ratio) -> Balance {
+		// the inner of `checked_from_rational(n, d)` is `n * accuracy / d`, so use the inner
+		// of `collateral_ratio` as denominator to get `collateral_value / collateral_ratio`.
+		Ratio::checked_from_rational(collateral_value, collateral_ratio.into_inner())
+			.map(|max_debit_value| max_debit_value.into_inner())
+			.unwrap_or_else(Balance::max_value)
+	}
+
+	/// Get the collateral ratio of the CDP by the current price of price
+	/// source, returns `None` if the price is not available.
+	pub fn get_collateral_ratio(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Option<Ratio> {
+		T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())
+			.map(|price| Self::calculate_collateral_ratio(currency_id, collateral_balance, debit_balance, price))
+	}
+
+	/// Get the relative price of collateral to stable currency at which the
+	/// CDP reaches the liquidation ratio, returns `None` if the CDP has no
+	/// collateral.
+	pub fn get_liquidation_price(
+		currency_id: CurrencyId,
+		collateral_balance: Balance,
+		debit_balance: Balance,
+	) -> Option<Price> {
+		let liquidation_debit_value = Self::get_liquidation_ratio(currency_id)
+			.saturating_mul_int(Self::get_debit_value(currency_id, debit_balance));
+		Price::checked_from_rational(liquidation_debit_value, collateral_balance)
+	}
+
+	/// Get the stability fee accrued by `debit_balance` since the last interest
+	/// accumulation, it will be added to the debit value by the next
+	/// accumulation.
+	pub fn get_accrued_stability_fee(currency_id: CurrencyId, debit_balance: Balance) -> Balance {
+		let last_accumulation_secs = Self::last_accumulation_secs();
+		if T::EmergencyShutdown::is_shutdown() || last_accumulation_secs.is_zero() {
+			return Zero::zero();
+		}
+
+		let interval_secs = T::UnixTime::now().as_secs().saturating_sub(last_accumulation_secs);
+		Self::compound_interest_rate(Self::get_interest_rate_per_sec(currency_id), interval_secs)
+			.saturating_mul_int(Self::get_debit_value(currency_id, debit_balance))
+	}
+
+	/// Get the positions of `currency_id` which have debit, sorted by their
+	/// collateral ratio in ascending order so that the position nearest to
+	/// liquidation is the first. Returns at most `limit` positions starting
+	/// from the `start` index.
+	pub fn get_positions_nearest_to_liquidation(
+		currency_id: CurrencyId,
+		start: u32,
+		limit: u32,
+	) -> Vec<(T::AccountId, Position)> {
+		let mut positions = <loans::Positions<T>>::iter_prefix(currency_id)
+			.filter(|(_, position)| !position.debit.is_zero())
+			.collect::<Vec<_>>();
+
+		// all positions share the same price and debit exchange rate, so sorting by the ratio
+		// of collateral to debit is the same as sorting by collateral ratio.
+		positions.sort_by_key(|(_, position)| {
+			Ratio::checked_from_rational(position.collateral, position.debit).unwrap_or_else(Ratio::max_value)
+		});
+
+		positions
+			.into_iter()
+			.skip(start as usize)
+			.take(limit as usize)
+			.collect()
+	}
+
+	/// Get the health of the position of `who` in `currency_id`, returns
+	/// `None` if the position is empty.
+	pub fn get_position_health(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+	) -> Option<PositionHealth<T::AccountId, Balance>> {
+		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
+		if collateral.is_zero() && debit.is_zero() {
+			return None;
+		}
+		let status = match Self::check_position_status(&who, currency_id) {
+			CDPStatus::Safe => PositionStatus::Safe,
+			CDPStatus::Unsafe => PositionStatus::Unsafe,
+			CDPStatus::ChecksFailed(_) => PositionStatus::ChecksFailed,
+		};
+		Some(PositionHealth {
+			cross_collateral: <LoansOf<T>>::is_cross_collateral(&who),
+			owner: who,
+			currency_id,
+			collateral,
+			debit,
+			debit_value: Self::get_debit_value(currency_id, debit),
+			collateral_ratio: Self::get_collateral_ratio(currency_id, collateral, debit),
+			liquidation_ratio: Self::get_liquidation_ratio(currency_id),
+			liquidation_price: Self::get_liquidation_price(currency_id, collateral, debit),
+			accrued_stability_fee: Self::get_accrued_stability_fee(currency_id, debit),
+			status,
+		})
+	}
+
+	/// Get the health of all positions of `who`.
+	pub fn get_account_positions_health(who: T::AccountId) -> Vec<PositionHealth<T::AccountId, Balance>> {
+		T::CollateralCurrencyIds::get()
+			.into_iter()
+			.filter_map(|currency_id| Self::get_position_health(who.clone(), currency_id))
+			.collect()
+	}
+
+	/// Get the health of at most `limit` positions of `currency_id`, starting
+	/// after the position of `start` if it's specified, so the positions can
+	/// be read page by page.
+	pub fn get_collateral_positions_health(
+		currency_id: CurrencyId,
+		start: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<PositionHealth<T::AccountId, Balance>> {
+		let iterator = match start {
+			Some(who) => <loans::Positions<T>>::iter_prefix_from(
+				currency_id,
+				<loans::Positions<T>>::hashed_key_for(currency_id, who),
+			),
+			None => <loans::Positions<T>>::iter_prefix(currency_id),
+		};
+		iterator
+			.take(limit as usize)
+			.filter_map(|(who, _)| Self::get_position_health(who, currency_id))
+			.collect()
+	}
+
+	/// Get the health of the positions nearest to liquidation, see
+	/// `get_positions_nearest_to_liquidation`.
+	pub fn get_positions_health_nearest_to_liquidation(
+		currency_id: CurrencyId,
+		start: u32,
+		limit: u32,
+	) -> Vec<PositionHealth<T::AccountId, Balance>> {
+		Self::get_positions_nearest_to_liquidation(currency_id, start, limit)
+			.into_iter()
+			.filter_map(|(who, _)| Self::get_position_health(who, currency_id))
+			.collect()
+	}
+
+	/// Enable or disable cross-collateral position of `who`, the positions of
+	/// `who` must be valid under the new mode.
+	#[transactional]
+	pub fn set_cross_collateral(who: &T::AccountId, enabled: bool) -> DispatchResult {
+		<LoansOf<T>>::set_cross_collateral(who, enabled);
+
+		if enabled {
+			Self::check_cross_collateral_position_valid(who, true)?;
+		} else {
+			for currency_id in T::CollateralCurrencyIds::get() {
+				let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, who);
+				Self::check_position_valid(currency_id, collateral, debit, true)?;
+			}
+		}
+		Ok(())
+	}
+
 	pub fn adjust_position(
 		who: &T::AccountId,
 		currency_id: CurrencyId,
@@ -806,9 +1657,40 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InvalidCollateralType,
 		);
 		<LoansOf<T>>::adjust_position(who, currency_id, collateral_adjustment, debit_adjustment)?;
+
+		if debit_adjustment.is_positive() {
+			Self::deposit_limits_reached_events(who, currency_id);
+		}
 		Ok(())
 	}
 
+	// deposit the events of the debit issuance limits which are reached after the debit of `who`
+	// is increased. The events are deposited after the adjustment succeeds, so that they are not
+	// deposited for the adjustments reverted by exceeding the limits.
+	fn deposit_limits_reached_events(who: &T::AccountId, currency_id: CurrencyId) {
+		let limits = Self::debit_issuance_limits(currency_id);
+		if let Some(ceiling) = limits.per_account {
+			let debit_value = Self::get_debit_value(currency_id, <LoansOf<T>>::positions(currency_id, who).debit);
+			if debit_value >= ceiling {
+				Self::deposit_event(Event::AccountDebitCeilingReached(currency_id, who.clone()));
+			}
+		}
+
+		let record = Self::debit_issuance(currency_id);
+		let now = <frame_system::Pallet<T>>::block_number();
+		if let Some(limit) = limits.per_block {
+			if record.block == now && record.block_issued >= limit {
+				Self::deposit_event(Event::BlockIssuanceLimitReached(currency_id));
+			}
+		}
+		if let Some(limit) = limits.per_epoch {
+			let epoch = now / T::IssuanceEpochPeriod::get().max(One::one());
+			if record.epoch == epoch && record.epoch_issued >= limit {
+				Self::deposit_event(Event::EpochIssuanceLimitReached(currency_id));
+			}
+		}
+	}
+
 	// settle cdp has debit when emergency shutdown
 	pub fn settle_cdp_has_debit(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
@@ -840,7 +1722,7 @@ impl<T: Config> Pallet<T> {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
 		ensure!(!debit.is_zero(), Error::<T>::NoDebitValue);
 		ensure!(
-			matches!(Self::check_cdp_status(currency_id, collateral, debit), CDPStatus::Safe),
+			matches!(Self::check_position_status(&who, currency_id), CDPStatus::Safe),
 			Error::<T>::MustBeSafe
 		);
 
@@ -896,6 +1778,11 @@ impl<T: Config> Pallet<T> {
 			.expect("swap succecced means collateral >= actual_supply_collateral; qed");
 		<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
 
+		// the remain positions of cross-collateral CDP must be still valid
+		if <LoansOf<T>>::is_cross_collateral(&who) {
+			Self::check_cross_collateral_position_valid(&who, true)?;
+		}
+
 		Self::deposit_event(Event::CloseCDPInDebitByDEX(
 			currency_id,
 			who,
@@ -906,8 +1793,22 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	// liquidate unsafe cdp
-	pub fn liquidate_unsafe_cdp(who: T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+	// liquidate unsafe cdp or cross-collateral cdp of `who`, returns the count of liquidated
+	// collateral types, the liquidated debit value and the liquidation penalty.
+	fn do_liquidate(who: T::AccountId, currency_id: CurrencyId) -> Result<(u32, Balance, Balance), DispatchError> {
+		if <LoansOf<T>>::is_cross_collateral(&who) {
+			Self::liquidate_unsafe_cross_collateral_cdp(who)
+		} else {
+			let (bad_debt_value, penalty) = Self::liquidate_unsafe_cdp(who, currency_id)?;
+			Ok((1, bad_debt_value, penalty))
+		}
+	}
+
+	// liquidate unsafe cdp, returns the liquidated debit value and the liquidation penalty.
+	pub fn liquidate_unsafe_cdp(
+		who: T::AccountId,
+		currency_id: CurrencyId,
+	) -> Result<(Balance, Balance), DispatchError> {
 		let Position { collateral, debit } = <LoansOf<T>>::positions(currency_id, &who);
 
 		// ensure the cdp is unsafe
@@ -919,74 +1820,208 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::MustBeUnsafe
 		);
 
-		// confiscate all collateral and debit of unsafe cdp to cdp treasury
-		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, debit)?;
+		// if partial liquidation is available, only confiscate the debit needed to restore
+		// the collateral ratio to the target ratio and the collateral to cover it, otherwise
+		// confiscate all collateral and debit of unsafe cdp.
+		let (confiscate_collateral, confiscate_debit) =
+			match Self::calculate_partial_liquidation_debit(currency_id, collateral, debit) {
+				Some(partial_debit) => {
+					let target_stable_amount = Self::get_liquidation_penalty(currency_id)
+						.saturating_mul_acc_int(Self::get_debit_value(currency_id, partial_debit));
+					(
+						collateral.min(Self::get_max_collateral_supply(currency_id, target_stable_amount)),
+						partial_debit,
+					)
+				}
+				None => (collateral, debit),
+			};
 
-		let bad_debt_value = Self::get_debit_value(currency_id, debit);
+		// confiscate collateral and debit of unsafe cdp to cdp treasury
+		<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, confiscate_collateral, confiscate_debit)?;
+
+		let bad_debt_value = Self::get_debit_value(currency_id, confiscate_debit);
 		let target_stable_amount = Self::get_liquidation_penalty(currency_id).saturating_mul_acc_int(bad_debt_value);
-		let liquidation_strategy = (|| -> Result<LiquidationStrategy, DispatchError> {
-			let default_swap_parital_path_list: Vec<Vec<CurrencyId>> = T::DefaultSwapParitalPathList::get();
-
-			// calculate the supply limit by slippage limit for the price of oracle,
-			let max_supply_limit = Ratio::one()
-				.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
-				.reciprocal()
-				.unwrap_or_else(Ratio::max_value)
-				.saturating_mul_int(
-					T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
-						.expect("the oracle price should be avalible because liquidation are triggered by it.")
-						.saturating_mul_int(target_stable_amount),
-				);
-			let collateral_supply = collateral.min(max_supply_limit);
+		let liquidation_strategy =
+			Self::liquidate_collateral(&who, currency_id, confiscate_collateral, target_stable_amount)?;
+
+		Self::deposit_event(Event::LiquidateUnsafeCDP(
+			currency_id,
+			who,
+			confiscate_collateral,
+			bad_debt_value,
+			liquidation_strategy,
+		));
+		Ok((bad_debt_value, target_stable_amount.saturating_sub(bad_debt_value)))
+	}
 
-			// iterator default_swap_parital_path_list to try swap until swap succeed.
-			for partial_path in default_swap_parital_path_list {
-				let partial_path_len = partial_path.len();
+	/// Calculate the debit balance of the unsafe CDP to be liquidated to
+	/// restore its collateral ratio to the partial liquidation target ratio.
+	/// Returns `None` if partial liquidation is not set or not available, then
+	/// the entire CDP should be liquidated.
+	///
+	/// The collateral seized for liquidated debit value `d` is worth at most
+	/// `d * s`, where `s = (1 + liquidation_penalty) / (1 - max_swap_slippage)`,
+	/// so the remaining position reaches the target ratio `r` when
+	/// `d >= (r * debit_value - collateral_value) / (r - s)`.
+	pub fn calculate_partial_liquidation_debit(
+		currency_id: CurrencyId,
+		collateral: Balance,
+		debit: Balance,
+	) -> Option<Balance> {
+		let target_ratio = Self::collateral_params(currency_id).partial_liquidation_target_ratio?;
+		let feed_price = T::PriceSource::get_relative_price(currency_id, T::GetStableCurrencyId::get())?;
+		let collateral_value = feed_price.saturating_mul_int(collateral);
+		let debit_value = Self::get_debit_value(currency_id, debit);
 
-				// check collateral currency_id and partial_path can form a valid swap path.
-				if partial_path_len > 0 && currency_id != partial_path[0] {
-					let mut swap_path = vec![currency_id];
-					swap_path.extend(partial_path);
+		let seized_ratio = Ratio::one()
+			.saturating_add(Self::get_liquidation_penalty(currency_id))
+			.checked_div(&Ratio::one().saturating_sub(T::MaxSwapSlippageCompareToOracle::get()))?;
+		let ratio_diff = target_ratio.checked_sub(&seized_ratio).filter(|r| !r.is_zero())?;
+		let shortfall_value = target_ratio
+			.saturating_mul_int(debit_value)
+			.checked_sub(collateral_value)
+			.filter(|v| !v.is_zero())?;
+
+		// round up the liquidated debit value and the debit balance to ensure the target ratio
+		let liquidated_debit_value = Self::calculate_max_debit_value(shortfall_value, ratio_diff).saturating_add(1);
+		let liquidated_debit =
+			Self::calculate_max_debit_value(liquidated_debit_value, Self::get_debit_exchange_rate(currency_id))
+				.saturating_add(1);
+
+		// the remaining debit value of the position must not be dust
+		let remain_debit = debit.checked_sub(liquidated_debit).filter(|d| !d.is_zero())?;
+		if Self::get_debit_value(currency_id, remain_debit) < T::MinimumDebitValue::get() {
+			return None;
+		}
 
-					if let Ok(actual_supply_collateral) = <T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
-						currency_id,
-						collateral_supply,
-						target_stable_amount,
-						&swap_path,
-						false,
-					) {
-						// refund remain collateral to CDP owner
-						let refund_collateral_amount = collateral
-							.checked_sub(actual_supply_collateral)
-							.expect("swap succecced means collateral >= actual_supply_collateral; qed");
-
-						<T as Config>::CDPTreasury::withdraw_collateral(&who, currency_id, refund_collateral_amount)?;
-
-						return Ok(LiquidationStrategy::Exchange);
-					}
-				}
+		Some(liquidated_debit)
+	}
+
+	// liquidate unsafe cross-collateral cdp, confiscate all debits and seize the collaterals in
+	// the liquidation priority order until the debit value with penalty is covered, returns
+	// the count of liquidated collateral types, the liquidated debit value and the liquidation
+	// penalty.
+	pub fn liquidate_unsafe_cross_collateral_cdp(who: T::AccountId) -> Result<(u32, Balance, Balance), DispatchError> {
+		// ensure the cdp is unsafe
+		ensure!(
+			matches!(Self::check_cross_collateral_cdp_status(&who), CDPStatus::Unsafe),
+			Error::<T>::MustBeUnsafe
+		);
+
+		// confiscate all debit of the positions to cdp treasury
+		let mut remain_target_stable_amount: Balance = Zero::zero();
+		let mut total_bad_debt_value: Balance = Zero::zero();
+		for currency_id in T::CollateralCurrencyIds::get() {
+			let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, &who);
+			if !debit.is_zero() {
+				<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, Zero::zero(), debit)?;
+
+				let bad_debt_value = Self::get_debit_value(currency_id, debit);
+				total_bad_debt_value = total_bad_debt_value.saturating_add(bad_debt_value);
+				remain_target_stable_amount = remain_target_stable_amount
+					.saturating_add(Self::get_liquidation_penalty(currency_id).saturating_mul_acc_int(bad_debt_value));
+			}
+		}
+		let penalty = remain_target_stable_amount.saturating_sub(total_bad_debt_value);
+
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		let mut liquidated_count: u32 = 0;
+		for currency_id in Self::get_cross_collateral_liquidation_order() {
+			if remain_target_stable_amount.is_zero() {
+				break;
 			}
 
-			// if cannot liquidate by swap, create collateral auctions by cdp treasury
-			<T as Config>::CDPTreasury::create_collateral_auctions(
+			let Position { collateral, .. } = <LoansOf<T>>::positions(currency_id, &who);
+			let collateral_value = T::PriceSource::get_relative_price(currency_id, stable_currency_id)
+				.ok_or(Error::<T>::InvalidFeedPrice)?
+				.saturating_mul_int(collateral);
+			let target_stable_amount = remain_target_stable_amount.min(collateral_value);
+			if target_stable_amount.is_zero() {
+				continue;
+			}
+
+			// confiscate the whole collateral of this type to cdp treasury
+			<LoansOf<T>>::confiscate_collateral_and_debit(&who, currency_id, collateral, Zero::zero())?;
+			let liquidation_strategy = Self::liquidate_collateral(&who, currency_id, collateral, target_stable_amount)?;
+
+			remain_target_stable_amount = remain_target_stable_amount.saturating_sub(target_stable_amount);
+			liquidated_count = liquidated_count.saturating_add(1);
+
+			Self::deposit_event(Event::LiquidateCrossCollateralCDP(
+				who.clone(),
 				currency_id,
 				collateral,
 				target_stable_amount,
-				who.clone(),
-				true,
-			)?;
+				liquidation_strategy,
+			));
+		}
 
-			Ok(LiquidationStrategy::Auction)
-		})()?;
+		Ok((liquidated_count, total_bad_debt_value, penalty))
+	}
 
-		Self::deposit_event(Event::LiquidateUnsafeCDP(
-			currency_id,
-			who,
-			collateral,
-			bad_debt_value,
-			liquidation_strategy,
-		));
-		Ok(())
+	// calculate the amount of collateral worth `target_stable_amount` by the price of oracle.
+	fn get_oracle_collateral_amount(currency_id: CurrencyId, target_stable_amount: Balance) -> Balance {
+		T::PriceSource::get_relative_price(T::GetStableCurrencyId::get(), currency_id)
+			.expect("the oracle price should be avalible because liquidation are triggered by it.")
+			.saturating_mul_int(target_stable_amount)
+	}
+
+	// calculate the supply limit of collateral to get `target_stable_amount` by slippage limit for
+	// the price of oracle.
+	fn get_max_collateral_supply(currency_id: CurrencyId, target_stable_amount: Balance) -> Balance {
+		Ratio::one()
+			.saturating_sub(T::MaxSwapSlippageCompareToOracle::get())
+			.reciprocal()
+			.unwrap_or_else(Ratio::max_value)
+			.saturating_mul_int(Self::get_oracle_collateral_amount(currency_id, target_stable_amount))
+	}
+
+	// the weight of asking all the registered liquidation contracts to liquidate one collateral,
+	// each contract is called twice at most.
+	fn liquidation_contracts_weight() -> Weight {
+		T::GasToWeight::convert(LIQUIDATION_CONTRACT_GAS_LIMIT)
+			.saturating_mul(T::MaxLiquidationContracts::get().saturating_mul(2) as Weight)
+	}
+
+	/// The liquidation strategies of specific collateral type, the default
+	/// strategies are `[Exchange, Auction]`.
+	pub fn get_liquidation_strategies(currency_id: CurrencyId) -> Vec<LiquidationStrategy> {
+		let strategies = Self::liquidation_strategies(currency_id);
+		if strategies.is_empty() {
+			vec![LiquidationStrategy::Exchange, LiquidationStrategy::Auction]
+		} else {
+			strategies
+		}
+	}
+
+	// liquidate the `collateral` which has been confiscated to cdp treasury to get
+	// `target_stable_amount`, try the liquidation strategies of the collateral type in order
+	// until one succeeds. The remain collateral will be refunded to `who`.
+	fn liquidate_collateral(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		collateral: Balance,
+		target_stable_amount: Balance,
+	) -> Result<LiquidationStrategy, DispatchError> {
+		for strategy in Self::get_liquidation_strategies(currency_id) {
+			let result = match strategy {
+				LiquidationStrategy::Exchange => {
+					LiquidateViaDex::<T>::liquidate(who, currency_id, collateral, target_stable_amount)
+				}
+				LiquidationStrategy::Auction => {
+					LiquidateViaAuction::<T>::liquidate(who, currency_id, collateral, target_stable_amount)
+				}
+				LiquidationStrategy::Contract => {
+					LiquidateViaContracts::<T>::liquidate(who, currency_id, collateral, target_stable_amount)
+				}
+			};
+
+			if result.is_ok() {
+				return Ok(strategy);
+			}
+		}
+
+		Err(Error::<T>::LiquidationFailed.into())
 	}
 }
 
@@ -1034,12 +2069,90 @@ impl<T: Config> RiskManager<T::AccountId, CurrencyId, Balance, Balance> for Pall
 		Ok(())
 	}
 
-	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: Balance) -> DispatchResult {
+	fn check_debit_cap(
+		who: &T::AccountId,
+		currency_id: CurrencyId,
+		total_debit_balance: Balance,
+		debit_increase: Balance,
+	) -> DispatchResult {
 		let hard_cap = Self::maximum_total_debit_value(currency_id);
 		let total_debit_value = Self::get_debit_value(currency_id, total_debit_balance);
 
 		ensure!(total_debit_value <= hard_cap, Error::<T>::ExceedDebitValueHardCap,);
 
+		if debit_increase.is_zero() {
+			return Ok(());
+		}
+
+		Self::check_account_debit_ceiling(who, currency_id)?;
+
+		let limits = Self::debit_issuance_limits(currency_id);
+		if limits.per_block.is_none() && limits.per_epoch.is_none() {
+			return Ok(());
+		}
+
+		let issued_value = Self::get_debit_value(currency_id, debit_increase);
+		let now = <frame_system::Pallet<T>>::block_number();
+		let epoch = now / T::IssuanceEpochPeriod::get().max(One::one());
+		DebitIssuance::<T>::try_mutate(currency_id, |record| -> DispatchResult {
+			if record.block != now {
+				record.block = now;
+				record.block_issued = Zero::zero();
+			}
+			if record.epoch != epoch {
+				record.epoch = epoch;
+				record.epoch_issued = Zero::zero();
+			}
+			record.block_issued = record.block_issued.saturating_add(issued_value);
+			record.epoch_issued = record.epoch_issued.saturating_add(issued_value);
+
+			if let Some(limit) = limits.per_block {
+				ensure!(record.block_issued <= limit, Error::<T>::ExceedBlockIssuanceLimit);
+			}
+			if let Some(limit) = limits.per_epoch {
+				ensure!(record.epoch_issued <= limit, Error::<T>::ExceedEpochIssuanceLimit);
+			}
+			Ok(())
+		})
+	}
+
+	fn check_account_debit_ceiling(who: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
+		if let Some(ceiling) = Self::debit_issuance_limits(currency_id).per_account {
+			let debit_value = Self::get_debit_value(currency_id, <LoansOf<T>>::positions(currency_id, who).debit);
+			ensure!(debit_value <= ceiling, Error::<T>::ExceedAccountDebitCeiling);
+		}
+		Ok(())
+	}
+
+	fn check_cross_collateral_position_valid(who: &T::AccountId, check_required_ratio: bool) -> DispatchResult {
+		let (total_debit_value, liquidation_debit_limit, required_debit_limit) =
+			Self::get_cross_collateral_debit_limits(who)?;
+
+		if !total_debit_value.is_zero() {
+			// check the required collateral ratio
+			if check_required_ratio {
+				ensure!(
+					required_debit_limit >= total_debit_value,
+					Error::<T>::BelowRequiredCollateralRatio
+				);
+			}
+
+			// check the liquidation ratio
+			ensure!(
+				liquidation_debit_limit >= total_debit_value,
+				Error::<T>::BelowLiquidationRatio
+			);
+
+			// check the minimum_debit_value of each position
+			for currency_id in T::CollateralCurrencyIds::get() {
+				let Position { debit, .. } = <LoansOf<T>>::positions(currency_id, who);
+				ensure!(
+					debit.is_zero() || Self::get_debit_value(currency_id, debit) >= T::MinimumDebitValue::get(),
+					Error::<T>::RemainDebitValueTooSmall,
+				);
+			}
+		}
+
 		Ok(())
 	}
 }
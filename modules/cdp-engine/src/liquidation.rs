@@ -0,0 +1,191 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use orml_traits::MultiCurrency;
+use sp_std::{convert::TryFrom, marker::PhantomData};
+
+/// Liquidate collateral by swap with DEX through `DefaultSwapParitalPathList`.
+pub struct LiquidateViaDex<T>(PhantomData<T>);
+
+impl<T: Config> LiquidationStrategyTrait<T::AccountId, CurrencyId, Balance> for LiquidateViaDex<T> {
+	#[transactional]
+	fn liquidate(
+		refund_receiver: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		let collateral_supply = amount.min(Pallet::<T>::get_max_collateral_supply(
+			currency_id,
+			target_stable_amount,
+		));
+
+		// iterator default_swap_parital_path_list to try swap until swap succeed.
+		for partial_path in T::DefaultSwapParitalPathList::get() {
+			// check collateral currency_id and partial_path can form a valid swap path.
+			if !partial_path.is_empty() && currency_id != partial_path[0] {
+				let mut swap_path = vec![currency_id];
+				swap_path.extend(partial_path);
+
+				if let Ok(actual_supply_collateral) = <T as Config>::CDPTreasury::swap_collateral_to_exact_stable(
+					currency_id,
+					collateral_supply,
+					target_stable_amount,
+					&swap_path,
+					false,
+				) {
+					// refund remain collateral to CDP owner
+					let refund_collateral_amount = amount
+						.checked_sub(actual_supply_collateral)
+						.expect("swap succecced means collateral >= actual_supply_collateral; qed");
+
+					return <T as Config>::CDPTreasury::withdraw_collateral(
+						refund_receiver,
+						currency_id,
+						refund_collateral_amount,
+					);
+				}
+			}
+		}
+
+		Err(Error::<T>::LiquidationFailed.into())
+	}
+}
+
+/// Liquidate collateral by creating collateral auctions by cdp treasury.
+pub struct LiquidateViaAuction<T>(PhantomData<T>);
+
+impl<T: Config> LiquidationStrategyTrait<T::AccountId, CurrencyId, Balance> for LiquidateViaAuction<T> {
+	#[transactional]
+	fn liquidate(
+		refund_receiver: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		<T as Config>::CDPTreasury::create_collateral_auctions(
+			currency_id,
+			amount,
+			target_stable_amount,
+			refund_receiver.clone(),
+			true,
+		)
+	}
+}
+
+/// Liquidate collateral by the registered liquidation contracts in EVM.
+///
+/// The contracts are asked in order to repay at least the target amount of
+/// stable currency for the collateral worth the target amount by the price of
+/// oracle, which is never more than the supply limit by
+/// `MaxSwapSlippageCompareToOracle` like the swap with DEX. The first contract
+/// repays enough gets that collateral, the repayment is deposited to cdp
+/// treasury as surplus and the remaining collateral is refunded.
+pub struct LiquidateViaContracts<T>(PhantomData<T>);
+
+impl<T: Config> LiquidationStrategyTrait<T::AccountId, CurrencyId, Balance> for LiquidateViaContracts<T> {
+	#[transactional]
+	fn liquidate(
+		refund_receiver: &T::AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult {
+		let collateral = EvmAddress::try_from(currency_id).map_err(|_| Error::<T>::InvalidCollateralType)?;
+		// ask for the collateral worth the target by the price of oracle rather than the supply
+		// limit, so the CDP owner gets back all collateral not needed to cover the target.
+		let collateral_supply = amount
+			.min(Pallet::<T>::get_max_collateral_supply(
+				currency_id,
+				target_stable_amount,
+			))
+			.min(Pallet::<T>::get_oracle_collateral_amount(
+				currency_id,
+				target_stable_amount,
+			));
+
+		for contract in Pallet::<T>::liquidation_contracts() {
+			if let Ok(repayment) = Self::liquidate_by_contract(
+				contract,
+				currency_id,
+				collateral,
+				collateral_supply,
+				target_stable_amount,
+			) {
+				// refund remain collateral to CDP owner
+				<T as Config>::CDPTreasury::withdraw_collateral(
+					refund_receiver,
+					currency_id,
+					amount.saturating_sub(collateral_supply),
+				)?;
+
+				Pallet::<T>::deposit_event(Event::LiquidateByContract(
+					currency_id,
+					contract,
+					collateral_supply,
+					repayment,
+				));
+				return Ok(());
+			}
+		}
+
+		Err(Error::<T>::LiquidationFailed.into())
+	}
+}
+
+impl<T: Config> LiquidateViaContracts<T> {
+	// ask the `contract` to repay `target_stable_amount` for `amount` of collateral, if the
+	// repayment is not enough all changes are reverted. Returns the repayment.
+	#[transactional]
+	fn liquidate_by_contract(
+		contract: EvmAddress,
+		currency_id: CurrencyId,
+		collateral: EvmAddress,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		// the contracts are called by a dedicated account rather than cdp treasury, it pays the
+		// storage of the contracts and receives the repayment.
+		let caller = T::LiquidationContractsAccount::get();
+		let caller_address = T::EvmAddressMapping::get_or_create_evm_address(&caller);
+		let context = InvokeContext {
+			contract,
+			sender: caller_address,
+			origin: caller_address,
+		};
+
+		let balance_before = <T as loans::Config>::Currency::free_balance(stable_currency_id, &caller);
+		T::LiquidationEvmBridge::liquidate(context, collateral, caller_address, amount, target_stable_amount)?;
+		let repayment =
+			<T as loans::Config>::Currency::free_balance(stable_currency_id, &caller).saturating_sub(balance_before);
+		ensure!(repayment >= target_stable_amount, Error::<T>::LiquidationFailed);
+		<T as Config>::CDPTreasury::deposit_surplus(&caller, repayment)?;
+
+		// transfer the collateral to the contract
+		<T as Config>::CDPTreasury::withdraw_collateral(
+			&T::EvmAddressMapping::get_account_id(&contract),
+			currency_id,
+			amount,
+		)?;
+		T::LiquidationEvmBridge::on_collateral_transfer(context, collateral, amount)?;
+
+		Ok(repayment)
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{CollateralParams, Config, Pallet, RiskManagementParams, Weight};
+use codec::{Decode, Encode};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, StorageVersion},
+};
+use primitives::Balance;
+use sp_runtime::FixedU128;
+
+pub mod v1 {
+	use super::*;
+
+	/// `RiskManagementParams` before `partial_liquidation_target_ratio` was
+	/// added.
+	#[derive(Encode, Decode)]
+	struct OldRiskManagementParams {
+		maximum_total_debit_value: Balance,
+		interest_rate_per_sec: Option<FixedU128>,
+		liquidation_ratio: Option<FixedU128>,
+		liquidation_penalty: Option<FixedU128>,
+		required_collateral_ratio: Option<FixedU128>,
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"cdp-engine already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"cdp-engine storage version not set."
+		);
+		assert!(
+			CollateralParams::<T>::iter_values().all(|params| params.partial_liquidation_target_ratio.is_none()),
+			"partial liquidation target ratio has been set."
+		);
+		Ok(())
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "cdp-engine", "Migrating cdp-engine v1");
+
+		let mut translated: u64 = 0;
+		CollateralParams::<T>::translate::<OldRiskManagementParams, _>(|_, old| {
+			translated += 1;
+			Some(RiskManagementParams {
+				maximum_total_debit_value: old.maximum_total_debit_value,
+				interest_rate_per_sec: old.interest_rate_per_sec,
+				liquidation_ratio: old.liquidation_ratio,
+				liquidation_penalty: old.liquidation_penalty,
+				required_collateral_ratio: old.required_collateral_ratio,
+				partial_liquidation_target_ratio: None,
+			})
+		});
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "cdp-engine", "Completed cdp-engine migration to v1");
+
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+}
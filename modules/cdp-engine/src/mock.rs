@@ -27,7 +27,7 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
 use primitives::{Moment, TokenSymbol, TradingPair};
 use sp_core::H256;
 use sp_runtime::{
@@ -147,6 +147,7 @@ impl loans::Config for Runtime {
 
 thread_local! {
 	static RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::one()));
+	static DOT_RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(None);
 }
 
 pub struct MockPriceSource;
@@ -154,18 +155,90 @@ impl MockPriceSource {
 	pub fn set_relative_price(price: Option<Price>) {
 		RELATIVE_PRICE.with(|v| *v.borrow_mut() = price);
 	}
+
+	pub fn set_dot_relative_price(price: Option<Price>) {
+		DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut() = price);
+	}
 }
 impl PriceProvider<CurrencyId> for MockPriceSource {
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
 		match (base, quote) {
 			(AUSD, BTC) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			(BTC, AUSD) => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			(AUSD, DOT) => DOT_RELATIVE_PRICE
+				.with(|v| *v.borrow_mut())
+				.and_then(|price| price.reciprocal()),
+			(DOT, AUSD) => DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			_ => None,
+		}
+	}
+
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			AUSD => Some(Price::one()),
+			BTC => RELATIVE_PRICE.with(|v| *v.borrow_mut()),
+			DOT => DOT_RELATIVE_PRICE.with(|v| *v.borrow_mut()),
 			_ => None,
 		}
 	}
+}
+
+thread_local! {
+	static LIQUIDATION_REPAYMENT: RefCell<Balance> = RefCell::new(0);
+}
+
+pub struct MockAddressMapping;
+impl AddressMapping<AccountId> for MockAddressMapping {
+	fn get_account_id(evm: &EvmAddress) -> AccountId {
+		let mut data = [0u8; 16];
+		data.copy_from_slice(&evm.as_bytes()[4..20]);
+		AccountId::from_be_bytes(data)
+	}
+
+	fn get_evm_address(account_id: &AccountId) -> Option<EvmAddress> {
+		let mut data = [0u8; 20];
+		data[4..20].copy_from_slice(&account_id.to_be_bytes());
+		Some(EvmAddress::from(data))
+	}
+
+	fn get_or_create_evm_address(account_id: &AccountId) -> EvmAddress {
+		Self::get_evm_address(account_id).unwrap_or_default()
+	}
+
+	fn get_default_evm_address(account_id: &AccountId) -> EvmAddress {
+		Self::get_or_create_evm_address(account_id)
+	}
+
+	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool {
+		Self::get_evm_address(account_id) == Some(*evm)
+	}
+}
+
+pub struct MockLiquidationEvmBridge;
+impl MockLiquidationEvmBridge {
+	pub fn set_repayment(repayment: Balance) {
+		LIQUIDATION_REPAYMENT.with(|v| *v.borrow_mut() = repayment);
+	}
+}
+impl LiquidationEvmBridge<Balance> for MockLiquidationEvmBridge {
+	fn liquidate(
+		context: InvokeContext,
+		_collateral: EvmAddress,
+		repay_dest: EvmAddress,
+		_amount: Balance,
+		_min_repayment: Balance,
+	) -> DispatchResult {
+		let repayment = LIQUIDATION_REPAYMENT.with(|v| *v.borrow());
+		Currencies::transfer(
+			AUSD,
+			&MockAddressMapping::get_account_id(&context.contract),
+			&MockAddressMapping::get_account_id(&repay_dest),
+			repayment,
+		)
+	}
 
-	fn get_price(_currency_id: CurrencyId) -> Option<Price> {
-		unimplemented!()
+	fn on_collateral_transfer(_context: InvokeContext, _collateral: EvmAddress, _amount: Balance) -> DispatchResult {
+		Ok(())
 	}
 }
 
@@ -184,6 +257,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_amount: Self::Balance, _fix: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -195,6 +276,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 parameter_types! {
@@ -214,13 +303,18 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub DEXProtocolFeeShare: Ratio = Default::default();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10;
+	pub const MaxPriceObservations: u32 = 10;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 10;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -234,12 +328,18 @@ impl dex::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = TreasuryAccount;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 parameter_types! {
@@ -278,6 +378,10 @@ parameter_types! {
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const StabilityFeeAdjustmentPeriod: BlockNumber = 10;
+	pub const IssuanceEpochPeriod: BlockNumber = 10;
+	pub LiquidationContractsAccount: AccountId = PalletId(*b"aca/cdpe").into_account();
+	pub const MaxLiquidationContracts: u32 = 2;
 	pub CollateralCurrencyIds: Vec<CurrencyId> = vec![BTC, DOT];
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
@@ -288,6 +392,9 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type DEX = DEXModule;
+	type StabilityFeeAdjustmentPeriod = StabilityFeeAdjustmentPeriod;
+	type IssuanceEpochPeriod = IssuanceEpochPeriod;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -301,6 +408,11 @@ impl Config for Runtime {
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type LiquidationEvmBridge = MockLiquidationEvmBridge;
+	type EvmAddressMapping = MockAddressMapping;
+	type LiquidationContractsAccount = LiquidationContractsAccount;
+	type GasToWeight = ();
+	type MaxLiquidationContracts = MaxLiquidationContracts;
 	type WeightInfo = ();
 }
 
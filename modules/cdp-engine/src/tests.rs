@@ -57,6 +57,7 @@ fn check_cdp_status_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Safe);
 
@@ -68,6 +69,7 @@ fn check_cdp_status_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 100, 500), CDPStatus::Unsafe);
 
@@ -109,6 +111,7 @@ fn get_liquidation_penalty_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_penalty(BTC),
@@ -132,6 +135,7 @@ fn get_liquidation_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_liquidation_ratio(BTC),
@@ -174,6 +178,7 @@ fn set_collateral_params_work() {
 				Change::NoChange,
 				Change::NoChange,
 				Change::NoChange,
+				Change::NoChange,
 			),
 			Error::<Runtime>::InvalidCollateralType
 		);
@@ -188,6 +193,7 @@ fn set_collateral_params_work() {
 				Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 				Change::NewValue(10000),
+				Change::NoChange,
 			),
 			BadOrigin
 		);
@@ -199,6 +205,7 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
 		));
 		System::assert_has_event(Event::CDPEngineModule(crate::Event::InterestRatePerSecUpdated(
 			BTC,
@@ -219,6 +226,9 @@ fn set_collateral_params_work() {
 		System::assert_has_event(Event::CDPEngineModule(crate::Event::MaximumTotalDebitValueUpdated(
 			BTC, 10000,
 		)));
+		System::assert_has_event(Event::CDPEngineModule(
+			crate::Event::PartialLiquidationTargetRatioUpdated(BTC, Some(Ratio::saturating_from_rational(2, 1))),
+		));
 
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
@@ -228,6 +238,7 @@ fn set_collateral_params_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
 		));
 
 		let new_collateral_params = CDPEngineModule::collateral_params(BTC);
@@ -249,6 +260,10 @@ fn set_collateral_params_work() {
 			Some(Ratio::saturating_from_rational(9, 5))
 		);
 		assert_eq!(new_collateral_params.maximum_total_debit_value, 10000);
+		assert_eq!(
+			new_collateral_params.partial_liquidation_target_ratio,
+			Some(Ratio::saturating_from_rational(2, 1))
+		);
 	});
 }
 
@@ -263,11 +278,187 @@ fn calculate_collateral_ratio_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::calculate_collateral_ratio(BTC, 100, 500, Price::saturating_from_rational(1, 1)),
 			Ratio::saturating_from_rational(100, 50)
 		);
+		assert_eq!(
+			CDPEngineModule::get_collateral_ratio(BTC, 100, 500),
+			Some(Ratio::saturating_from_rational(100, 50))
+		);
+
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(CDPEngineModule::get_collateral_ratio(BTC, 100, 500), None);
+	});
+}
+
+#[test]
+fn get_liquidation_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_eq!(
+			CDPEngineModule::get_liquidation_price(BTC, 100, 500),
+			Some(Price::saturating_from_rational(3, 4))
+		);
+		assert_eq!(CDPEngineModule::get_liquidation_price(BTC, 100, 0), Some(Price::zero()));
+		assert_eq!(CDPEngineModule::get_liquidation_price(BTC, 0, 500), None);
+	});
+}
+
+#[test]
+fn get_accrued_stability_fee_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		Timestamp::set_timestamp(3000);
+		assert_eq!(CDPEngineModule::get_accrued_stability_fee(BTC, 50000), 0);
+
+		// 5000 * (1.01 ^ 2 - 1)
+		LastAccumulationSecs::<Runtime>::put(1);
+		assert_eq!(CDPEngineModule::get_accrued_stability_fee(BTC, 50000), 100);
+		assert_eq!(CDPEngineModule::get_accrued_stability_fee(BTC, 0), 0);
+
+		mock_shutdown();
+		assert_eq!(CDPEngineModule::get_accrued_stability_fee(BTC, 50000), 0);
+	});
+}
+
+#[test]
+fn get_positions_nearest_to_liquidation_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 400));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 200, 0));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		assert_eq!(
+			CDPEngineModule::get_positions_nearest_to_liquidation(BTC, 0, 10),
+			vec![
+				(
+					ALICE,
+					Position {
+						collateral: 100,
+						debit: 500
+					}
+				),
+				(
+					BOB,
+					Position {
+						collateral: 100,
+						debit: 400
+					}
+				),
+			]
+		);
+		assert_eq!(
+			CDPEngineModule::get_positions_nearest_to_liquidation(BTC, 1, 1),
+			vec![(
+				BOB,
+				Position {
+					collateral: 100,
+					debit: 400
+				}
+			)]
+		);
+		assert_eq!(
+			CDPEngineModule::get_positions_nearest_to_liquidation(BTC, 2, 10),
+			vec![]
+		);
+		assert_eq!(
+			CDPEngineModule::get_positions_nearest_to_liquidation(DOT, 0, 10),
+			vec![]
+		);
+	});
+}
+
+#[test]
+fn get_positions_health_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 400));
+		assert_ok!(CDPEngineModule::adjust_position(&CAROL, BTC, 200, 0));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		assert_eq!(CDPEngineModule::get_position_health(ALICE, DOT), None);
+		let health = CDPEngineModule::get_position_health(BOB, BTC).unwrap();
+		assert_eq!(health.owner, BOB);
+		assert_eq!(health.collateral, 100);
+		assert_eq!(health.debit, 400);
+		assert_eq!(health.debit_value, CDPEngineModule::get_debit_value(BTC, 400));
+		assert_eq!(health.liquidation_ratio, Ratio::saturating_from_rational(3, 2));
+		assert!(!health.cross_collateral);
+		assert_eq!(
+			health.status,
+			match CDPEngineModule::check_position_status(&BOB, BTC) {
+				CDPStatus::Safe => PositionStatus::Safe,
+				CDPStatus::Unsafe => PositionStatus::Unsafe,
+				CDPStatus::ChecksFailed(_) => PositionStatus::ChecksFailed,
+			}
+		);
+
+		assert_eq!(CDPEngineModule::get_account_positions_health(BOB).len(), 1);
+		assert_eq!(CDPEngineModule::get_account_positions_health(BOB)[0], health);
+
+		// read the positions page by page
+		let first_page = CDPEngineModule::get_collateral_positions_health(BTC, None, 2);
+		assert_eq!(first_page.len(), 2);
+		let second_page = CDPEngineModule::get_collateral_positions_health(BTC, Some(first_page[1].owner), 2);
+		assert_eq!(second_page.len(), 1);
+		let mut owners = first_page
+			.iter()
+			.chain(second_page.iter())
+			.map(|health| health.owner)
+			.collect::<Vec<_>>();
+		owners.sort_unstable();
+		let mut expected_owners = vec![ALICE, BOB, CAROL];
+		expected_owners.sort_unstable();
+		assert_eq!(owners, expected_owners);
+		assert!(CDPEngineModule::get_collateral_positions_health(DOT, None, 10).is_empty());
+
+		assert_eq!(
+			CDPEngineModule::get_positions_health_nearest_to_liquidation(BTC, 0, 10)
+				.into_iter()
+				.map(|health| health.owner)
+				.collect::<Vec<_>>(),
+			vec![ALICE, BOB]
+		);
 	});
 }
 
@@ -282,15 +473,220 @@ fn check_debit_cap_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
-		assert_ok!(CDPEngineModule::check_debit_cap(BTC, 100000));
+		assert_ok!(CDPEngineModule::check_debit_cap(&ALICE, BTC, 100000, 0));
 		assert_noop!(
-			CDPEngineModule::check_debit_cap(BTC, 100010),
+			CDPEngineModule::check_debit_cap(&ALICE, BTC, 100010, 0),
 			Error::<Runtime>::ExceedDebitValueHardCap,
 		);
 	});
 }
 
+#[test]
+fn set_debit_issuance_limits_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_debit_issuance_limits(
+				Origin::signed(5),
+				BTC,
+				Change::NewValue(Some(100)),
+				Change::NoChange,
+				Change::NoChange,
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_debit_issuance_limits(
+				Origin::signed(1),
+				LDOT,
+				Change::NewValue(Some(100)),
+				Change::NoChange,
+				Change::NoChange,
+			),
+			Error::<Runtime>::InvalidCollateralType
+		);
+
+		assert_ok!(CDPEngineModule::set_debit_issuance_limits(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(100)),
+			Change::NewValue(Some(1000)),
+			Change::NoChange,
+		));
+		let limits = IssuanceLimits {
+			per_block: Some(100),
+			per_epoch: Some(1000),
+			per_account: None,
+		};
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::DebitIssuanceLimitsUpdated(
+			BTC,
+			limits.clone(),
+		)));
+		assert_eq!(CDPEngineModule::debit_issuance_limits(BTC), limits);
+
+		assert_ok!(CDPEngineModule::set_debit_issuance_limits(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(None),
+			Change::NoChange,
+			Change::NewValue(Some(10)),
+		));
+		assert_eq!(
+			CDPEngineModule::debit_issuance_limits(BTC),
+			IssuanceLimits {
+				per_block: None,
+				per_epoch: Some(1000),
+				per_account: Some(10),
+			}
+		);
+	});
+}
+
+#[test]
+fn block_issuance_limit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_debit_issuance_limits(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(60)),
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 200, 500));
+		assert!(!System::events()
+			.iter()
+			.any(|record| record.event == Event::CDPEngineModule(crate::Event::BlockIssuanceLimitReached(BTC))));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 100));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::BlockIssuanceLimitReached(BTC)));
+		assert_eq!(
+			CDPEngineModule::debit_issuance(BTC),
+			IssuanceRecord {
+				block: 1,
+				block_issued: 60,
+				epoch: 0,
+				epoch_issued: 60,
+			}
+		);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&BOB, BTC, 100, 20),
+			Error::<Runtime>::ExceedBlockIssuanceLimit
+		);
+
+		// repaying debit is not limited
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, -100));
+
+		System::set_block_number(2);
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 20));
+		assert_eq!(CDPEngineModule::debit_issuance(BTC).block_issued, 2);
+	});
+}
+
+#[test]
+fn epoch_issuance_limit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_debit_issuance_limits(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(80)),
+			Change::NoChange,
+		));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 200, 500));
+		System::set_block_number(2);
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 300));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::EpochIssuanceLimitReached(BTC)));
+
+		System::set_block_number(3);
+		assert_noop!(
+			CDPEngineModule::adjust_position(&BOB, BTC, 0, 10),
+			Error::<Runtime>::ExceedEpochIssuanceLimit
+		);
+
+		// the next epoch starts at block 10
+		System::set_block_number(10);
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 0, 10));
+		assert_eq!(
+			CDPEngineModule::debit_issuance(BTC),
+			IssuanceRecord {
+				block: 10,
+				block_issued: 1,
+				epoch: 1,
+				epoch_issued: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn account_debit_ceiling_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_debit_issuance_limits(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(50)),
+		));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 400));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 0, 100));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::AccountDebitCeilingReached(
+			BTC, ALICE,
+		)));
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, BTC, 0, 10),
+			Error::<Runtime>::ExceedAccountDebitCeiling
+		);
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
+
+		// the transferred loan is limited by the ceiling of the receiver
+		assert_noop!(
+			LoansModule::transfer_loan(&BOB, &ALICE, BTC),
+			Error::<Runtime>::ExceedAccountDebitCeiling
+		);
+
+		// the issuance is not recorded without the per block and per epoch limits
+		assert_eq!(CDPEngineModule::debit_issuance(BTC), Default::default());
+	});
+}
+
 #[test]
 fn check_position_valid_failed_when_invalid_feed_price() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -302,6 +698,7 @@ fn check_position_valid_failed_when_invalid_feed_price() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		MockPriceSource::set_relative_price(None);
@@ -326,6 +723,7 @@ fn check_position_valid_failed_when_remain_debit_value_too_small() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 2, 10, true),
@@ -345,6 +743,7 @@ fn check_position_valid_ratio_below_liquidate_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::check_position_valid(BTC, 91, 500, true),
@@ -364,6 +763,7 @@ fn check_position_valid_ratio_below_required_ratio() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::check_position_valid(BTC, 89, 500, false));
 		assert_noop!(
@@ -384,6 +784,7 @@ fn adjust_position_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::adjust_position(&ALICE, ACA, 100, 500),
@@ -418,6 +819,7 @@ fn remain_debit_value_too_small_check() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert!(!CDPEngineModule::adjust_position(&ALICE, BTC, 0, -490).is_ok());
@@ -437,6 +839,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -455,6 +858,7 @@ fn liquidate_unsafe_cdp_by_collateral_auction() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
@@ -490,111 +894,735 @@ fn liquidate_unsafe_cdp_by_collateral_auction_when_limited_by_slippage() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(DEXModule::add_liquidity(
 			Origin::signed(CAROL),
 			BTC,
-			AUSD,
-			100,
-			121,
-			0,
-			false
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// pool is enough, but slippage limit the swap
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
+		assert_eq!(DEXModule::get_swap_supply_amount(&[BTC, AUSD], 60), Some(99));
+		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100), Some(60));
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_swap() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			121,
+			0,
+			false
+		));
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Exchange,
+		)));
+
+		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 901);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn partial_liquidate_unsafe_cdp_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 250, 1000));
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 750);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// the target ratio cannot be reached when it's below the collateral seized ratio
+		assert_eq!(
+			CDPEngineModule::calculate_partial_liquidation_debit(BTC, 250, 1000),
+			None
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(4, 1))),
+		));
+		assert_eq!(
+			CDPEngineModule::calculate_partial_liquidation_debit(BTC, 250, 1000),
+			Some(941)
+		);
+
+		// the remaining debit value would be dust
+		assert_eq!(
+			CDPEngineModule::calculate_partial_liquidation_debit(BTC, 242, 1000),
+			None
+		);
+
+		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			224,
+			94,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 94);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 750);
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 100);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 59);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 26);
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 26, 59), CDPStatus::Safe);
+	});
+}
+
+#[test]
+fn set_keeper_reward_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = KeeperRewardParams {
+			fixed: 2,
+			percentage: Rate::saturating_from_rational(1, 10),
+			max_penalty_share: Ratio::saturating_from_rational(1, 2),
+		};
+		assert_noop!(
+			CDPEngineModule::set_keeper_reward_params(Origin::signed(5), Some(params.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_keeper_reward_params(
+				Origin::signed(1),
+				Some(KeeperRewardParams {
+					max_penalty_share: Ratio::one(),
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidKeeperRewardParams
+		);
+
+		assert_ok!(CDPEngineModule::set_keeper_reward_params(
+			Origin::signed(1),
+			Some(params.clone())
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::KeeperRewardParamsUpdated(Some(
+			params.clone(),
+		))));
+		assert_eq!(CDPEngineModule::keeper_reward(), Some(params));
+
+		assert_ok!(CDPEngineModule::set_keeper_reward_params(Origin::signed(1), None));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::KeeperRewardParamsUpdated(None)));
+		assert_eq!(CDPEngineModule::keeper_reward(), None);
+	});
+}
+
+#[test]
+fn liquidate_by_keeper_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::KeeperRewardDisabled,
+		);
+		assert_ok!(CDPEngineModule::set_keeper_reward_params(
+			Origin::signed(1),
+			Some(KeeperRewardParams {
+				fixed: 2,
+				percentage: Rate::saturating_from_rational(1, 10),
+				max_penalty_share: Ratio::saturating_from_rational(1, 2),
+			})
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::signed(ALICE), BTC, ALICE),
+			Error::<Runtime>::CannotLiquidateSelf,
+		);
+		assert_noop!(
+			CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE));
+
+		// no penalty is collected before the collateral auction is done, no reward
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Auction,
+		)));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::KeeperRewarded(BOB, ALICE, 0)));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
+		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+	});
+}
+
+#[test]
+fn keeper_reward_limited_by_liquidation_penalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			vec![LiquidationStrategy::Contract, LiquidationStrategy::Auction]
+		));
+		assert_ok!(CDPEngineModule::register_liquidation_contract(
+			Origin::signed(1),
+			MockAddressMapping::get_or_create_evm_address(&CAROL)
+		));
+		MockLiquidationEvmBridge::set_repayment(60);
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::set_keeper_reward_params(
+			Origin::signed(1),
+			Some(KeeperRewardParams {
+				fixed: 100,
+				percentage: Rate::saturating_from_rational(1, 10),
+				max_penalty_share: Ratio::saturating_from_rational(1, 2),
+			})
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::max_value())),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::liquidate_by_keeper(Origin::signed(BOB), BTC, ALICE));
+
+		// reward is limited by half of the collected liquidation penalty 50 * 20%
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Contract,
+		)));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::KeeperRewarded(BOB, ALICE, 5)));
+		assert_eq!(Currencies::free_balance(AUSD, &BOB), 5);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 55);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
+	});
+}
+
+#[test]
+fn set_liquidation_strategies_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(
+			CDPEngineModule::get_liquidation_strategies(BTC),
+			vec![LiquidationStrategy::Exchange, LiquidationStrategy::Auction]
+		);
+
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(Origin::signed(5), BTC, vec![LiquidationStrategy::Auction]),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(Origin::signed(1), LDOT, vec![LiquidationStrategy::Auction]),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(
+				Origin::signed(1),
+				BTC,
+				vec![LiquidationStrategy::Auction, LiquidationStrategy::Auction]
+			),
+			Error::<Runtime>::DuplicatedLiquidationStrategy
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(
+				Origin::signed(1),
+				BTC,
+				vec![LiquidationStrategy::Auction, LiquidationStrategy::Contract]
+			),
+			Error::<Runtime>::AuctionNotLastResort
+		);
+		assert_noop!(
+			CDPEngineModule::set_liquidation_strategies(Origin::signed(1), BTC, vec![LiquidationStrategy::Contract]),
+			Error::<Runtime>::AuctionNotLastResort
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			vec![LiquidationStrategy::Contract, LiquidationStrategy::Auction]
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationStrategiesUpdated(
+			BTC,
+			vec![LiquidationStrategy::Contract, LiquidationStrategy::Auction],
+		)));
+		assert_eq!(
+			CDPEngineModule::get_liquidation_strategies(BTC),
+			vec![LiquidationStrategy::Contract, LiquidationStrategy::Auction]
+		);
+
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			vec![]
+		));
+		assert_eq!(
+			CDPEngineModule::get_liquidation_strategies(BTC),
+			vec![LiquidationStrategy::Exchange, LiquidationStrategy::Auction]
+		);
+	});
+}
+
+#[test]
+fn register_and_deregister_liquidation_contract_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract_1 = EvmAddress::from_low_u64_be(1);
+		let contract_2 = EvmAddress::from_low_u64_be(2);
+		let contract_3 = EvmAddress::from_low_u64_be(3);
+
+		assert_noop!(
+			CDPEngineModule::register_liquidation_contract(Origin::signed(5), contract_1),
+			BadOrigin
+		);
+		assert_ok!(CDPEngineModule::register_liquidation_contract(
+			Origin::signed(1),
+			contract_1
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationContractRegistered(
+			contract_1,
+		)));
+		assert_noop!(
+			CDPEngineModule::register_liquidation_contract(Origin::signed(1), contract_1),
+			Error::<Runtime>::LiquidationContractAlreadyRegistered
+		);
+		assert_ok!(CDPEngineModule::register_liquidation_contract(
+			Origin::signed(1),
+			contract_2
+		));
+		assert_noop!(
+			CDPEngineModule::register_liquidation_contract(Origin::signed(1), contract_3),
+			Error::<Runtime>::TooManyLiquidationContracts
+		);
+		assert_eq!(CDPEngineModule::liquidation_contracts(), vec![contract_1, contract_2]);
+
+		assert_noop!(
+			CDPEngineModule::deregister_liquidation_contract(Origin::signed(5), contract_1),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::deregister_liquidation_contract(Origin::signed(1), contract_3),
+			Error::<Runtime>::LiquidationContractNotRegistered
+		);
+		assert_ok!(CDPEngineModule::deregister_liquidation_contract(
+			Origin::signed(1),
+			contract_1
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidationContractDeregistered(
+			contract_1,
+		)));
+		assert_eq!(CDPEngineModule::liquidation_contracts(), vec![contract_2]);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cdp_by_contract() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = MockAddressMapping::get_or_create_evm_address(&CAROL);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_liquidation_strategies(
+			Origin::signed(1),
+			BTC,
+			vec![LiquidationStrategy::Contract, LiquidationStrategy::Auction]
+		));
+		assert_ok!(CDPEngineModule::register_liquidation_contract(
+			Origin::signed(1),
+			contract
 		));
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
-
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
-
+		assert_ok!(CDPEngineModule::adjust_position(&BOB, BTC, 100, 500));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
 			Change::NoChange,
-			Change::NewValue(Some(Ratio::max_value())),
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 1))),
+			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
 		));
 
-		// pool is enough, but slippage limit the swap
-		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
-		assert_eq!(DEXModule::get_swap_supply_amount(&[BTC, AUSD], 60), Some(99));
-		assert_eq!(DEXModule::get_swap_target_amount(&[BTC, AUSD], 100), Some(60));
-		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
+		// the repayment of contract is below the target, fallback to auction
+		MockLiquidationEvmBridge::set_repayment(59);
+		assert_ok!(CDPEngineModule::liquidate(Origin::none(), BTC, BOB));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
 			BTC,
-			ALICE,
+			BOB,
 			100,
 			50,
 			LiquidationStrategy::Auction,
 		)));
-
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
 		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 10000);
+
+		MockLiquidationEvmBridge::set_repayment(60);
+		assert_ok!(CDPEngineModule::liquidate(Origin::none(), BTC, ALICE));
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::LiquidateByContract(
+			BTC, contract, 60, 60,
+		)));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
+			BTC,
+			ALICE,
+			100,
+			50,
+			LiquidationStrategy::Contract,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 60);
+		assert_eq!(Currencies::free_balance(AUSD, &LiquidationContractsAccount::get()), 0);
+		// the contract only gets the collateral worth the target by the price of oracle, the
+		// remaining collateral is refunded to the CDP owner
+		assert_eq!(Currencies::free_balance(BTC, &CAROL), 10060);
+		assert_eq!(Currencies::free_balance(AUSD, &CAROL), 9940);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 940);
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
 	});
 }
 
+fn setup_cross_collateral_params() {
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		Origin::signed(1),
+		BTC,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+		Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
+		Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+		Change::NewValue(10000),
+		Change::NoChange,
+	));
+	assert_ok!(CDPEngineModule::set_collateral_params(
+		Origin::signed(1),
+		DOT,
+		Change::NoChange,
+		Change::NewValue(Some(Ratio::saturating_from_rational(2, 1))),
+		Change::NewValue(Some(Rate::saturating_from_rational(1, 10))),
+		Change::NoChange,
+		Change::NewValue(10000),
+		Change::NoChange,
+	));
+	MockPriceSource::set_dot_relative_price(Some(Price::saturating_from_integer(2)));
+}
+
 #[test]
-fn liquidate_unsafe_cdp_by_swap() {
+fn check_cross_collateral_position_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(CDPEngineModule::set_collateral_params(
-			Origin::signed(1),
-			BTC,
-			Change::NewValue(Some(Rate::saturating_from_rational(1, 100000))),
-			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
-			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
-			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
-			Change::NewValue(10000),
-		));
-		assert_ok!(DEXModule::add_liquidity(
-			Origin::signed(CAROL),
-			BTC,
-			AUSD,
-			100,
-			121,
-			0,
-			false
-		));
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (100, 121));
+		setup_cross_collateral_params();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 300, 500));
 
-		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 500);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
+		// single position can't issue debit without collateral
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, DOT, 0, 1000),
+			Error::<Runtime>::BelowLiquidationRatio
+		);
+
+		assert_ok!(CDPEngineModule::set_cross_collateral(&ALICE, true));
+		assert!(LoansModule::is_cross_collateral(&ALICE));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 0, 1000));
+		assert_eq!(
+			CDPEngineModule::get_cross_collateral_debit_limits(&ALICE),
+			Ok((150, 200, 166))
+		);
+		assert_eq!(
+			CDPEngineModule::check_cross_collateral_cdp_status(&ALICE),
+			CDPStatus::Safe
+		);
+
+		// the blended required collateral ratio is limited
+		assert_noop!(
+			CDPEngineModule::adjust_position(&ALICE, DOT, 0, 200),
+			Error::<Runtime>::BelowRequiredCollateralRatio
+		);
+
+		// can't be disabled when the positions are invalid as independent CDPs
+		assert_noop!(
+			CDPEngineModule::set_cross_collateral(&ALICE, false),
+			Error::<Runtime>::BelowLiquidationRatio
+		);
+
+		// the position of BTC is safe, but the blended one is unsafe
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
+		assert_eq!(CDPEngineModule::check_cdp_status(BTC, 300, 500), CDPStatus::Safe);
+		assert_eq!(
+			CDPEngineModule::check_cross_collateral_cdp_status(&ALICE),
+			CDPStatus::Unsafe
+		);
+		assert_eq!(CDPEngineModule::check_position_status(&ALICE, DOT), CDPStatus::Unsafe);
+
+		MockPriceSource::set_relative_price(None);
+		assert_eq!(
+			CDPEngineModule::check_position_status(&ALICE, BTC),
+			CDPStatus::ChecksFailed(Error::<Runtime>::InvalidFeedPrice.into())
+		);
+	});
+}
 
+#[test]
+fn set_cross_collateral_failed_when_below_required_ratio() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_cross_collateral_params();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
 			BTC,
 			Change::NoChange,
-			Change::NewValue(Some(Ratio::max_value())),
 			Change::NoChange,
 			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(5, 2))),
+			Change::NoChange,
 			Change::NoChange,
 		));
 
-		assert_ok!(CDPEngineModule::liquidate_unsafe_cdp(ALICE, BTC));
-		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateUnsafeCDP(
-			BTC,
+		assert_noop!(
+			CDPEngineModule::set_cross_collateral(&ALICE, true),
+			Error::<Runtime>::BelowRequiredCollateralRatio
+		);
+		assert!(!LoansModule::is_cross_collateral(&ALICE));
+	});
+}
+
+#[test]
+fn set_cross_collateral_liquidation_priority_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPEngineModule::set_cross_collateral_liquidation_priority(Origin::signed(5), vec![DOT]),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_collateral_liquidation_priority(Origin::signed(1), vec![AUSD]),
+			Error::<Runtime>::InvalidCollateralType
+		);
+		assert_noop!(
+			CDPEngineModule::set_cross_collateral_liquidation_priority(Origin::signed(1), vec![DOT, DOT]),
+			Error::<Runtime>::DuplicatedCollateralType
+		);
+		assert_eq!(
+			CDPEngineModule::get_cross_collateral_liquidation_order(),
+			vec![BTC, DOT]
+		);
+
+		assert_ok!(CDPEngineModule::set_cross_collateral_liquidation_priority(
+			Origin::signed(1),
+			vec![DOT]
+		));
+		System::assert_last_event(Event::CDPEngineModule(
+			crate::Event::CrossCollateralLiquidationPriorityUpdated(vec![DOT]),
+		));
+		assert_eq!(CDPEngineModule::cross_collateral_liquidation_priority(), vec![DOT]);
+		assert_eq!(
+			CDPEngineModule::get_cross_collateral_liquidation_order(),
+			vec![DOT, BTC]
+		);
+	});
+}
+
+#[test]
+fn liquidate_unsafe_cross_collateral_cdp_by_priority() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		setup_cross_collateral_params();
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
+		assert_ok!(CDPEngineModule::adjust_position(&ALICE, DOT, 100, 1000));
+		assert_ok!(CDPEngineModule::set_cross_collateral(&ALICE, true));
+		assert_ok!(CDPEngineModule::set_cross_collateral_liquidation_priority(
+			Origin::signed(1),
+			vec![DOT]
+		));
+		assert_noop!(
+			CDPEngineModule::liquidate(Origin::none(), BTC, ALICE),
+			Error::<Runtime>::MustBeUnsafe,
+		);
+
+		MockPriceSource::set_dot_relative_price(Some(Price::one()));
+		assert_ok!(CDPEngineModule::liquidate(Origin::none(), BTC, ALICE));
+
+		// target is 50 * 1.2 + 100 * 1.1, seize DOT first
+		System::assert_has_event(Event::CDPEngineModule(crate::Event::LiquidateCrossCollateralCDP(
 			ALICE,
+			DOT,
 			100,
-			50,
-			LiquidationStrategy::Exchange,
+			100,
+			LiquidationStrategy::Auction,
 		)));
-
-		assert_eq!(DEXModule::get_liquidity_pool(BTC, AUSD), (199, 61));
-		assert_eq!(CDPTreasuryModule::debit_pool(), 50);
-		assert_eq!(Currencies::free_balance(BTC, &ALICE), 901);
-		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 50);
-		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
-		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 0);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::LiquidateCrossCollateralCDP(
+			ALICE,
+			BTC,
+			100,
+			70,
+			LiquidationStrategy::Auction,
+		)));
+		assert_eq!(CDPTreasuryModule::debit_pool(), 150);
+		assert_eq!(
+			LoansModule::positions(BTC, ALICE),
+			Position {
+				collateral: 0,
+				debit: 0
+			}
+		);
+		assert_eq!(
+			LoansModule::positions(DOT, ALICE),
+			Position {
+				collateral: 0,
+				debit: 0
+			}
+		);
+		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
+		assert_eq!(Currencies::free_balance(DOT, &ALICE), 900);
 	});
 }
 
@@ -625,6 +1653,7 @@ fn get_interest_rate_per_sec_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_eq!(
 			CDPEngineModule::get_interest_rate_per_sec(BTC),
@@ -683,6 +1712,7 @@ fn accumulate_interest_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::set_collateral_params(
 			Origin::signed(1),
@@ -692,6 +1722,7 @@ fn accumulate_interest_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		CDPEngineModule::accumulate_interest(1, 0);
@@ -745,6 +1776,171 @@ fn accumulate_interest_work() {
 	});
 }
 
+fn stability_fee_controller_params() -> StabilityFeeControllerParams {
+	StabilityFeeControllerParams {
+		reference_currency_id: BTC,
+		twap_window: 10,
+		tolerance: Ratio::saturating_from_rational(5, 100),
+		sensitivity: Rate::saturating_from_rational(1, 100_000_000),
+		max_adjustment: Rate::saturating_from_rational(1, 1_000_000_000),
+		min_rate: Rate::zero(),
+		max_rate: Rate::saturating_from_rational(5, 1_000_000_000),
+	}
+}
+
+#[test]
+fn set_stability_fee_controller_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = stability_fee_controller_params();
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(Origin::signed(5), Some(params.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				Origin::signed(1),
+				Some(StabilityFeeControllerParams {
+					reference_currency_id: AUSD,
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				Origin::signed(1),
+				Some(StabilityFeeControllerParams {
+					twap_window: 0,
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+		assert_noop!(
+			CDPEngineModule::set_stability_fee_controller(
+				Origin::signed(1),
+				Some(StabilityFeeControllerParams {
+					min_rate: Rate::one(),
+					..params.clone()
+				})
+			),
+			Error::<Runtime>::InvalidStabilityFeeControllerParams
+		);
+
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			Origin::signed(1),
+			Some(params.clone())
+		));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::StabilityFeeControllerUpdated(
+			Some(params.clone()),
+		)));
+		assert_eq!(CDPEngineModule::stability_fee_controller(), Some(params));
+
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(Origin::signed(1), None));
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::StabilityFeeControllerUpdated(
+			None,
+		)));
+		assert_eq!(CDPEngineModule::stability_fee_controller(), None);
+	});
+}
+
+#[test]
+fn adjust_global_interest_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(DEXModule::add_liquidity(
+			Origin::signed(CAROL),
+			BTC,
+			AUSD,
+			100,
+			125,
+			0,
+			false
+		));
+		assert_ok!(CDPEngineModule::set_stability_fee_controller(
+			Origin::signed(1),
+			Some(stability_fee_controller_params())
+		));
+
+		// TWAP is not available
+		System::set_block_number(10);
+		CDPEngineModule::on_initialize(10);
+		assert_eq!(CDPEngineModule::get_stable_currency_market_price(BTC, 10), None);
+		assert_eq!(CDPEngineModule::global_interest_rate_per_sec(), Rate::zero());
+
+		// price is below the peg, raise the rate limited by max adjustment
+		System::set_block_number(20);
+		CDPEngineModule::on_initialize(20);
+		assert_eq!(
+			CDPEngineModule::get_stable_currency_market_price(BTC, 10),
+			Some(Price::saturating_from_rational(4, 5))
+		);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(1, 1_000_000_000)
+		);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalInterestRatePerSecAdjusted(
+			Price::saturating_from_rational(4, 5),
+			Rate::zero(),
+			Rate::saturating_from_rational(1, 1_000_000_000),
+		)));
+
+		// only adjust at the end of epoch
+		System::set_block_number(25);
+		CDPEngineModule::on_initialize(25);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(1, 1_000_000_000)
+		);
+
+		// limited by max rate
+		assert_ok!(CDPEngineModule::set_global_params(
+			Origin::signed(1),
+			Rate::saturating_from_rational(5, 1_000_000_000)
+		));
+		System::set_block_number(30);
+		CDPEngineModule::on_initialize(30);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(5, 1_000_000_000)
+		);
+
+		// price is above the peg, lower the rate
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_integer(2)));
+		System::set_block_number(40);
+		CDPEngineModule::on_initialize(40);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(4, 1_000_000_000)
+		);
+		System::assert_last_event(Event::CDPEngineModule(crate::Event::GlobalInterestRatePerSecAdjusted(
+			Price::saturating_from_rational(8, 5),
+			Rate::saturating_from_rational(5, 1_000_000_000),
+			Rate::saturating_from_rational(4, 1_000_000_000),
+		)));
+
+		// price is within the tolerance
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(5, 4)));
+		System::set_block_number(50);
+		CDPEngineModule::on_initialize(50);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(4, 1_000_000_000)
+		);
+
+		// not adjust after shutdown
+		MockPriceSource::set_relative_price(Some(Price::saturating_from_rational(1, 2)));
+		mock_shutdown();
+		System::set_block_number(60);
+		CDPEngineModule::on_initialize(60);
+		assert_eq!(
+			CDPEngineModule::global_interest_rate_per_sec(),
+			Rate::saturating_from_rational(4, 1_000_000_000)
+		);
+	});
+}
+
 #[test]
 fn settle_cdp_has_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -757,6 +1953,7 @@ fn settle_cdp_has_debit_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
 		assert_eq!(Currencies::free_balance(BTC, &ALICE), 900);
@@ -804,6 +2001,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 0));
@@ -833,6 +2031,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_noop!(
 			CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None),
@@ -847,6 +2046,7 @@ fn close_cdp_has_debit_by_dex_work() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 
 		assert_noop!(
@@ -906,6 +2106,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_eq!(DEXModule::get_liquidity_pool(BTC, ACA), (100, 1000));
@@ -926,6 +2127,7 @@ fn close_cdp_has_debit_by_swap_on_alternative_path() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		assert_ok!(CDPEngineModule::close_cdp_has_debit_by_dex(ALICE, BTC, 100, None));
 		System::assert_last_event(Event::CDPEngineModule(crate::Event::CloseCDPInDebitByDEX(
@@ -963,6 +2165,7 @@ fn offchain_worker_works_cdp() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		// offchain worker will not liquidate alice
@@ -989,6 +2192,7 @@ fn offchain_worker_works_cdp() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(System::block_number() + collateral_currencies_num);
 
@@ -1051,6 +2255,7 @@ fn offchain_worker_iteration_limit_works() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 
 		assert_ok!(CDPEngineModule::adjust_position(&ALICE, BTC, 100, 500));
@@ -1064,6 +2269,7 @@ fn offchain_worker_iteration_limit_works() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(2);
 		let tx = pool_state.write().transactions.pop().unwrap();
@@ -1117,6 +2323,7 @@ fn offchain_default_max_iterator_works() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		// checks that max iterations is stored as none
 		assert!(offchain
@@ -1137,6 +2344,7 @@ fn offchain_default_max_iterator_works() {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		));
 		run_to_block_offchain(2);
 		// should only run 1000 iterations stopping due to DEFAULT_MAX_ITERATIONS
@@ -53,6 +53,15 @@ pub trait WeightInfo {
 	fn liquidate_by_auction() -> Weight;
 	fn liquidate_by_dex() -> Weight;
 	fn settle() -> Weight;
+	fn set_cross_collateral_liquidation_priority() -> Weight;
+	fn set_stability_fee_controller() -> Weight;
+	fn adjust_global_interest_rate() -> Weight;
+	fn set_liquidation_strategies() -> Weight;
+	fn register_liquidation_contract() -> Weight;
+	fn deregister_liquidation_contract() -> Weight;
+	fn set_keeper_reward_params() -> Weight;
+	fn liquidate_by_keeper() -> Weight;
+	fn set_debit_issuance_limits() -> Weight;
 }
 
 /// Weights for module_cdp_engine using the Acala node and recommended hardware.
@@ -88,6 +97,47 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn set_cross_collateral_liquidation_priority() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn adjust_global_interest_rate() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_strategies() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn register_liquidation_contract() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_liquidation_contract() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_keeper_reward_params() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn liquidate_by_keeper() -> Weight {
+		(268_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(32 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	fn set_debit_issuance_limits() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -122,4 +172,45 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
+	fn set_cross_collateral_liquidation_priority() -> Weight {
+		(12_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn adjust_global_interest_rate() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_liquidation_strategies() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn register_liquidation_contract() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_liquidation_contract() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn set_keeper_reward_params() -> Weight {
+		(13_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn liquidate_by_keeper() -> Weight {
+		(268_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(32 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(17 as Weight))
+	}
+	fn set_debit_issuance_limits() -> Weight {
+		(17_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
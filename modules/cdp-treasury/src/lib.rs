@@ -24,19 +24,23 @@
 //! CDPs, and handle excessive surplus or debits timely in order to keep the
 //! system healthy with low risk. It's the only entry for issuing/burning stable
 //! coin for whole system.
+//!
+//! At the end of each block, surplus above the surplus buffer is sold by
+//! surplus auctions for native token, and debit not offset by surplus is
+//! covered by debit auctions which mint native token.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use orml_traits::{Change, MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
 };
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio};
+use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, EmergencyShutdown, Ratio};
 
 mod mock;
 mod tests;
@@ -45,6 +49,8 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+type ChangeBalance = Change<Balance>;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -85,6 +91,10 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// Emergency shutdown, no surplus or debit auction is created after
+		/// shutdown.
+		type EmergencyShutdown: EmergencyShutdown;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -107,6 +117,17 @@ pub mod module {
 		/// The expected amount size for per lot collateral auction of specific
 		/// collateral type updated. \[collateral_type, new_size\]
 		ExpectedCollateralAuctionSizeUpdated(CurrencyId, Balance),
+		/// The buffer size of surplus pool updated. \[new_size\]
+		SurplusBufferSizeUpdated(Balance),
+		/// The fixed amount of stable currency for sale per surplus auction
+		/// updated. \[new_size\]
+		SurplusAuctionFixedSizeUpdated(Balance),
+		/// The fixed amount of stable currency to get per debit auction
+		/// updated. \[new_size\]
+		DebitAuctionFixedSizeUpdated(Balance),
+		/// The initial amount of native currency for sale per debit auction
+		/// updated. \[new_amount\]
+		InitialAmountPerDebitAuctionUpdated(Balance),
 	}
 
 	/// The expected amount size for per lot collateral auction of specific
@@ -125,6 +146,37 @@ pub mod module {
 	#[pallet::getter(fn debit_pool)]
 	pub type DebitPool<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// The amount of surplus kept in the surplus pool, only the surplus above
+	/// it is sold by surplus auctions.
+	///
+	/// SurplusBufferSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_buffer_size)]
+	pub type SurplusBufferSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of stable currency for sale per surplus auction. If
+	/// zero, no surplus auction is created.
+	///
+	/// SurplusAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn surplus_auction_fixed_size)]
+	pub type SurplusAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The fixed amount of stable currency to get per debit auction. If zero,
+	/// no debit auction is created.
+	///
+	/// DebitAuctionFixedSize: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn debit_auction_fixed_size)]
+	pub type DebitAuctionFixedSize<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The initial amount of native currency for sale per debit auction.
+	///
+	/// InitialAmountPerDebitAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn initial_amount_per_debit_auction)]
+	pub type InitialAmountPerDebitAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub expected_collateral_auction_size: Vec<(CurrencyId, Balance)>,
@@ -155,10 +207,21 @@ pub mod module {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Reserve the weight of creating surplus and debit auctions on block
+		/// end
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			T::WeightInfo::on_finalize(T::MaxAuctionsCount::get())
+		}
+
 		/// Handle excessive surplus or debits of system when block end
 		fn on_finalize(_now: T::BlockNumber) {
 			// offset the same amount between debit pool and surplus pool
 			Self::offset_surplus_and_debit();
+
+			// create surplus and debit auctions for the rest
+			if !T::EmergencyShutdown::is_shutdown() {
+				Self::create_surplus_and_debit_auctions();
+			}
 		}
 	}
 
@@ -168,6 +231,11 @@ pub mod module {
 		#[transactional]
 		pub fn extract_surplus_to_treasury(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
+			// surplus in auction has been sold, keep it for the winner
+			ensure!(
+				amount <= Self::surplus_pool_not_in_auction(),
+				Error::<T>::SurplusPoolNotEnough
+			);
 			T::Currency::transfer(
 				T::GetStableCurrencyId::get(),
 				&Self::account_id(),
@@ -216,6 +284,43 @@ pub mod module {
 			Self::deposit_event(Event::ExpectedCollateralAuctionSizeUpdated(currency_id, size));
 			Ok(())
 		}
+
+		/// Update parameters related to surplus and debit auction
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `surplus_buffer_size`: the surplus kept in the surplus pool
+		/// - `surplus_auction_fixed_size`: stable currency amount per surplus auction
+		/// - `debit_auction_fixed_size`: stable currency amount per debit auction
+		/// - `initial_amount_per_debit_auction`: initial native currency amount per debit auction
+		#[pallet::weight((T::WeightInfo::set_debit_and_surplus_handle_params(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_debit_and_surplus_handle_params(
+			origin: OriginFor<T>,
+			surplus_buffer_size: ChangeBalance,
+			surplus_auction_fixed_size: ChangeBalance,
+			debit_auction_fixed_size: ChangeBalance,
+			initial_amount_per_debit_auction: ChangeBalance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			if let Change::NewValue(amount) = surplus_buffer_size {
+				SurplusBufferSize::<T>::put(amount);
+				Self::deposit_event(Event::SurplusBufferSizeUpdated(amount));
+			}
+			if let Change::NewValue(amount) = surplus_auction_fixed_size {
+				SurplusAuctionFixedSize::<T>::put(amount);
+				Self::deposit_event(Event::SurplusAuctionFixedSizeUpdated(amount));
+			}
+			if let Change::NewValue(amount) = debit_auction_fixed_size {
+				DebitAuctionFixedSize::<T>::put(amount);
+				Self::deposit_event(Event::DebitAuctionFixedSizeUpdated(amount));
+			}
+			if let Change::NewValue(amount) = initial_amount_per_debit_auction {
+				InitialAmountPerDebitAuction::<T>::put(amount);
+				Self::deposit_event(Event::InitialAmountPerDebitAuctionUpdated(amount));
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -241,8 +346,20 @@ impl<T: Config> Pallet<T> {
 			.saturating_sub(T::AuctionManagerHandler::get_total_collateral_in_auction(currency_id))
 	}
 
+	/// Get surplus not in surplus auction
+	pub fn surplus_pool_not_in_auction() -> Balance {
+		Self::surplus_pool().saturating_sub(T::AuctionManagerHandler::get_total_surplus_in_auction())
+	}
+
+	/// Get debit not in debit auction
+	pub fn debit_pool_not_in_auction() -> Balance {
+		Self::debit_pool().saturating_sub(T::AuctionManagerHandler::get_total_debit_in_auction())
+	}
+
 	fn offset_surplus_and_debit() {
-		let offset_amount = sp_std::cmp::min(Self::debit_pool(), Self::surplus_pool());
+		// surplus in auction has been sold, keep it for the winner. debit in auction will be
+		// offset by the payment of the winner after the auction ends.
+		let offset_amount = sp_std::cmp::min(Self::debit_pool_not_in_auction(), Self::surplus_pool_not_in_auction());
 
 		// Burn the amount that is equal to offset amount of stable currency.
 		if !offset_amount.is_zero() {
@@ -265,6 +382,54 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 	}
+
+	/// Create surplus auctions for the surplus above buffer and debit
+	/// auctions for the debit not in auction, at most `MaxAuctionsCount`
+	/// lots in total.
+	fn create_surplus_and_debit_auctions() {
+		let max_auctions_count = T::MaxAuctionsCount::get();
+		let mut created_lots: u32 = 0;
+
+		let surplus_auction_fixed_size = Self::surplus_auction_fixed_size();
+		if !surplus_auction_fixed_size.is_zero() {
+			let mut remain_surplus = Self::surplus_pool_not_in_auction();
+			let threshold = Self::surplus_buffer_size().saturating_add(surplus_auction_fixed_size);
+
+			while remain_surplus >= threshold && created_lots < max_auctions_count {
+				if let Err(e) = T::AuctionManagerHandler::new_surplus_auction(surplus_auction_fixed_size) {
+					log::warn!(
+						target: "cdp-treasury",
+						"new_surplus_auction: failed to create surplus auction of {:?}: {:?}. \
+						This is unexpected but should be safe",
+						surplus_auction_fixed_size, e
+					);
+					break;
+				}
+				remain_surplus = remain_surplus.saturating_sub(surplus_auction_fixed_size);
+				created_lots = created_lots.saturating_add(1);
+			}
+		}
+
+		let debit_auction_fixed_size = Self::debit_auction_fixed_size();
+		if !debit_auction_fixed_size.is_zero() {
+			let mut remain_debit = Self::debit_pool_not_in_auction();
+			let initial_amount = Self::initial_amount_per_debit_auction();
+
+			while remain_debit >= debit_auction_fixed_size && created_lots < max_auctions_count {
+				if let Err(e) = T::AuctionManagerHandler::new_debit_auction(initial_amount, debit_auction_fixed_size) {
+					log::warn!(
+						target: "cdp-treasury",
+						"new_debit_auction: failed to create debit auction of {:?}: {:?}. \
+						This is unexpected but should be safe",
+						debit_auction_fixed_size, e
+					);
+					break;
+				}
+				remain_debit = remain_debit.saturating_sub(debit_auction_fixed_size);
+				created_lots = created_lots.saturating_add(1);
+			}
+		}
+	}
 }
 
 impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
@@ -275,6 +440,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		Self::surplus_pool()
 	}
 
+	fn get_surplus_pool_not_in_auction() -> Self::Balance {
+		Self::surplus_pool_not_in_auction()
+	}
+
 	fn get_debit_pool() -> Self::Balance {
 		Self::debit_pool()
 	}
@@ -317,6 +486,10 @@ impl<T: Config> CDPTreasury<T::AccountId> for Pallet<T> {
 		T::Currency::transfer(T::GetStableCurrencyId::get(), from, &Self::account_id(), surplus)
 	}
 
+	fn withdraw_surplus(to: &T::AccountId, surplus: Self::Balance) -> DispatchResult {
+		T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), to, surplus)
+	}
+
 	fn deposit_collateral(from: &T::AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult {
 		T::Currency::transfer(currency_id, from, &Self::account_id(), amount)
 	}
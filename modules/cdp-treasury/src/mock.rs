@@ -128,7 +128,11 @@ impl orml_currencies::Config for Runtime {
 parameter_types! {
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const GetExchangeFee: (u32, u32) = (0, 100);
+	pub DEXProtocolFeeShare: Ratio = Default::default();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10;
+	pub const MaxPriceObservations: u32 = 10;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 10;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, BTC).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -141,17 +145,27 @@ impl module_dex::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = TreasuryAccount;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type DEXIncentives = ();
 	type WeightInfo = ();
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 thread_local! {
 	pub static TOTAL_COLLATERAL_AUCTION: RefCell<u32> = RefCell::new(0);
 	pub static TOTAL_COLLATERAL_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static TOTAL_DEBIT_AUCTION: RefCell<u32> = RefCell::new(0);
+	pub static TOTAL_DEBIT_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
+	pub static TOTAL_SURPLUS_AUCTION: RefCell<u32> = RefCell::new(0);
+	pub static TOTAL_SURPLUS_IN_AUCTION: RefCell<Balance> = RefCell::new(0);
 }
 
 pub struct MockAuctionManager;
@@ -171,6 +185,18 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_amount: Self::Balance, fix: Self::Balance) -> DispatchResult {
+		TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut() += 1);
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut() += fix);
+		Ok(())
+	}
+
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult {
+		TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut() += 1);
+		TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut() += amount);
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -182,6 +208,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_target_in_auction() -> Self::Balance {
 		unimplemented!()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		TOTAL_DEBIT_IN_AUCTION.with(|v| *v.borrow_mut())
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		TOTAL_SURPLUS_IN_AUCTION.with(|v| *v.borrow_mut())
+	}
 }
 
 ord_parameter_types! {
@@ -198,6 +232,17 @@ thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
 }
 
+pub fn mock_shutdown() {
+	IS_SHUTDOWN.with(|v| *v.borrow_mut() = true)
+}
+
+pub struct MockEmergencyShutdown;
+impl EmergencyShutdown for MockEmergencyShutdown {
+	fn is_shutdown() -> bool {
+		IS_SHUTDOWN.with(|v| *v.borrow_mut())
+	}
+}
+
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
@@ -208,6 +253,7 @@ impl Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -139,6 +139,21 @@ fn deposit_surplus_work() {
 	});
 }
 
+#[test]
+fn withdraw_surplus_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(500));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1000);
+		assert_noop!(
+			CDPTreasuryModule::withdraw_surplus(&ALICE, 501),
+			orml_tokens::Error::<Runtime>::BalanceTooLow,
+		);
+		assert_ok!(CDPTreasuryModule::withdraw_surplus(&ALICE, 300));
+		assert_eq!(Currencies::free_balance(AUSD, &ALICE), 1300);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 200);
+	});
+}
+
 #[test]
 fn deposit_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -427,5 +442,150 @@ fn extract_surplus_to_treasury_work() {
 		assert_eq!(CDPTreasuryModule::surplus_pool(), 800);
 		assert_eq!(Currencies::free_balance(AUSD, &CDPTreasuryModule::account_id()), 800);
 		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 200);
+
+		// surplus in auction can not be extracted
+		assert_ok!(MockAuctionManager::new_surplus_auction(700));
+		assert_noop!(
+			CDPTreasuryModule::extract_surplus_to_treasury(Origin::signed(1), 101),
+			Error::<Runtime>::SurplusPoolNotEnough
+		);
+		assert_ok!(CDPTreasuryModule::extract_surplus_to_treasury(Origin::signed(1), 100));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 700);
+		assert_eq!(CDPTreasuryModule::surplus_pool_not_in_auction(), 0);
+	});
+}
+
+#[test]
+fn set_debit_and_surplus_handle_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			CDPTreasuryModule::set_debit_and_surplus_handle_params(
+				Origin::signed(5),
+				Change::NewValue(100),
+				Change::NewValue(200),
+				Change::NewValue(300),
+				Change::NewValue(400),
+			),
+			BadOrigin
+		);
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NewValue(100),
+			Change::NewValue(200),
+			Change::NewValue(300),
+			Change::NewValue(400),
+		));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusBufferSizeUpdated(100)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::SurplusAuctionFixedSizeUpdated(
+			200,
+		)));
+		System::assert_has_event(Event::CDPTreasuryModule(crate::Event::DebitAuctionFixedSizeUpdated(
+			300,
+		)));
+		System::assert_last_event(Event::CDPTreasuryModule(
+			crate::Event::InitialAmountPerDebitAuctionUpdated(400),
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_auction_fixed_size(), 200);
+		assert_eq!(CDPTreasuryModule::debit_auction_fixed_size(), 300);
+		assert_eq!(CDPTreasuryModule::initial_amount_per_debit_auction(), 400);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NewValue(0),
+			Change::NoChange,
+			Change::NoChange,
+		));
+		assert_eq!(CDPTreasuryModule::surplus_buffer_size(), 100);
+		assert_eq!(CDPTreasuryModule::surplus_auction_fixed_size(), 0);
+		assert_eq!(CDPTreasuryModule::debit_auction_fixed_size(), 300);
+	});
+}
+
+#[test]
+fn create_surplus_auctions_on_finalize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+		CDPTreasuryModule::on_finalize(1);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 0);
+
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NewValue(100),
+			Change::NewValue(100),
+			Change::NoChange,
+			Change::NoChange,
+		));
+
+		// capped by MaxAuctionsCount
+		CDPTreasuryModule::on_finalize(2);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 5);
+		assert_eq!(MockAuctionManager::get_total_surplus_in_auction(), 500);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 1000);
+		assert_eq!(CDPTreasuryModule::surplus_pool_not_in_auction(), 500);
+
+		// keep the surplus buffer
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 9);
+		assert_eq!(MockAuctionManager::get_total_surplus_in_auction(), 900);
+		assert_eq!(CDPTreasuryModule::surplus_pool_not_in_auction(), 100);
+
+		// surplus in auction is not offset by debit
+		assert_ok!(CDPTreasuryModule::on_system_debit(300));
+		CDPTreasuryModule::on_finalize(4);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 900);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 200);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 9);
+	});
+}
+
+#[test]
+fn create_debit_auctions_on_finalize_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(100),
+			Change::NewValue(1000),
+		));
+		assert_ok!(CDPTreasuryModule::on_system_surplus(50));
+		assert_ok!(CDPTreasuryModule::on_system_debit(400));
+
+		CDPTreasuryModule::on_finalize(1);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 0);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 350);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 3);
+		assert_eq!(MockAuctionManager::get_total_debit_in_auction(), 300);
+
+		CDPTreasuryModule::on_finalize(2);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 3);
+
+		// debit in auction is not offset by surplus
+		assert_ok!(CDPTreasuryModule::on_system_surplus(400));
+		CDPTreasuryModule::on_finalize(3);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 350);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 300);
+		assert_eq!(CDPTreasuryModule::debit_pool_not_in_auction(), 0);
+	});
+}
+
+#[test]
+fn no_surplus_and_debit_auction_after_shutdown() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::set_debit_and_surplus_handle_params(
+			Origin::signed(1),
+			Change::NewValue(0),
+			Change::NewValue(100),
+			Change::NewValue(100),
+			Change::NewValue(1000),
+		));
+		assert_ok!(CDPTreasuryModule::on_system_debit(300));
+		mock_shutdown();
+		CDPTreasuryModule::on_finalize(1);
+		assert_eq!(TOTAL_DEBIT_AUCTION.with(|v| *v.borrow_mut()), 0);
+		assert_eq!(TOTAL_SURPLUS_AUCTION.with(|v| *v.borrow_mut()), 0);
 	});
 }
@@ -51,6 +51,8 @@ pub trait WeightInfo {
 	fn extract_surplus_to_treasury() -> Weight;
 	fn auction_collateral() -> Weight;
 	fn set_expected_collateral_auction_size() -> Weight;
+	fn on_finalize(c: u32) -> Weight;
+	fn set_debit_and_surplus_handle_params() -> Weight;
 }
 
 /// Weights for module_cdp_treasury using the Acala node and recommended hardware.
@@ -70,6 +72,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(14_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn on_finalize(c: u32) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((45_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -88,4 +100,14 @@ impl WeightInfo for () {
 		(14_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn on_finalize(c: u32) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((45_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(16_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
 }
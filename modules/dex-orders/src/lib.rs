@@ -0,0 +1,597 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # DEX Orders Module
+//!
+//! ## Overview
+//!
+//! Resting orders on top of DEX. Accounts reserve the supply currency of an
+//! order and a deposit in native currency, the supply currency is swapped
+//! through DEX once the price of the pool crosses the trigger price of the
+//! order:
+//! - a limit order is executed when the price rises to or above the trigger price.
+//! - a stop-loss order is executed when the price falls to or below the trigger price.
+//!
+//! Offchain worker iterates the orders and submits unsigned tx to execute
+//! matched orders or to remove expired ones. Every order is also scheduled as
+//! an idle task, so it will be handled in blocks with spare weight even if no
+//! offchain worker is running. The number of open orders of an account is
+//! limited, and the deposit is returned once the order is closed.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+#![allow(clippy::upper_case_acronyms)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::{log, pallet_prelude::*, transactional};
+use frame_system::{
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::*,
+};
+use orml_traits::MultiReservableCurrency;
+use orml_utilities::OffchainErr;
+use primitives::{
+	task::{DispatchableTask, IdelScheduler, TaskResult},
+	Balance, CurrencyId,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	offchain::{
+		storage::StorageValueRef,
+		storage_lock::{StorageLock, Time},
+		Duration,
+	},
+	traits::{One, Zero},
+	transaction_validity::{
+		InvalidTransaction, TransactionPriority, TransactionSource, TransactionValidity, ValidTransaction,
+	},
+	DispatchResult, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::prelude::*;
+use support::{DEXManager, Price};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const OFFCHAIN_WORKER_DATA: &[u8] = b"acala/dex-orders/data/";
+pub const OFFCHAIN_WORKER_LOCK: &[u8] = b"acala/dex-orders/lock/";
+pub const OFFCHAIN_WORKER_MAX_ITERATIONS: &[u8] = b"acala/dex-orders/max-iterations/";
+pub const LOCK_DURATION: u64 = 100;
+pub const DEFAULT_MAX_ITERATIONS: u32 = 1000;
+
+pub type OrderId = u64;
+
+/// The kind of order
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum OrderKind {
+	/// Executed when the price rises to or above the trigger price.
+	Limit,
+	/// Executed when the price falls to or below the trigger price.
+	StopLoss,
+}
+
+/// Resting order, the price is the amount of target currency for one unit
+/// of supply currency.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct Order<AccountId, BlockNumber> {
+	/// The owner of the order, whose supply currency is reserved.
+	pub owner: AccountId,
+	/// The kind of order.
+	pub kind: OrderKind,
+	/// The currency to sell.
+	pub supply_currency_id: CurrencyId,
+	/// The currency to buy.
+	pub target_currency_id: CurrencyId,
+	/// The reserved amount of supply currency.
+	pub supply_amount: Balance,
+	/// The price which triggers the order.
+	pub trigger_price: Price,
+	/// The acceptable minimum amount of target currency.
+	pub min_target_amount: Balance,
+	/// The order cannot be executed after this block.
+	pub expiry: BlockNumber,
+	/// The reserved deposit of native currency.
+	pub deposit: Balance,
+}
+
+/// Idle tasks of this module, which are the fallback executor of orders.
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum DexOrdersTask<T: Config> {
+	/// Execute the order once it's matched, or remove it once it's expired.
+	#[codec(index = 0)]
+	ExecuteOrder { order_id: OrderId, owner: T::AccountId },
+}
+
+impl<T: Config> DispatchableTask for DexOrdersTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			DexOrdersTask::ExecuteOrder { order_id, owner } => {
+				Pallet::<T>::dispatch_execute_order_task(order_id, owner, weight)
+			}
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to reserve the supply amount of orders.
+		type Currency: MultiReservableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// DEX to execute orders.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// Idle tasks, which must include the tasks of this module.
+		type Task: From<DexOrdersTask<Self>>;
+
+		/// Idle scheduler to schedule the fallback execution of orders.
+		type IdleScheduler: IdelScheduler<Self::Task>;
+
+		/// Native currency id, the deposit of orders is reserved in it.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// The deposit reserved for every open order, it's returned once the
+		/// order is closed.
+		#[pallet::constant]
+		type OrderDeposit: Get<Balance>;
+
+		/// The maximum number of open orders of an account.
+		#[pallet::constant]
+		type MaxOrdersPerAccount: Get<u32>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple modules send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The supply amount or the min target amount is zero.
+		InvalidAmount,
+		/// The supply currency and the target currency are the same.
+		InvalidCurrencyId,
+		/// The expiry is not after the current block.
+		InvalidExpiry,
+		/// The order does not exist.
+		OrderNotFound,
+		/// The caller is not the owner of the order.
+		NoPermission,
+		/// The price has not crossed the trigger price of the order.
+		OrderNotMatched,
+		/// The order has not expired.
+		OrderNotExpired,
+		/// The account has too many open orders.
+		TooManyOrders,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Place order success. \[order_id, owner, kind, supply_currency_id,
+		/// target_currency_id, supply_amount, trigger_price\]
+		OrderPlaced(OrderId, T::AccountId, OrderKind, CurrencyId, CurrencyId, Balance, Price),
+		/// Cancel order success. \[order_id, owner\]
+		OrderCancelled(OrderId, T::AccountId),
+		/// Order executed. \[order_id, owner, supply_amount, target_amount\]
+		OrderExecuted(OrderId, T::AccountId, Balance, Balance),
+		/// Order expired and has been removed. \[order_id, owner\]
+		OrderExpired(OrderId, T::AccountId),
+	}
+
+	/// Open orders.
+	///
+	/// Orders: map OrderId => Option<Order>
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, Order<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// The id of the next order.
+	///
+	/// NextOrderId: OrderId
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// The number of open orders of accounts.
+	///
+	/// OrderCount: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn order_count)]
+	pub type OrderCount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Runs after every block. Start offchain worker to check orders and
+		/// submit unsigned tx to execute or remove them.
+		fn offchain_worker(now: T::BlockNumber) {
+			if let Err(e) = Self::_offchain_worker() {
+				log::info!(
+					target: "dex-orders offchain worker",
+					"cannot run offchain worker at {:?}: {:?}",
+					now,
+					e,
+				);
+			} else {
+				log::debug!(
+					target: "dex-orders offchain worker",
+					"offchain worker start at block: {:?} already done!",
+					now,
+				);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Reserve `supply_amount` of `supply_currency_id` for an order, which
+		/// will be swapped to `target_currency_id` once the price crosses
+		/// `trigger_price`. `OrderDeposit` of native currency is reserved
+		/// until the order is closed.
+		///
+		/// - `kind`: limit order or stop-loss order.
+		/// - `supply_currency_id`: the currency to sell.
+		/// - `target_currency_id`: the currency to buy.
+		/// - `supply_amount`: the amount of supply currency to sell.
+		/// - `trigger_price`: the amount of target currency for one unit of supply currency.
+		/// - `min_target_amount`: the acceptable minimum amount of target currency.
+		/// - `expiry`: the last block in which the order can be executed.
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		#[transactional]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			kind: OrderKind,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			trigger_price: Price,
+			#[pallet::compact] min_target_amount: Balance,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!supply_amount.is_zero() && !min_target_amount.is_zero(),
+				Error::<T>::InvalidAmount
+			);
+			ensure!(supply_currency_id != target_currency_id, Error::<T>::InvalidCurrencyId);
+			ensure!(
+				expiry > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			ensure!(
+				Self::order_count(&who) < T::MaxOrdersPerAccount::get(),
+				Error::<T>::TooManyOrders
+			);
+
+			let deposit = T::OrderDeposit::get();
+			T::Currency::reserve(T::GetNativeCurrencyId::get(), &who, deposit)?;
+			T::Currency::reserve(supply_currency_id, &who, supply_amount)?;
+			OrderCount::<T>::mutate(&who, |count| *count = count.saturating_add(1));
+
+			let order_id = NextOrderId::<T>::mutate(|current| {
+				let id = *current;
+				*current = current.saturating_add(One::one());
+				id
+			});
+			Orders::<T>::insert(
+				order_id,
+				Order {
+					owner: who.clone(),
+					kind,
+					supply_currency_id,
+					target_currency_id,
+					supply_amount,
+					trigger_price,
+					min_target_amount,
+					expiry,
+					deposit,
+				},
+			);
+			T::IdleScheduler::schedule(
+				DexOrdersTask::<T>::ExecuteOrder {
+					order_id,
+					owner: who.clone(),
+				}
+				.into(),
+			);
+
+			Self::deposit_event(Event::OrderPlaced(
+				order_id,
+				who,
+				kind,
+				supply_currency_id,
+				target_currency_id,
+				supply_amount,
+				trigger_price,
+			));
+			Ok(())
+		}
+
+		/// Cancel the order and unreserve its supply currency and deposit.
+		///
+		/// The dispatch origin of this call must be the owner of the order.
+		///
+		/// - `order_id`: the id of the order.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		#[transactional]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.owner == who, Error::<T>::NoPermission);
+
+			Self::do_remove_order(order_id, &order);
+			Self::deposit_event(Event::OrderCancelled(order_id, who));
+			Ok(())
+		}
+
+		/// Execute the matched order, or remove the expired order.
+		///
+		/// The dispatch origin of this call must be _None_.
+		///
+		/// - `order_id`: the id of the order.
+		#[pallet::weight(<T as Config>::WeightInfo::execute_order())]
+		#[transactional]
+		pub fn execute_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_none(origin)?;
+			let order = Self::orders(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			if Self::is_expired(&order) {
+				Self::do_remove_expired_order(order_id, order)
+			} else {
+				Self::do_execute_order(order_id, order)
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			match call {
+				Call::execute_order { order_id } => {
+					let order = Self::orders(order_id).ok_or(InvalidTransaction::Stale)?;
+					if !Self::is_expired(&order) && Self::get_matched_path(&order).is_none() {
+						return InvalidTransaction::Stale.into();
+					}
+
+					ValidTransaction::with_tag_prefix("DexOrdersOffchainWorker")
+						.priority(T::UnsignedPriority::get())
+						.and_provides(order_id)
+						.longevity(64_u64)
+						.propagate(true)
+						.build()
+				}
+				_ => InvalidTransaction::Call.into(),
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn is_expired(order: &Order<T::AccountId, T::BlockNumber>) -> bool {
+		order.expiry < <frame_system::Pallet<T>>::block_number()
+	}
+
+	/// The acceptable minimum amount of target currency when the order is
+	/// executed. Limit orders never sell below the trigger price.
+	fn get_min_target_amount(order: &Order<T::AccountId, T::BlockNumber>) -> Balance {
+		match order.kind {
+			OrderKind::Limit => order
+				.min_target_amount
+				.max(order.trigger_price.saturating_mul_int(order.supply_amount)),
+			OrderKind::StopLoss => order.min_target_amount,
+		}
+	}
+
+	/// Returns the best swap path if the price of it has crossed the trigger
+	/// price of the order, and the order can be executed.
+	pub fn get_matched_path(order: &Order<T::AccountId, T::BlockNumber>) -> Option<Vec<CurrencyId>> {
+		let path = T::DEX::get_best_swap_path_with_exact_supply(
+			order.supply_currency_id,
+			order.target_currency_id,
+			order.supply_amount,
+		)?;
+		let target_amount = T::DEX::get_swap_target_amount(&path, order.supply_amount)?;
+		let trigger_amount = order.trigger_price.saturating_mul_int(order.supply_amount);
+		let crossed = match order.kind {
+			OrderKind::Limit => target_amount >= trigger_amount,
+			OrderKind::StopLoss => target_amount <= trigger_amount,
+		};
+
+		if crossed && target_amount >= Self::get_min_target_amount(order) {
+			Some(path)
+		} else {
+			None
+		}
+	}
+
+	fn do_remove_order(order_id: OrderId, order: &Order<T::AccountId, T::BlockNumber>) {
+		T::Currency::unreserve(order.supply_currency_id, &order.owner, order.supply_amount);
+		T::Currency::unreserve(T::GetNativeCurrencyId::get(), &order.owner, order.deposit);
+		OrderCount::<T>::mutate_exists(&order.owner, |maybe_count| {
+			*maybe_count = maybe_count
+				.and_then(|count| count.checked_sub(1))
+				.filter(|count| *count > 0)
+		});
+		Orders::<T>::remove(order_id);
+	}
+
+	fn do_remove_expired_order(order_id: OrderId, order: Order<T::AccountId, T::BlockNumber>) -> DispatchResult {
+		ensure!(Self::is_expired(&order), Error::<T>::OrderNotExpired);
+		Self::do_remove_order(order_id, &order);
+		Self::deposit_event(Event::OrderExpired(order_id, order.owner));
+		Ok(())
+	}
+
+	#[transactional]
+	fn do_execute_order(order_id: OrderId, order: Order<T::AccountId, T::BlockNumber>) -> DispatchResult {
+		let path = Self::get_matched_path(&order).ok_or(Error::<T>::OrderNotMatched)?;
+		Self::do_remove_order(order_id, &order);
+		let target_amount = T::DEX::swap_with_exact_supply(
+			&order.owner,
+			&path,
+			order.supply_amount,
+			Self::get_min_target_amount(&order),
+		)?;
+
+		Self::deposit_event(Event::OrderExecuted(
+			order_id,
+			order.owner,
+			order.supply_amount,
+			target_amount,
+		));
+		Ok(())
+	}
+
+	/// Handle the order on idle. The task is finished once the order is
+	/// closed, executed or removed.
+	fn dispatch_execute_order_task(order_id: OrderId, owner: T::AccountId, weight: Weight) -> TaskResult {
+		let execute_weight = <T as Config>::WeightInfo::execute_order();
+		if weight < execute_weight {
+			return TaskResult {
+				used_weight: Zero::zero(),
+				finished: false,
+			};
+		}
+
+		let order = match Self::orders(order_id) {
+			Some(order) if order.owner == owner => order,
+			_ => {
+				return TaskResult {
+					used_weight: T::DbWeight::get().reads(1),
+					finished: true,
+				}
+			}
+		};
+
+		let result = if Self::is_expired(&order) {
+			Self::do_remove_expired_order(order_id, order)
+		} else {
+			Self::do_execute_order(order_id, order)
+		};
+
+		TaskResult {
+			used_weight: execute_weight,
+			finished: result.is_ok(),
+		}
+	}
+
+	fn submit_unsigned_execute_order_tx(order_id: OrderId) {
+		let call = Call::<T>::execute_order { order_id };
+		if SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()).is_err() {
+			log::info!(
+				target: "dex-orders offchain worker",
+				"submit unsigned execute order tx for \nOrderId {:?} \nfailed!",
+				order_id,
+			);
+		}
+	}
+
+	fn _offchain_worker() -> Result<(), OffchainErr> {
+		// check if we are a potential validator
+		if !sp_io::offchain::is_validator() {
+			return Err(OffchainErr::NotValidator);
+		}
+
+		// acquire offchain worker lock
+		let lock_expiration = Duration::from_millis(LOCK_DURATION);
+		let mut lock = StorageLock::<'_, Time>::with_deadline(OFFCHAIN_WORKER_LOCK, lock_expiration);
+		let mut guard = lock.try_lock().map_err(|_| OffchainErr::OffchainLock)?;
+
+		let to_be_continue = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA);
+
+		// get to_be_continue record
+		let start_key = to_be_continue.get::<Option<Vec<u8>>>().unwrap_or_default().flatten();
+
+		// get the max iterationns config
+		let max_iterations = StorageValueRef::persistent(OFFCHAIN_WORKER_MAX_ITERATIONS)
+			.get::<u32>()
+			.unwrap_or(Some(DEFAULT_MAX_ITERATIONS))
+			.unwrap_or(DEFAULT_MAX_ITERATIONS);
+
+		// If start key is Some(value) continue iterating from that point in storage otherwise start
+		// iterating from the beginning of <Orders<T>>
+		let mut map_iterator = match start_key.clone() {
+			Some(key) => Orders::<T>::iter_from(key),
+			None => Orders::<T>::iter(),
+		};
+
+		let mut finished = true;
+		let mut iteration_count = 0;
+		let iteration_start_time = sp_io::offchain::timestamp();
+
+		#[allow(clippy::while_let_on_iterator)]
+		while let Some((order_id, order)) = map_iterator.next() {
+			if Self::is_expired(&order) || Self::get_matched_path(&order).is_some() {
+				Self::submit_unsigned_execute_order_tx(order_id);
+			}
+
+			iteration_count += 1;
+			if iteration_count == max_iterations {
+				finished = false;
+				break;
+			}
+			// extend offchain worker lock
+			guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+		}
+		let iteration_end_time = sp_io::offchain::timestamp();
+		log::debug!(
+			target: "dex-orders offchain worker",
+			"iteration info:\n max iterations is {:?}\n start key: {:?}, iterate count: {:?}\n iteration start at: {:?}, end at: {:?}, execution time: {:?}\n",
+			max_iterations,
+			start_key,
+			iteration_count,
+			iteration_start_time,
+			iteration_end_time,
+			iteration_end_time.diff(&iteration_start_time)
+		);
+
+		// if iteration for map storage finished, clear to be continue record
+		// otherwise, update to be continue record
+		if finished {
+			to_be_continue.set(&Option::<Vec<u8>>::None);
+		} else {
+			to_be_continue.set(&Some(map_iterator.last_raw_key()));
+		}
+
+		// Consume the guard but **do not** unlock the underlying lock.
+		guard.forget();
+
+		Ok(())
+	}
+}
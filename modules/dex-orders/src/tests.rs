@@ -0,0 +1,455 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the dex orders module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Call as MockCall, Event, *};
+use orml_traits::MultiCurrency;
+use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+use sp_runtime::traits::BadOrigin;
+
+fn init_liquidity_pool() {
+	// 1 DOT = 10 AUSD
+	assert_ok!(DEXModule::add_liquidity(
+		Origin::signed(CAROL),
+		AUSD,
+		DOT,
+		1_000_000,
+		100_000,
+		0,
+		false,
+	));
+}
+
+fn place_limit_order() {
+	assert_ok!(DexOrdersModule::place_order(
+		Origin::signed(ALICE),
+		OrderKind::Limit,
+		DOT,
+		AUSD,
+		1_000,
+		Price::saturating_from_integer(10),
+		9_000,
+		100,
+	));
+}
+
+fn place_stop_loss_order() {
+	assert_ok!(DexOrdersModule::place_order(
+		Origin::signed(ALICE),
+		OrderKind::StopLoss,
+		DOT,
+		AUSD,
+		1_000,
+		Price::saturating_from_integer(9),
+		5_000,
+		100,
+	));
+}
+
+fn offchain_worker_at_next_lock_deadline() {
+	sp_io::offchain::sleep_until(sp_io::offchain::timestamp().add(Duration::from_millis(LOCK_DURATION + 200)));
+	DexOrdersModule::offchain_worker(System::block_number());
+}
+
+#[test]
+fn place_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(ALICE),
+				OrderKind::Limit,
+				DOT,
+				AUSD,
+				0,
+				Price::saturating_from_integer(10),
+				9_000,
+				100,
+			),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(ALICE),
+				OrderKind::Limit,
+				DOT,
+				DOT,
+				1_000,
+				Price::saturating_from_integer(10),
+				9_000,
+				100,
+			),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(ALICE),
+				OrderKind::Limit,
+				DOT,
+				AUSD,
+				1_000,
+				Price::saturating_from_integer(10),
+				9_000,
+				1,
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(ALICE),
+				OrderKind::Limit,
+				DOT,
+				AUSD,
+				20_000,
+				Price::saturating_from_integer(10),
+				9_000,
+				100,
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		place_limit_order();
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderPlaced(
+			0,
+			ALICE,
+			OrderKind::Limit,
+			DOT,
+			AUSD,
+			1_000,
+			Price::saturating_from_integer(10),
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 9_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 1_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 10);
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 1);
+		assert_eq!(
+			DexOrdersModule::orders(0),
+			Some(Order {
+				owner: ALICE,
+				kind: OrderKind::Limit,
+				supply_currency_id: DOT,
+				target_currency_id: AUSD,
+				supply_amount: 1_000,
+				trigger_price: Price::saturating_from_integer(10),
+				min_target_amount: 9_000,
+				expiry: 100,
+				deposit: 10,
+			})
+		);
+		assert_eq!(DexOrdersModule::next_order_id(), 1);
+		assert_eq!(
+			IdleScheduler::tasks(0),
+			Some(ScheduledTasks::DexOrdersTask(crate::DexOrdersTask::ExecuteOrder {
+				order_id: 0,
+				owner: ALICE
+			}))
+		);
+	});
+}
+
+#[test]
+fn cancel_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(ALICE), 0),
+			Error::<Runtime>::OrderNotFound
+		);
+		place_limit_order();
+		assert_noop!(
+			DexOrdersModule::cancel_order(Origin::signed(BOB), 0),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderCancelled(0, ALICE)));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 10_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(ACA, &ALICE), 1_000);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 0);
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 0);
+		assert_eq!(DexOrdersModule::orders(0), None);
+	});
+}
+
+#[test]
+fn place_order_limited_by_deposit_and_max_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// BOB has no native currency for the deposit
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(BOB),
+				OrderKind::Limit,
+				DOT,
+				AUSD,
+				1_000,
+				Price::saturating_from_integer(10),
+				9_000,
+				100,
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		place_limit_order();
+		place_stop_loss_order();
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 2);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 20);
+		assert_noop!(
+			DexOrdersModule::place_order(
+				Origin::signed(ALICE),
+				OrderKind::Limit,
+				DOT,
+				AUSD,
+				1_000,
+				Price::saturating_from_integer(10),
+				9_000,
+				100,
+			),
+			Error::<Runtime>::TooManyOrders
+		);
+
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(ALICE), 1));
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 1);
+		place_limit_order();
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 2);
+	});
+}
+
+#[test]
+fn execute_limit_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_limit_order();
+		assert_noop!(DexOrdersModule::execute_order(Origin::signed(ALICE), 0), BadOrigin);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), 1),
+			Error::<Runtime>::OrderNotFound
+		);
+
+		// 1_000 DOT can be swapped to 9_900 AUSD, below the trigger price
+		assert_eq!(DEXModule::get_swap_target_amount(&[DOT, AUSD], 1_000), Some(9_900));
+		assert_eq!(
+			DexOrdersModule::get_matched_path(&DexOrdersModule::orders(0).unwrap()),
+			None
+		);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), 0),
+			Error::<Runtime>::OrderNotMatched
+		);
+
+		// price of DOT rises
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			100_000,
+			0
+		));
+		assert_eq!(
+			DexOrdersModule::get_matched_path(&DexOrdersModule::orders(0).unwrap()),
+			Some(vec![DOT, AUSD])
+		);
+
+		assert_ok!(DexOrdersModule::execute_order(Origin::none(), 0));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderExecuted(
+			0, ALICE, 1_000, 11_968,
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 9_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 21_968);
+		assert_eq!(Tokens::free_balance(ACA, &ALICE), 1_000);
+		assert_eq!(DexOrdersModule::order_count(&ALICE), 0);
+		assert_eq!(DexOrdersModule::orders(0), None);
+	});
+}
+
+#[test]
+fn execute_stop_loss_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_stop_loss_order();
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), 0),
+			Error::<Runtime>::OrderNotMatched
+		);
+
+		// price of DOT falls
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![DOT, AUSD],
+			20_000,
+			0
+		));
+		assert_eq!(DEXModule::get_swap_target_amount(&[DOT, AUSD], 1_000), Some(6_887));
+
+		assert_ok!(DexOrdersModule::execute_order(Origin::none(), 0));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderExecuted(
+			0, ALICE, 1_000, 6_887,
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 9_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 16_887);
+	});
+}
+
+#[test]
+fn stop_loss_order_respects_min_target_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_stop_loss_order();
+
+		// price of DOT crashes below the acceptable minimum
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![DOT, AUSD],
+			100_000,
+			0
+		));
+		assert_eq!(DEXModule::get_swap_target_amount(&[DOT, AUSD], 1_000), Some(2_487));
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), 0),
+			Error::<Runtime>::OrderNotMatched
+		);
+	});
+}
+
+#[test]
+fn remove_expired_order_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_limit_order();
+
+		System::set_block_number(100);
+		assert_noop!(
+			DexOrdersModule::execute_order(Origin::none(), 0),
+			Error::<Runtime>::OrderNotMatched
+		);
+
+		System::set_block_number(101);
+		assert_ok!(DexOrdersModule::execute_order(Origin::none(), 0));
+		System::assert_last_event(Event::DexOrdersModule(crate::Event::OrderExpired(0, ALICE)));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 10_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+		assert_eq!(Tokens::reserved_balance(ACA, &ALICE), 0);
+		assert_eq!(DexOrdersModule::orders(0), None);
+	});
+}
+
+#[test]
+fn validate_unsigned_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_limit_order();
+		let call = crate::Call::<Runtime>::execute_order { order_id: 0 };
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::Stale.into()
+		);
+		assert_eq!(
+			DexOrdersModule::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::<Runtime>::execute_order { order_id: 1 }
+			),
+			InvalidTransaction::Stale.into()
+		);
+
+		System::set_block_number(101);
+		assert!(DexOrdersModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+	});
+}
+
+#[test]
+fn idle_scheduler_execute_orders_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_liquidity_pool();
+		place_limit_order();
+		place_stop_loss_order();
+
+		// both orders are not matched, tasks remain
+		IdleScheduler::on_idle(1, 1_000_000_000_000);
+		assert!(IdleScheduler::tasks(0).is_some());
+		assert!(IdleScheduler::tasks(1).is_some());
+
+		// not enough weight to dispatch task
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			100_000,
+			0
+		));
+		IdleScheduler::on_idle(1, 1_000);
+		assert!(DexOrdersModule::orders(0).is_some());
+		assert!(IdleScheduler::tasks(0).is_some());
+
+		// limit order is executed
+		IdleScheduler::on_idle(1, 1_000_000_000_000);
+		assert_eq!(DexOrdersModule::orders(0), None);
+		assert!(DexOrdersModule::orders(1).is_some());
+		assert_eq!(IdleScheduler::tasks(0), None);
+		assert!(IdleScheduler::tasks(1).is_some());
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 21_968);
+
+		// stop-loss order is cancelled, task is finished
+		assert_ok!(DexOrdersModule::cancel_order(Origin::signed(ALICE), 1));
+		IdleScheduler::on_idle(1, 1_000_000_000_000);
+		assert_eq!(IdleScheduler::tasks(1), None);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 9_000);
+		assert_eq!(Tokens::reserved_balance(DOT, &ALICE), 0);
+	});
+}
+
+#[test]
+fn offchain_worker_works() {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let mut ext = ExtBuilder::default().build();
+	ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(OffchainDbExt::new(offchain));
+
+	ext.execute_with(|| {
+		init_liquidity_pool();
+		place_limit_order();
+		place_stop_loss_order();
+
+		// offchain worker will not execute any order
+		DexOrdersModule::offchain_worker(1);
+		assert!(pool_state.write().transactions.pop().is_none());
+
+		// price of DOT rises
+		assert_ok!(DEXModule::swap_with_exact_supply(
+			Origin::signed(BOB),
+			vec![AUSD, DOT],
+			100_000,
+			0
+		));
+		System::set_block_number(2);
+		offchain_worker_at_next_lock_deadline();
+
+		// offchain worker will execute the limit order
+		let tx = pool_state.write().transactions.pop().unwrap();
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			MockCall::DexOrdersModule(crate::Call::execute_order { order_id: 0 })
+		);
+		assert!(pool_state.write().transactions.pop().is_none());
+	});
+}
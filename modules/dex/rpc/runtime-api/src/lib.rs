@@ -0,0 +1,177 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for dex module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `too_many_arguments` warning originates from `decl_runtime_apis` macro.
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::{Codec, Decode, Encode};
+use primitives::{CurrencyId, TradingPair};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sp_runtime::traits::{MaybeDisplay, MaybeFromStr};
+use sp_std::prelude::*;
+use support::Ratio;
+
+/// The quote of a swap along a trading path.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct SwapQuote<Balance> {
+	pub path: Vec<CurrencyId>,
+	/// The amount of each currency on the path, the first one is the supply amount and the
+	/// last one is the target amount.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_vec_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_vec_from_string"))]
+	pub amounts: Vec<Balance>,
+	/// The exchange fee charged on the supply amount of each hop.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_vec_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_vec_from_string"))]
+	pub exchange_fees: Vec<Balance>,
+	/// The price impact of the swap, exchange fees excluded.
+	pub price_impact: Ratio,
+}
+
+/// The status of a trading pair that is listed on dex.
+#[derive(Eq, PartialEq, Encode, Decode, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+pub enum TradingPairState {
+	Provisioning,
+	Enabled,
+}
+
+/// The state of a listed trading pair.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct TradingPairInfo<Balance> {
+	pub trading_pair: TradingPair,
+	pub state: TradingPairState,
+	/// The liquidity pool of the first currency of trading pair, or the accumulated
+	/// provision if the trading pair is provisioning.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub pool_0: Balance,
+	/// The liquidity pool of the second currency of trading pair, or the accumulated
+	/// provision if the trading pair is provisioning.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub pool_1: Balance,
+	pub exchange_fee: (u32, u32),
+	/// The total issuance of the dex share of trading pair.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub total_share: Balance,
+}
+
+/// The dex share of an account and the liquidity it is worth.
+#[derive(Eq, PartialEq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct LiquidityShareInfo<Balance> {
+	pub trading_pair: TradingPair,
+	/// The dex share held by the account, including the share deposited to incentives.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub share: Balance,
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub total_share: Balance,
+	/// The amount of the first currency of trading pair that the share is worth.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount_0: Balance,
+	/// The amount of the second currency of trading pair that the share is worth.
+	#[cfg_attr(feature = "std", serde(bound(serialize = "Balance: std::fmt::Display")))]
+	#[cfg_attr(feature = "std", serde(serialize_with = "serialize_as_string"))]
+	#[cfg_attr(feature = "std", serde(bound(deserialize = "Balance: std::str::FromStr")))]
+	#[cfg_attr(feature = "std", serde(deserialize_with = "deserialize_from_string"))]
+	pub amount_1: Balance,
+}
+
+#[cfg(feature = "std")]
+fn serialize_as_string<S: Serializer, T: std::fmt::Display>(t: &T, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&t.to_string())
+}
+
+#[cfg(feature = "std")]
+fn deserialize_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(deserializer: D) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	s.parse::<T>()
+		.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+}
+
+#[cfg(feature = "std")]
+fn serialize_vec_as_string<S: Serializer, T: std::fmt::Display>(t: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.collect_seq(t.iter().map(|x| x.to_string()))
+}
+
+#[cfg(feature = "std")]
+fn deserialize_vec_from_string<'de, D: Deserializer<'de>, T: std::str::FromStr>(
+	deserializer: D,
+) -> Result<Vec<T>, D::Error> {
+	let v = Vec::<String>::deserialize(deserializer)?;
+	v.iter()
+		.map(|s| {
+			s.parse::<T>()
+				.map_err(|_| serde::de::Error::custom("Parse from string failed"))
+		})
+		.collect()
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec + MaybeDisplay + MaybeFromStr,
+	{
+		fn get_swap_quote_with_exact_supply(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Option<SwapQuote<Balance>>;
+
+		fn get_swap_quote_with_exact_target(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Option<SwapQuote<Balance>>;
+
+		fn get_trading_pairs() -> Vec<TradingPairInfo<Balance>>;
+
+		fn get_liquidity_shares(
+			account: AccountId
+		) -> Vec<LiquidityShareInfo<Balance>>;
+	}
+}
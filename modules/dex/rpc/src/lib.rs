@@ -0,0 +1,186 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the dex module.
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use module_dex_rpc_runtime_api::{LiquidityShareInfo, SwapQuote, TradingPairInfo};
+use primitives::CurrencyId;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, MaybeDisplay, MaybeFromStr},
+};
+use std::{convert::TryFrom, sync::Arc};
+
+pub use self::gen_client::Client as DexClient;
+pub use module_dex_rpc_runtime_api::DexApi as DexRuntimeApi;
+
+#[rpc]
+pub trait DexApi<BlockHash, AccountId, Balance> {
+	#[rpc(name = "dex_getSwapQuoteWithExactSupply")]
+	fn get_swap_quote_with_exact_supply(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> Result<Option<SwapQuote<Balance>>>;
+
+	#[rpc(name = "dex_getSwapQuoteWithExactTarget")]
+	fn get_swap_quote_with_exact_target(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> Result<Option<SwapQuote<Balance>>>;
+
+	#[rpc(name = "dex_getTradingPairs")]
+	fn get_trading_pairs(&self, at: Option<BlockHash>) -> Result<Vec<TradingPairInfo<Balance>>>;
+
+	#[rpc(name = "dex_getLiquidityShares")]
+	fn get_liquidity_shares(
+		&self,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<LiquidityShareInfo<Balance>>>;
+}
+
+/// A struct that implements the [`DexApi`].
+pub struct Dex<C, B> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> Dex<C, B> {
+	/// Create new `Dex` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Dex {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+pub enum Error {
+	RuntimeError,
+	InvalidParams,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidParams => 2,
+		}
+	}
+}
+
+fn decode_amount<Balance: TryFrom<NumberOrHex>>(amount: NumberOrHex) -> Result<Balance> {
+	Balance::try_from(amount).map_err(|_| RpcError {
+		code: ErrorCode::ServerError(Error::InvalidParams.into()),
+		message: "Amount doesn't fit in balance type.".into(),
+		data: None,
+	})
+}
+
+impl<C, Block, AccountId, Balance> DexApi<<Block as BlockT>::Hash, AccountId, Balance> for Dex<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: DexRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec,
+	Balance: Codec + MaybeDisplay + MaybeFromStr + TryFrom<NumberOrHex>,
+{
+	fn get_swap_quote_with_exact_supply(
+		&self,
+		path: Vec<CurrencyId>,
+		supply_amount: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapQuote<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+		let supply_amount = decode_amount(supply_amount)?;
+
+		api.get_swap_quote_with_exact_supply(&at, path, supply_amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get swap quote.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_swap_quote_with_exact_target(
+		&self,
+		path: Vec<CurrencyId>,
+		target_amount: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<SwapQuote<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+		let target_amount = decode_amount(target_amount)?;
+
+		api.get_swap_quote_with_exact_target(&at, path, target_amount)
+			.map_err(|e| RpcError {
+				code: ErrorCode::ServerError(Error::RuntimeError.into()),
+				message: "Unable to get swap quote.".into(),
+				data: Some(format!("{:?}", e).into()),
+			})
+	}
+
+	fn get_trading_pairs(&self, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<TradingPairInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_trading_pairs(&at).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get trading pairs.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+
+	fn get_liquidity_shares(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<LiquidityShareInfo<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or(
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash,
+		));
+
+		api.get_liquidity_shares(&at, account).map_err(|e| RpcError {
+			code: ErrorCode::ServerError(Error::RuntimeError.into()),
+			message: "Unable to get liquidity shares.".into(),
+			data: Some(format!("{:?}", e).into()),
+		})
+	}
+}
@@ -0,0 +1,466 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Pricing curves of trading pairs in DEX.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_core::U256;
+use sp_runtime::{
+	traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero},
+	FixedPointNumber, FixedU128, RuntimeDebug,
+};
+use sp_std::convert::TryInto;
+
+/// The maximum amplification coefficient of `SwapCurve::Amplified`.
+pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+/// The maximum iterations of the numerical methods used by curves.
+const MAX_ITERATIONS: u32 = 255;
+
+/// The maximum times to top up the estimated supply amount of curves which
+/// are calculated by numerical methods.
+const MAX_SUPPLY_ADJUSTMENTS: u32 = 3;
+
+/// Pricing curve of TradingPair
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum SwapCurve {
+	/// Constant product formula `x * y = k`, the pool keeps equal value of
+	/// both currencies.
+	ConstantProduct,
+	/// Weighted constant product formula `x^w_x * y^w_y = k`, the weights
+	/// are the percentages of pool value kept by the currencies, ordered as
+	/// the currencies of the trading pair, and must sum up to 100.
+	Weighted(u8, u8),
+	/// StableSwap invariant with the amplification coefficient, which
+	/// flattens the curve around the balanced point for correlated
+	/// currencies.
+	Amplified(u32),
+}
+
+impl Default for SwapCurve {
+	fn default() -> Self {
+		Self::ConstantProduct
+	}
+}
+
+impl SwapCurve {
+	/// Check the parameters of the curve.
+	pub fn is_valid(&self) -> bool {
+		match *self {
+			SwapCurve::ConstantProduct => true,
+			SwapCurve::Weighted(weight_0, weight_1) => {
+				!weight_0.is_zero() && !weight_1.is_zero() && weight_0.saturating_add(weight_1) == 100
+			}
+			SwapCurve::Amplified(amplification) => !amplification.is_zero() && amplification <= MAX_AMPLIFICATION,
+		}
+	}
+
+	/// Reverse the order of the currencies the curve parameters refer to.
+	pub fn reversed(self) -> Self {
+		match self {
+			SwapCurve::Weighted(weight_0, weight_1) => SwapCurve::Weighted(weight_1, weight_0),
+			other => other,
+		}
+	}
+
+	/// Get the marginal price of supply currency in target currency, exchange fee
+	/// excluded. The curve parameters must be ordered as (supply, target).
+	pub fn get_spot_price(&self, supply_pool: Balance, target_pool: Balance) -> Option<FixedU128> {
+		if supply_pool.is_zero() || target_pool.is_zero() {
+			return None;
+		}
+
+		match *self {
+			SwapCurve::ConstantProduct => FixedU128::checked_from_rational(target_pool, supply_pool),
+			SwapCurve::Weighted(supply_weight, target_weight) => {
+				// price = (target_pool / target_weight) / (supply_pool / supply_weight)
+				FixedU128::checked_from_rational(target_pool, supply_pool)?.checked_mul(
+					&FixedU128::checked_from_rational(Balance::from(supply_weight), Balance::from(target_weight))?,
+				)
+			}
+			SwapCurve::Amplified(amplification) => {
+				// price = (Ann + D^3 / (4 * x^2 * y)) / (Ann + D^3 / (4 * x * y^2))
+				let invariant: Balance =
+					get_invariant(U256::from(supply_pool), U256::from(target_pool), amplification)?
+						.try_into()
+						.ok()?;
+				let ann = FixedU128::checked_from_integer(Balance::from(amplification).checked_mul(2)?)?;
+				let half_supply_ratio = FixedU128::checked_from_rational(invariant, supply_pool.checked_mul(2)?)?;
+				let half_target_ratio = FixedU128::checked_from_rational(invariant, target_pool.checked_mul(2)?)?;
+				let supply_derivative = half_supply_ratio
+					.checked_mul(&half_supply_ratio)?
+					.checked_mul(&FixedU128::checked_from_rational(invariant, target_pool)?)?
+					.checked_add(&ann)?;
+				let target_derivative = half_target_ratio
+					.checked_mul(&half_target_ratio)?
+					.checked_mul(&FixedU128::checked_from_rational(invariant, supply_pool)?)?
+					.checked_add(&ann)?;
+				supply_derivative.checked_div(&target_derivative)
+			}
+		}
+	}
+
+	/// Get how much target amount will be got for specific supply amount.
+	/// The curve parameters must be ordered as (supply, target).
+	pub fn get_target_amount(
+		&self,
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		match *self {
+			SwapCurve::ConstantProduct => {
+				let supply_amount_with_fee: U256 =
+					U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
+				let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
+				let denominator: U256 = U256::from(supply_pool)
+					.saturating_mul(U256::from(fee_denominator))
+					.saturating_add(supply_amount_with_fee);
+
+				numerator
+					.checked_div(denominator)
+					.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+					.unwrap_or_else(Zero::zero)
+			}
+			SwapCurve::Weighted(supply_weight, target_weight) => {
+				deduct_fee(supply_amount, fee_numerator, fee_denominator)
+					.and_then(|supply_amount_with_fee| {
+						weighted_target_amount(
+							supply_pool,
+							target_pool,
+							supply_amount_with_fee,
+							supply_weight,
+							target_weight,
+						)
+					})
+					.unwrap_or_else(Zero::zero)
+			}
+			SwapCurve::Amplified(amplification) => deduct_fee(supply_amount, fee_numerator, fee_denominator)
+				.and_then(|supply_amount_with_fee| {
+					amplified_target_amount(supply_pool, target_pool, supply_amount_with_fee, amplification)
+				})
+				.unwrap_or_else(Zero::zero),
+		}
+	}
+
+	/// Get how much supply amount will be paid for specific target amount.
+	/// The curve parameters must be ordered as (supply, target).
+	pub fn get_supply_amount(
+		&self,
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
+			return Zero::zero();
+		}
+
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let estimated_supply_amount = match *self {
+			SwapCurve::ConstantProduct => {
+				let numerator: U256 = U256::from(supply_pool)
+					.saturating_mul(U256::from(target_amount))
+					.saturating_mul(U256::from(fee_denominator));
+				let denominator: U256 = U256::from(target_pool)
+					.saturating_sub(U256::from(target_amount))
+					.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
+
+				return numerator
+					.checked_div(denominator)
+					.and_then(|r| r.checked_add(U256::one())) // add 1 to result so that correct the possible losses caused by remainder discarding in
+					.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+					.unwrap_or_else(Zero::zero);
+			}
+			SwapCurve::Weighted(supply_weight, target_weight) => {
+				weighted_supply_amount(supply_pool, target_pool, target_amount, supply_weight, target_weight)
+			}
+			SwapCurve::Amplified(amplification) => {
+				amplified_supply_amount(supply_pool, target_pool, target_amount, amplification)
+			}
+		}
+		.and_then(|supply_amount_with_fee| add_fee(supply_amount_with_fee, fee_numerator, fee_denominator));
+
+		// the numerical methods round in both directions, make sure the estimated supply amount
+		// can really afford the target amount, otherwise top it up.
+		let mut supply_amount = match estimated_supply_amount {
+			Some(amount) => amount,
+			None => return Zero::zero(),
+		};
+		for _ in 0..MAX_SUPPLY_ADJUSTMENTS {
+			if self.get_target_amount(supply_pool, target_pool, supply_amount, exchange_fee) >= target_amount {
+				return supply_amount;
+			}
+			supply_amount = supply_amount.saturating_add(supply_amount / 1_000_000_000_000 + 1);
+		}
+
+		Zero::zero()
+	}
+}
+
+fn deduct_fee(amount: Balance, fee_numerator: u32, fee_denominator: u32) -> Option<Balance> {
+	U256::from(amount)
+		.checked_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)))?
+		.checked_div(U256::from(fee_denominator))?
+		.try_into()
+		.ok()
+}
+
+fn add_fee(amount: Balance, fee_numerator: u32, fee_denominator: u32) -> Option<Balance> {
+	U256::from(amount)
+		.checked_mul(U256::from(fee_denominator))?
+		.checked_div(U256::from(fee_denominator.saturating_sub(fee_numerator)))?
+		.checked_add(U256::one())?
+		.try_into()
+		.ok()
+}
+
+/// target_amount = target_pool * (1 - (supply_pool / (supply_pool + supply_amount)) ^
+/// (supply_weight / target_weight))
+fn weighted_target_amount(
+	supply_pool: Balance,
+	target_pool: Balance,
+	supply_amount: Balance,
+	supply_weight: u8,
+	target_weight: u8,
+) -> Option<Balance> {
+	// base = supply_pool / (supply_pool + supply_amount), rounded up so that the pool will not
+	// pay more than the curve.
+	let numerator = U256::from(supply_pool).checked_mul(U256::from(FixedU128::accuracy()))?;
+	let denominator = supply_pool.checked_add(supply_amount).filter(|n| !n.is_zero())?;
+	let (quotient, remainder) = numerator.div_mod(U256::from(denominator));
+	let base_inner = if remainder.is_zero() {
+		quotient
+	} else {
+		quotient.checked_add(U256::one())?
+	};
+	let base = FixedU128::from_inner(TryInto::<u128>::try_into(base_inner).ok()?);
+	let power = checked_pow_ratio(base, supply_weight.into(), target_weight.into())?;
+	FixedU128::one().checked_sub(&power)?.checked_mul_int(target_pool)
+}
+
+/// supply_amount = supply_pool * ((target_pool / (target_pool - target_amount)) ^
+/// (target_weight / supply_weight) - 1)
+fn weighted_supply_amount(
+	supply_pool: Balance,
+	target_pool: Balance,
+	target_amount: Balance,
+	supply_weight: u8,
+	target_weight: u8,
+) -> Option<Balance> {
+	let remaining_target_pool = target_pool.checked_sub(target_amount).filter(|n| !n.is_zero())?;
+	let base = FixedU128::checked_from_rational(target_pool, remaining_target_pool)?;
+	let power = checked_pow_ratio(base, target_weight.into(), supply_weight.into())?;
+	power
+		.checked_sub(&FixedU128::one())?
+		.checked_mul_int(supply_pool)?
+		.checked_add(One::one())
+}
+
+fn amplified_target_amount(
+	supply_pool: Balance,
+	target_pool: Balance,
+	supply_amount: Balance,
+	amplification: u32,
+) -> Option<Balance> {
+	let (supply_pool, target_pool) = (U256::from(supply_pool), U256::from(target_pool));
+	let invariant = get_invariant(supply_pool, target_pool, amplification)?;
+	let new_target_pool = get_balance(
+		supply_pool.checked_add(U256::from(supply_amount))?,
+		invariant,
+		amplification,
+	)?;
+	// sub 1 to favor the pool for the rounding of the numerical method.
+	target_pool
+		.checked_sub(new_target_pool)?
+		.checked_sub(U256::one())?
+		.try_into()
+		.ok()
+}
+
+fn amplified_supply_amount(
+	supply_pool: Balance,
+	target_pool: Balance,
+	target_amount: Balance,
+	amplification: u32,
+) -> Option<Balance> {
+	let (supply_pool, target_pool) = (U256::from(supply_pool), U256::from(target_pool));
+	let invariant = get_invariant(supply_pool, target_pool, amplification)?;
+	let new_target_pool = target_pool
+		.checked_sub(U256::from(target_amount))
+		.filter(|n| !n.is_zero())?;
+	let new_supply_pool = get_balance(new_target_pool, invariant, amplification)?;
+	new_supply_pool
+		.checked_sub(supply_pool)?
+		.checked_add(U256::one())?
+		.try_into()
+		.ok()
+}
+
+/// Calculate the StableSwap invariant `D` of two currencies by Newton's method:
+/// A * n^n * (x + y) + D = A * D * n^n + D^(n+1) / (n^n * x * y), where n = 2
+fn get_invariant(pool_0: U256, pool_1: U256, amplification: u32) -> Option<U256> {
+	let sum = pool_0.checked_add(pool_1)?;
+	if sum.is_zero() {
+		return Some(U256::zero());
+	}
+
+	let ann = U256::from(amplification).checked_mul(U256::from(2))?;
+	let mut invariant = sum;
+	for _ in 0..MAX_ITERATIONS {
+		// invariant_product = D^3 / (4 * x * y)
+		let invariant_product = invariant
+			.checked_mul(invariant)?
+			.checked_div(pool_0.checked_mul(U256::from(2))?)?
+			.checked_mul(invariant)?
+			.checked_div(pool_1.checked_mul(U256::from(2))?)?;
+		let previous_invariant = invariant;
+
+		// D = (Ann * S + 2 * D_P) * D / ((Ann - 1) * D + 3 * D_P)
+		let numerator = ann
+			.checked_mul(sum)?
+			.checked_add(invariant_product.checked_mul(U256::from(2))?)?
+			.checked_mul(invariant)?;
+		let denominator = ann
+			.checked_sub(U256::one())?
+			.checked_mul(invariant)?
+			.checked_add(invariant_product.checked_mul(U256::from(3))?)?;
+		invariant = numerator.checked_div(denominator)?;
+
+		if abs_diff(invariant, previous_invariant) <= U256::one() {
+			return Some(invariant);
+		}
+	}
+
+	None
+}
+
+/// Calculate the balance of one currency by Newton's method, when the balance
+/// of the other currency is `balance` and the invariant is kept.
+fn get_balance(balance: U256, invariant: U256, amplification: u32) -> Option<U256> {
+	let ann = U256::from(amplification).checked_mul(U256::from(2))?;
+	// c = D^3 / (4 * x * Ann)
+	let c = invariant
+		.checked_mul(invariant)?
+		.checked_div(balance.checked_mul(U256::from(2))?)?
+		.checked_mul(invariant)?
+		.checked_div(ann.checked_mul(U256::from(2))?)?;
+	let b = balance.checked_add(invariant.checked_div(ann)?)?;
+
+	let mut new_balance = invariant;
+	for _ in 0..MAX_ITERATIONS {
+		let previous_balance = new_balance;
+		// y = (y^2 + c) / (2 * y + b - D)
+		new_balance = new_balance.checked_mul(new_balance)?.checked_add(c)?.checked_div(
+			new_balance
+				.checked_mul(U256::from(2))?
+				.checked_add(b)?
+				.checked_sub(invariant)?,
+		)?;
+
+		if abs_diff(new_balance, previous_balance) <= U256::one() {
+			return Some(new_balance);
+		}
+	}
+
+	None
+}
+
+fn abs_diff(a: U256, b: U256) -> U256 {
+	if a > b {
+		a - b
+	} else {
+		b - a
+	}
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+	if b.is_zero() {
+		a
+	} else {
+		gcd(b, a % b)
+	}
+}
+
+/// Calculate `base ^ exponent` by squaring.
+fn checked_pow(base: FixedU128, exponent: u32) -> Option<FixedU128> {
+	let mut result = FixedU128::one();
+	let mut base = base;
+	let mut exponent = exponent;
+	while !exponent.is_zero() {
+		if exponent % 2 == 1 {
+			result = result.checked_mul(&base)?;
+		}
+		exponent /= 2;
+		if !exponent.is_zero() {
+			base = base.checked_mul(&base)?;
+		}
+	}
+	Some(result)
+}
+
+/// Calculate the `n`th root of `value` by Newton's method.
+fn checked_nth_root(value: FixedU128, n: u32) -> Option<FixedU128> {
+	if n.is_zero() {
+		return None;
+	}
+	if n == 1 || value.is_zero() || value == FixedU128::one() {
+		return Some(value);
+	}
+
+	let n_fixed = FixedU128::saturating_from_integer(n);
+	let n_minus_one = FixedU128::saturating_from_integer(n - 1);
+
+	// start from an upper bound of the root, then the iteration decreases monotonically.
+	// for value > 1, (1 + (value - 1) / n) ^ n >= value by Bernoulli's inequality.
+	let mut root = if value > FixedU128::one() {
+		FixedU128::one().checked_add(&value.checked_sub(&FixedU128::one())?.checked_div(&n_fixed)?)?
+	} else {
+		FixedU128::one()
+	};
+	for _ in 0..MAX_ITERATIONS {
+		// root = ((n - 1) * root + value / root ^ (n - 1)) / n
+		let next_root = n_minus_one
+			.checked_mul(&root)?
+			.checked_add(&value.checked_div(&checked_pow(root, n - 1)?)?)?
+			.checked_div(&n_fixed)?;
+		if next_root >= root {
+			break;
+		}
+		root = next_root;
+	}
+	Some(root)
+}
+
+/// Calculate `base ^ (numerator / denominator)`.
+fn checked_pow_ratio(base: FixedU128, numerator: u32, denominator: u32) -> Option<FixedU128> {
+	let divisor = gcd(numerator, denominator);
+	if divisor.is_zero() {
+		return None;
+	}
+	// take the root first, so that the power of base below one won't lose precision.
+	checked_pow(checked_nth_root(base, denominator / divisor)?, numerator / divisor)
+}
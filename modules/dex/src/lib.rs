@@ -21,7 +21,8 @@
 //! ## Overview
 //!
 //! Built-in decentralized exchange modules in Acala network, the swap
-//! mechanism refers to the design of Uniswap V2. In addition to being used for
+//! mechanism refers to the design of Uniswap V2, and trading pairs can also
+//! choose weighted or amplified curve for pricing. In addition to being used for
 //! trading, DEX also participates in CDP liquidation, which is faster than
 //! liquidation by auction when the liquidity is sufficient. And providing
 //! market making liquidity for DEX will also receive stable currency as
@@ -34,26 +35,53 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::StorageVersion,
+	transactional,
+	weights::{GetDispatchInfo, PostDispatchInfo},
+	BoundedVec, PalletId,
+};
 use frame_system::pallet_prelude::*;
+use module_dex_rpc_runtime_api::{LiquidityShareInfo, SwapQuote, TradingPairInfo, TradingPairState};
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
 use scale_info::TypeInfo;
 use sp_core::{H160, U256};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, Bounded, CheckedDiv, CheckedSub, Dispatchable, One, Saturating, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
 };
-use sp_std::{convert::TryInto, prelude::*, vec};
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, prelude::*, vec};
 use support::{CurrencyIdMapping, DEXIncentives, DEXManager, ExchangeRate, Ratio};
 
+pub mod curve;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
 
+pub use curve::SwapCurve;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The maximum number of trading paths that a swap can be split across.
+pub const MAX_SWAP_SPLIT_PATHS: u32 = 4;
+
+/// The number of parts that the amount of a split swap is divided into, each part is
+/// allocated to the trading path with the best price at that moment.
+pub const SWAP_SPLIT_PARTS: u32 = 10;
+
+/// The maximum number of currencies whose Enabled trading pairs are read by the on-chain trading
+/// path search.
+pub const MAX_SEARCH_TRADING_PAIRS: u32 = 32;
+
+/// The maximum number of trading paths that the on-chain trading path search returns, each of
+/// them is simulated for every part of a split swap.
+pub const MAX_SEARCH_TRADING_PATHS: u32 = 8;
+
 /// Parameters of TradingPair in Provisioning status
 #[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
 pub struct ProvisioningParameters<Balance, BlockNumber> {
@@ -98,7 +126,7 @@ pub mod module {
 		/// Currency for transfer currencies
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
-		/// Trading fee rate
+		/// Default trading fee rate, used by trading pairs without their own fee rate.
 		/// The first item of the tuple is the numerator of the fee rate, second
 		/// item is the denominator, fee_rate = numerator / denominator,
 		/// use (u32, u32) over `Rate` type to minimize internal division
@@ -106,6 +134,17 @@ pub mod module {
 		#[pallet::constant]
 		type GetExchangeFee: Get<(u32, u32)>;
 
+		/// The share of trading fee that goes to the protocol, the rest is left in the
+		/// liquidity pool for liquidity providers.
+		#[pallet::constant]
+		type ProtocolFeeShare: Get<Ratio>;
+
+		/// The account that receives the protocol fee, which is paid in the supply currency of
+		/// swaps. It should not be an account whose balances are accounted as collateral, e.g.
+		/// cdp-treasury.
+		#[pallet::constant]
+		type ProtocolFeeReceiver: Get<Self::AccountId>;
+
 		/// The limit for length of trading path
 		#[pallet::constant]
 		type TradingPathLimit: Get<u32>;
@@ -126,6 +165,25 @@ pub mod module {
 
 		/// The origin which may list, enable or disable trading pairs.
 		type ListingOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The period of blocks to record the price observation of trading pair,
+		/// at most one observation is recorded in a period.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of price observations kept for a trading pair, the
+		/// longest TWAP window is about `PriceObservationPeriod * MaxPriceObservations`.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
+		/// The number of blocks after `not_before` of a provisioning trading pair, after which
+		/// the provisioning can be aborted if it doesn't meet the target.
+		#[pallet::constant]
+		type ExtendedProvisioningBlocks: Get<Self::BlockNumber>;
+
+		/// The callback call dispatched by flash swap, before the borrowed amount is
+		/// repaid.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo> + GetDispatchInfo;
 	}
 
 	#[pallet::error]
@@ -168,6 +226,26 @@ pub mod module {
 		UnqualifiedProvision,
 		/// Trading pair is still provisioning
 		StillProvisioning,
+		/// The parameters of swap curve are invalid
+		InvalidSwapCurve,
+		/// The swap curve can not be changed while the liquidity pool is not empty
+		SwapCurveChangeNotAllowed,
+		/// There's no available trading path between the currencies
+		NoAvailableTradingPath,
+		/// The number of trading paths to split the swap across is invalid
+		InvalidSplitPathsNumber,
+		/// The exchange fee rate is invalid
+		InvalidExchangeFee,
+		/// The provisioning is unqualified to be aborted, it is not expired or has met the target
+		UnqualifiedAbort,
+		/// The provision is not allowed to be refunded, the provisioning must be aborted
+		NotAllowedRefund,
+		/// The provisioning was aborted, the provision can only be refunded
+		ProvisioningAborted,
+		/// The provision of the aborted provisioning has not been fully refunded
+		ProvisionNotRefunded,
+		/// The liquidity pool is locked by an ongoing flash swap
+		FlashSwapLocked,
 	}
 
 	#[pallet::event]
@@ -195,6 +273,21 @@ pub mod module {
 		/// Provisioning trading pair convert to Enabled. \[trading_pair,
 		/// pool_0_amount, pool_1_amount, total_share_amount\]
 		ProvisioningToEnabled(TradingPair, Balance, Balance, Balance),
+		/// Flash swap from liquidity pool success. \[who, borrow_currency_id,
+		/// borrow_amount, repay_currency_id, repay_amount\]
+		FlashSwap(T::AccountId, CurrencyId, Balance, CurrencyId, Balance),
+		/// Set the exchange fee rate of trading pair. \[trading_pair,
+		/// exchange_fee\]
+		SetExchangeFee(TradingPair, (u32, u32)),
+		/// Exchange fee collected from a swap. \[trading_pair, currency_id,
+		/// liquidity_provider_fee_amount, protocol_fee_amount\]
+		CollectExchangeFee(TradingPair, CurrencyId, Balance, Balance),
+		/// Provisioning of trading pair is aborted. \[trading_pair,
+		/// accumulated_provision_0, accumulated_provision_1\]
+		ProvisioningAborted(TradingPair, Balance, Balance),
+		/// Refund provision of aborted provisioning success. \[who, currency_id_0,
+		/// contribution_0, currency_id_1, contribution_1\]
+		RefundProvision(T::AccountId, CurrencyId, Balance, CurrencyId, Balance),
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -212,6 +305,15 @@ pub mod module {
 	pub type TradingPairStatuses<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, TradingPairStatus<Balance, T::BlockNumber>, ValueQuery>;
 
+	/// The currencies that have an Enabled trading pair with the currency, used by the trading
+	/// path search.
+	///
+	/// EnabledTradingPairCurrencies: map CurrencyId => Vec<CurrencyId>
+	#[pallet::storage]
+	#[pallet::getter(fn enabled_trading_pair_currencies)]
+	pub type EnabledTradingPairCurrencies<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, Vec<CurrencyId>, ValueQuery>;
+
 	/// Provision of TradingPair by AccountId.
 	///
 	/// ProvisioningPool: double_map TradingPair, AccountId => (Balance,
@@ -229,6 +331,53 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// Swap curve for TradingPair, the curve parameters are ordered as the
+	/// currencies of TradingPair.
+	///
+	/// TradingPairSwapCurves: map TradingPair => SwapCurve
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_swap_curves)]
+	pub type TradingPairSwapCurves<T: Config> = StorageMap<_, Twox64Concat, TradingPair, SwapCurve, ValueQuery>;
+
+	/// Exchange fee rate for TradingPair, `GetExchangeFee` is used if it's not set.
+	///
+	/// TradingPairExchangeFees: map TradingPair => Option<(u32, u32)>
+	#[pallet::storage]
+	#[pallet::getter(fn trading_pair_exchange_fees)]
+	pub type TradingPairExchangeFees<T: Config> = StorageMap<_, Twox64Concat, TradingPair, (u32, u32), OptionQuery>;
+
+	/// Cumulative prices of TradingPair, updated before the liquidity pool changes.
+	/// The first item is the accumulation of `pool_1 / pool_0` per block, the second
+	/// item is the accumulation of `pool_0 / pool_1` per block, and the last item is
+	/// the block number of the last update.
+	///
+	/// PriceCumulatives: map TradingPair => (U256, U256, BlockNumber)
+	#[pallet::storage]
+	#[pallet::getter(fn price_cumulatives)]
+	pub type PriceCumulatives<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, (U256, U256, T::BlockNumber), ValueQuery>;
+
+	/// Observations of the cumulative prices of TradingPair, ordered by block number.
+	///
+	/// PriceObservations: map TradingPair => Vec<(BlockNumber, U256, U256)>
+	#[pallet::storage]
+	#[pallet::getter(fn price_observations)]
+	pub type PriceObservations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		TradingPair,
+		BoundedVec<(T::BlockNumber, U256, U256), T::MaxPriceObservations>,
+		ValueQuery,
+	>;
+
+	/// The TradingPairs whose liquidity pool is lent out by an ongoing flash swap, the
+	/// liquidity pool can not be used until the flash swap is repaid.
+	///
+	/// FlashSwapLocks: map TradingPair => bool
+	#[pallet::storage]
+	#[pallet::getter(fn flash_swap_locks)]
+	pub type FlashSwapLocks<T: Config> = StorageMap<_, Twox64Concat, TradingPair, bool, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -266,6 +415,7 @@ pub mod module {
 
 			self.initial_enabled_trading_pairs.iter().for_each(|trading_pair| {
 				TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+				<Pallet<T>>::insert_enabled_trading_pair(*trading_pair);
 			});
 
 			self.initial_added_liquidity_pools
@@ -293,7 +443,11 @@ pub mod module {
 		}
 	}
 
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -337,6 +491,111 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Trading with DEX through the best trading paths found on-chain, swap with exact
+		/// supply amount. The supply amount can be split across at most `max_paths` paths.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `supply_amount`: exact supply amount.
+		/// - `min_target_amount`: acceptable minimum target amount.
+		/// - `max_paths`: the maximum number of trading paths to split the swap across.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::swap_with_exact_supply_by_best_paths(*max_paths, MAX_SEARCH_TRADING_PATHS)
+				.saturating_add(T::DbWeight::get().reads(MAX_SEARCH_TRADING_PAIRS.into()))
+		)]
+		#[transactional]
+		pub fn swap_with_exact_supply_by_best_paths(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] supply_amount: Balance,
+			#[pallet::compact] min_target_amount: Balance,
+			max_paths: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_supply_by_best_paths(
+				&who,
+				supply_currency_id,
+				target_currency_id,
+				supply_amount,
+				min_target_amount,
+				max_paths,
+			)?;
+			Ok(())
+		}
+
+		/// Trading with DEX through the best trading paths found on-chain, swap with exact
+		/// target amount. The target amount can be split across at most `max_paths` paths.
+		///
+		/// - `supply_currency_id`: supply currency id.
+		/// - `target_currency_id`: target currency id.
+		/// - `target_amount`: exact target amount.
+		/// - `max_supply_amount`: acceptable maximum supply amount.
+		/// - `max_paths`: the maximum number of trading paths to split the swap across.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::swap_with_exact_target_by_best_paths(*max_paths, MAX_SEARCH_TRADING_PATHS)
+				.saturating_add(T::DbWeight::get().reads(MAX_SEARCH_TRADING_PAIRS.into()))
+		)]
+		#[transactional]
+		pub fn swap_with_exact_target_by_best_paths(
+			origin: OriginFor<T>,
+			supply_currency_id: CurrencyId,
+			target_currency_id: CurrencyId,
+			#[pallet::compact] target_amount: Balance,
+			#[pallet::compact] max_supply_amount: Balance,
+			max_paths: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_swap_with_exact_target_by_best_paths(
+				&who,
+				supply_currency_id,
+				target_currency_id,
+				target_amount,
+				max_supply_amount,
+				max_paths,
+			)?;
+			Ok(())
+		}
+
+		/// Flash swap from the liquidity pool of trading pair. The borrowed amount is
+		/// transferred to caller first, then `callback` is dispatched by caller, and the
+		/// borrowed amount with fee must be repaid in the end, otherwise the whole
+		/// transaction is reverted.
+		///
+		/// - `borrow_currency_id`: the currency id to borrow.
+		/// - `paired_currency_id`: the other currency id of the trading pair.
+		/// - `borrow_amount`: the amount to borrow.
+		/// - `repay_currency_id`: the currency id to repay, it can be `borrow_currency_id` as a
+		///   flash loan, or `paired_currency_id` as a flash swap.
+		/// - `max_repay_amount`: acceptable maximum repay amount.
+		/// - `callback`: the call dispatched after the borrowed amount is received.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::flash_swap().saturating_add(callback.get_dispatch_info().weight)
+		)]
+		#[transactional]
+		pub fn flash_swap(
+			origin: OriginFor<T>,
+			borrow_currency_id: CurrencyId,
+			paired_currency_id: CurrencyId,
+			#[pallet::compact] borrow_amount: Balance,
+			repay_currency_id: CurrencyId,
+			#[pallet::compact] max_repay_amount: Balance,
+			callback: Box<<T as Config>::Call>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let callback_origin: T::Origin = frame_system::RawOrigin::Signed(who.clone()).into();
+			Self::do_flash_swap(
+				&who,
+				borrow_currency_id,
+				paired_currency_id,
+				borrow_amount,
+				repay_currency_id,
+				max_repay_amount,
+				|| callback.dispatch(callback_origin).map(|_| ()).map_err(|e| e.error),
+			)?;
+			Ok(())
+		}
+
 		/// Add liquidity to Enabled trading pair.
 		/// - Add provision success will record the provision, issue shares to caller in the initial
 		///   exchange rate when trading pair convert to Enabled.
@@ -457,6 +716,9 @@ pub mod module {
 		}
 
 		/// List a new provisioning trading pair.
+		///
+		/// - `swap_curve`: the pricing curve of the trading pair, the curve parameters are ordered
+		///   as (currency_id_a, currency_id_b).
 		#[pallet::weight((<T as Config>::WeightInfo::list_provisioning(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn list_provisioning(
@@ -468,6 +730,7 @@ pub mod module {
 			#[pallet::compact] target_provision_a: Balance,
 			#[pallet::compact] target_provision_b: Balance,
 			#[pallet::compact] not_before: T::BlockNumber,
+			swap_curve: SwapCurve,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
 
@@ -514,6 +777,7 @@ pub mod module {
 					not_before,
 				}),
 			);
+			Self::do_set_swap_curve(trading_pair, currency_id_a, swap_curve)?;
 			Self::deposit_event(Event::ListProvisioning(trading_pair));
 			Ok(())
 		}
@@ -614,6 +878,7 @@ pub mod module {
 					)?;
 
 					// inject provision to liquidity pool
+					Self::update_price_cumulatives(trading_pair);
 					LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 						*pool_0 = pool_0.checked_add(total_provision_0).ok_or(ArithmeticError::Overflow)?;
 						*pool_1 = pool_1.checked_add(total_provision_1).ok_or(ArithmeticError::Overflow)?;
@@ -622,6 +887,7 @@ pub mod module {
 
 					// update trading_pair to Enabled status
 					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Enabled);
+					Self::insert_enabled_trading_pair(trading_pair);
 
 					// record initial exchange rate so that founders can use it to calculate their own shares
 					InitialShareExchangeRates::<T>::insert(
@@ -642,21 +908,91 @@ pub mod module {
 			Ok(())
 		}
 
+		/// Abort a Provisioning trading pair which doesn't meet the target after
+		/// `not_before` + `ExtendedProvisioningBlocks`, the trading pair becomes `Disabled`
+		/// and founders can refund their provision.
+		#[pallet::weight(<T as Config>::WeightInfo::abort_provisioning())]
+		#[transactional]
+		pub fn abort_provisioning(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+			match Self::trading_pair_statuses(trading_pair) {
+				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
+					let (total_provision_0, total_provision_1) = provisioning_parameters.accumulated_provision;
+					let expired = frame_system::Pallet::<T>::block_number()
+						> provisioning_parameters
+							.not_before
+							.saturating_add(T::ExtendedProvisioningBlocks::get());
+					let qualified = !total_provision_0.is_zero()
+						&& !total_provision_1.is_zero()
+						&& (total_provision_0 >= provisioning_parameters.target_provision.0
+							|| total_provision_1 >= provisioning_parameters.target_provision.1);
+					ensure!(expired && !qualified, Error::<T>::UnqualifiedAbort);
+
+					TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::<_, _>::Disabled);
+					Self::deposit_event(Event::ProvisioningAborted(
+						trading_pair,
+						total_provision_0,
+						total_provision_1,
+					));
+				}
+				_ => return Err(Error::<T>::MustBeProvisioning.into()),
+			}
+
+			Ok(())
+		}
+
+		/// Refund provision for founders who have participated in an aborted provisioning.
+		///
+		/// - `owner`: founder account.
+		/// - `currency_id_a`: currency id A.
+		/// - `currency_id_b`: currency id B.
+		#[pallet::weight(<T as Config>::WeightInfo::refund_provision())]
+		#[transactional]
+		pub fn refund_provision(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+			Self::do_refund_provision(&owner, currency_id_a, currency_id_b)?;
+			Ok(())
+		}
+
 		/// Enable a trading pair
-		/// if the status of trading pair is `Disabled`, or `Provisioning` without any accumulated
-		/// provision, enable it directly.
+		/// if the status of trading pair is `Disabled` without unrefunded provision of an aborted
+		/// provisioning, or `Provisioning` without any accumulated provision, enable it directly.
+		///
+		/// - `swap_curve`: the pricing curve of the trading pair, the curve parameters are ordered
+		///   as (currency_id_a, currency_id_b).
 		#[pallet::weight((<T as Config>::WeightInfo::enable_trading_pair(), DispatchClass::Operational))]
 		#[transactional]
 		pub fn enable_trading_pair(
 			origin: OriginFor<T>,
 			currency_id_a: CurrencyId,
 			currency_id_b: CurrencyId,
+			swap_curve: SwapCurve,
 		) -> DispatchResult {
 			T::ListingOrigin::ensure_origin(origin)?;
 			let trading_pair =
 				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 			match Self::trading_pair_statuses(trading_pair) {
-				TradingPairStatus::<_, _>::Disabled => {}
+				TradingPairStatus::<_, _>::Disabled => {
+					// the refund of an aborted provisioning requires the pair to stay `Disabled`
+					ensure!(
+						InitialShareExchangeRates::<T>::contains_key(trading_pair)
+							|| ProvisioningPool::<T>::iter_prefix(trading_pair).next().is_none(),
+						Error::<T>::ProvisionNotRefunded
+					);
+				}
 				TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
 					ensure!(
 						provisioning_parameters.accumulated_provision.0.is_zero()
@@ -667,7 +1003,9 @@ pub mod module {
 				TradingPairStatus::<_, _>::Enabled => return Err(Error::<T>::AlreadyEnabled.into()),
 			}
 
+			Self::do_set_swap_curve(trading_pair, currency_id_a, swap_curve)?;
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Enabled);
+			Self::insert_enabled_trading_pair(trading_pair);
 			Self::deposit_event(Event::EnableTradingPair(trading_pair));
 			Ok(())
 		}
@@ -692,9 +1030,40 @@ pub mod module {
 			);
 
 			TradingPairStatuses::<T>::insert(trading_pair, TradingPairStatus::Disabled);
+			Self::remove_enabled_trading_pair(trading_pair);
 			Self::deposit_event(Event::DisableTradingPair(trading_pair));
 			Ok(())
 		}
+
+		/// Set the exchange fee rate of a trading pair.
+		///
+		/// - `exchange_fee`: the fee rate as (numerator, denominator), `None` means to use the
+		///   default `GetExchangeFee`.
+		#[pallet::weight((<T as Config>::WeightInfo::set_exchange_fee(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_exchange_fee(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			exchange_fee: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+			if let Some((fee_numerator, fee_denominator)) = exchange_fee {
+				ensure!(fee_numerator < fee_denominator, Error::<T>::InvalidExchangeFee);
+			}
+
+			match exchange_fee {
+				Some(exchange_fee) => TradingPairExchangeFees::<T>::insert(trading_pair, exchange_fee),
+				None => TradingPairExchangeFees::<T>::remove(trading_pair),
+			}
+			Self::deposit_event(Event::SetExchangeFee(
+				trading_pair,
+				Self::get_exchange_fee(trading_pair),
+			));
+			Ok(())
+		}
 	}
 }
 
@@ -703,6 +1072,29 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	fn do_set_swap_curve(
+		trading_pair: TradingPair,
+		currency_id_a: CurrencyId,
+		swap_curve: SwapCurve,
+	) -> DispatchResult {
+		ensure!(swap_curve.is_valid(), Error::<T>::InvalidSwapCurve);
+		let swap_curve = if currency_id_a == trading_pair.first() {
+			swap_curve
+		} else {
+			swap_curve.reversed()
+		};
+
+		// changing the curve of a pool with liquidity would move the price immediately.
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		ensure!(
+			(pool_0.is_zero() && pool_1.is_zero()) || Self::trading_pair_swap_curves(trading_pair) == swap_curve,
+			Error::<T>::SwapCurveChangeNotAllowed
+		);
+
+		TradingPairSwapCurves::<T>::insert(trading_pair, swap_curve);
+		Ok(())
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -716,6 +1108,11 @@ impl<T: Config> Pallet<T> {
 
 		ProvisioningPool::<T>::try_mutate_exists(trading_pair, who, |maybe_contribution| -> DispatchResult {
 			if let Some((contribution_0, contribution_1)) = maybe_contribution.take() {
+				// the initial exchange rates are absent if the provisioning was aborted.
+				ensure!(
+					InitialShareExchangeRates::<T>::contains_key(trading_pair),
+					Error::<T>::ProvisioningAborted
+				);
 				let (exchange_rate_0, exchange_rate_1) = Self::initial_share_exchange_rates(trading_pair);
 				let shares_from_provision_0 = exchange_rate_0
 					.checked_mul_int(contribution_0)
@@ -748,6 +1145,38 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_refund_provision(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
+		let trading_pair =
+			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Disabled
+			) && !InitialShareExchangeRates::<T>::contains_key(trading_pair),
+			Error::<T>::NotAllowedRefund
+		);
+
+		ProvisioningPool::<T>::try_mutate_exists(trading_pair, who, |maybe_contribution| -> DispatchResult {
+			if let Some((contribution_0, contribution_1)) = maybe_contribution.take() {
+				let module_account_id = Self::account_id();
+				T::Currency::transfer(trading_pair.first(), &module_account_id, who, contribution_0)?;
+				T::Currency::transfer(trading_pair.second(), &module_account_id, who, contribution_1)?;
+
+				// decrease ref count
+				frame_system::Pallet::<T>::dec_consumers(who);
+
+				Self::deposit_event(Event::RefundProvision(
+					who.clone(),
+					trading_pair.first(),
+					contribution_0,
+					trading_pair.second(),
+					contribution_1,
+				));
+			}
+			Ok(())
+		})
+	}
+
 	fn do_add_provision(
 		who: &T::AccountId,
 		currency_id_a: CurrencyId,
@@ -847,7 +1276,9 @@ impl<T: Config> Pallet<T> {
 			!max_amount_a.is_zero() && !max_amount_b.is_zero(),
 			Error::<T>::InvalidLiquidityIncrement
 		);
+		ensure!(!Self::flash_swap_locks(trading_pair), Error::<T>::FlashSwapLocked);
 
+		Self::update_price_cumulatives(trading_pair);
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 			let dex_share_currency_id = trading_pair.dex_share_currency_id();
 			let total_shares = T::Currency::total_issuance(dex_share_currency_id);
@@ -954,7 +1385,9 @@ impl<T: Config> Pallet<T> {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
 		let dex_share_currency_id = trading_pair.dex_share_currency_id();
+		ensure!(!Self::flash_swap_locks(trading_pair), Error::<T>::FlashSwapLocked);
 
+		Self::update_price_cumulatives(trading_pair);
 		LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 			let (min_withdrawn_0, min_withdrawn_1) = if currency_id_a == trading_pair.first() {
 				(min_withdrawn_a, min_withdrawn_b)
@@ -995,7 +1428,8 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+	/// Get the liquidity pool of trading pair, ordered as (currency_id_a, currency_id_b).
+	pub fn get_liquidity(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
 			let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
 			if currency_id_a == trading_pair.first() {
@@ -1008,48 +1442,50 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Get how much target amount will be got for specific supply amount.
-	fn get_target_amount(supply_pool: Balance, target_pool: Balance, supply_amount: Balance) -> Balance {
-		if supply_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
-			Zero::zero()
+	/// Get the swap curve of trading pair, the curve parameters are ordered as
+	/// (currency_id_a, currency_id_b).
+	fn get_swap_curve(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> SwapCurve {
+		if let Some(trading_pair) = TradingPair::from_currency_ids(currency_id_a, currency_id_b) {
+			let swap_curve = Self::trading_pair_swap_curves(trading_pair);
+			if currency_id_a == trading_pair.first() {
+				swap_curve
+			} else {
+				swap_curve.reversed()
+			}
 		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
-			let supply_amount_with_fee: U256 =
-				U256::from(supply_amount).saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
-			let numerator: U256 = supply_amount_with_fee.saturating_mul(U256::from(target_pool));
-			let denominator: U256 = U256::from(supply_pool)
-				.saturating_mul(U256::from(fee_denominator))
-				.saturating_add(supply_amount_with_fee);
-
-			numerator
-				.checked_div(denominator)
-				.and_then(|n| TryInto::<Balance>::try_into(n).ok())
-				.unwrap_or_else(Zero::zero)
+			Default::default()
 		}
 	}
 
+	/// Get the exchange fee rate of trading pair.
+	pub fn get_exchange_fee(trading_pair: TradingPair) -> (u32, u32) {
+		Self::trading_pair_exchange_fees(trading_pair).unwrap_or_else(T::GetExchangeFee::get)
+	}
+
+	/// Get how much target amount will be got for specific supply amount.
+	fn get_target_amount(
+		swap_curve: SwapCurve,
+		supply_pool: Balance,
+		target_pool: Balance,
+		supply_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		swap_curve.get_target_amount(supply_pool, target_pool, supply_amount, exchange_fee)
+	}
+
 	/// Get how much supply amount will be paid for specific target amount.
-	fn get_supply_amount(supply_pool: Balance, target_pool: Balance, target_amount: Balance) -> Balance {
-		if target_amount.is_zero() || supply_pool.is_zero() || target_pool.is_zero() {
-			Zero::zero()
-		} else {
-			let (fee_numerator, fee_denominator) = T::GetExchangeFee::get();
-			let numerator: U256 = U256::from(supply_pool)
-				.saturating_mul(U256::from(target_amount))
-				.saturating_mul(U256::from(fee_denominator));
-			let denominator: U256 = U256::from(target_pool)
-				.saturating_sub(U256::from(target_amount))
-				.saturating_mul(U256::from(fee_denominator.saturating_sub(fee_numerator)));
-
-			numerator
-				.checked_div(denominator)
-				.and_then(|r| r.checked_add(U256::one())) // add 1 to result so that correct the possible losses caused by remainder discarding in
-				.and_then(|n| TryInto::<Balance>::try_into(n).ok())
-				.unwrap_or_else(Zero::zero)
-		}
+	fn get_supply_amount(
+		swap_curve: SwapCurve,
+		supply_pool: Balance,
+		target_pool: Balance,
+		target_amount: Balance,
+		exchange_fee: (u32, u32),
+	) -> Balance {
+		swap_curve.get_supply_amount(supply_pool, target_pool, target_amount, exchange_fee)
 	}
 
-	fn get_target_amounts(
+	/// Get the amounts of the currencies on the path when swapping with exact supply amount.
+	pub fn get_target_amounts(
 		path: &[CurrencyId],
 		supply_amount: Balance,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
@@ -1077,7 +1513,14 @@ impl<T: Config> Pallet<T> {
 				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let target_amount = Self::get_target_amount(supply_pool, target_pool, target_amounts[i]);
+			let swap_curve = Self::get_swap_curve(path[i], path[i + 1]);
+			let target_amount = Self::get_target_amount(
+				swap_curve,
+				supply_pool,
+				target_pool,
+				target_amounts[i],
+				Self::get_exchange_fee(trading_pair),
+			);
 			ensure!(!target_amount.is_zero(), Error::<T>::ZeroTargetAmount);
 
 			target_amounts[i + 1] = target_amount;
@@ -1087,7 +1530,8 @@ impl<T: Config> Pallet<T> {
 		Ok(target_amounts)
 	}
 
-	fn get_supply_amounts(
+	/// Get the amounts of the currencies on the path when swapping with exact target amount.
+	pub fn get_supply_amounts(
 		path: &[CurrencyId],
 		target_amount: Balance,
 	) -> sp_std::result::Result<Vec<Balance>, DispatchError> {
@@ -1115,7 +1559,14 @@ impl<T: Config> Pallet<T> {
 				!supply_pool.is_zero() && !target_pool.is_zero(),
 				Error::<T>::InsufficientLiquidity
 			);
-			let supply_amount = Self::get_supply_amount(supply_pool, target_pool, supply_amounts[i]);
+			let swap_curve = Self::get_swap_curve(path[i - 1], path[i]);
+			let supply_amount = Self::get_supply_amount(
+				swap_curve,
+				supply_pool,
+				target_pool,
+				supply_amounts[i],
+				Self::get_exchange_fee(trading_pair),
+			);
 			ensure!(!supply_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
 
 			supply_amounts[i - 1] = supply_amount;
@@ -1125,6 +1576,137 @@ impl<T: Config> Pallet<T> {
 		Ok(supply_amounts)
 	}
 
+	/// Get the exchange fee charged on each hop of the path, `amounts` is the result of
+	/// `get_target_amounts` or `get_supply_amounts`.
+	pub fn get_exchange_fee_amounts(path: &[CurrencyId], amounts: &[Balance]) -> Vec<Balance> {
+		path.windows(2)
+			.zip(amounts)
+			.map(|(pair, supply_amount)| {
+				TradingPair::from_currency_ids(pair[0], pair[1])
+					.map(|trading_pair| {
+						Self::get_exchange_fee_amount(*supply_amount, Self::get_exchange_fee(trading_pair))
+					})
+					.unwrap_or_else(Zero::zero)
+			})
+			.collect()
+	}
+
+	/// Get the price impact of swapping along the path, that is how much the price of the swap
+	/// is worse than the spot price of the path, exchange fee excluded.
+	pub fn get_price_impact(path: &[CurrencyId], amounts: &[Balance]) -> Option<Ratio> {
+		let mut price_ratio = Ratio::one();
+		for (i, pair) in path.windows(2).enumerate() {
+			let (supply_pool, target_pool) = Self::get_liquidity(pair[0], pair[1]);
+			let spot_price = Self::get_swap_curve(pair[0], pair[1]).get_spot_price(supply_pool, target_pool)?;
+			let exchange_fee = Self::get_exchange_fee(TradingPair::from_currency_ids(pair[0], pair[1])?);
+			let supply_amount = amounts
+				.get(i)?
+				.saturating_sub(Self::get_exchange_fee_amount(*amounts.get(i)?, exchange_fee));
+			let price = Ratio::checked_from_rational(*amounts.get(i + 1)?, supply_amount)?;
+			price_ratio = price_ratio.saturating_mul(price.checked_div(&spot_price)?.min(Ratio::one()));
+		}
+		Some(Ratio::one().saturating_sub(price_ratio))
+	}
+
+	/// Get the reserves of trading pair, which are the liquidity pool for enabled trading
+	/// pair or the accumulated provision for provisioning trading pair.
+	pub fn get_trading_pair_reserves(trading_pair: TradingPair) -> (Balance, Balance) {
+		match Self::trading_pair_statuses(trading_pair) {
+			TradingPairStatus::<_, _>::Provisioning(provisioning_parameters) => {
+				provisioning_parameters.accumulated_provision
+			}
+			_ => Self::liquidity_pool(trading_pair),
+		}
+	}
+
+	/// Get the amounts of liquidity pool that the dex share of trading pair is worth.
+	pub fn get_liquidity_share_value(trading_pair: TradingPair, share: Balance) -> (Balance, Balance) {
+		let total_shares = T::Currency::total_issuance(trading_pair.dex_share_currency_id());
+		let proportion = Ratio::checked_from_rational(share, total_shares).unwrap_or_default();
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+		(
+			proportion.saturating_mul_int(pool_0),
+			proportion.saturating_mul_int(pool_1),
+		)
+	}
+
+	/// Get the quote of swapping with exact supply amount along `path`, used by the RPC.
+	pub fn get_swap_quote_with_exact_supply(
+		path: Vec<CurrencyId>,
+		supply_amount: Balance,
+	) -> Option<SwapQuote<Balance>> {
+		let amounts = Self::get_target_amounts(&path, supply_amount).ok()?;
+		Some(SwapQuote {
+			exchange_fees: Self::get_exchange_fee_amounts(&path, &amounts),
+			price_impact: Self::get_price_impact(&path, &amounts)?,
+			path,
+			amounts,
+		})
+	}
+
+	/// Get the quote of swapping with exact target amount along `path`, used by the RPC.
+	pub fn get_swap_quote_with_exact_target(
+		path: Vec<CurrencyId>,
+		target_amount: Balance,
+	) -> Option<SwapQuote<Balance>> {
+		let amounts = Self::get_supply_amounts(&path, target_amount).ok()?;
+		Some(SwapQuote {
+			exchange_fees: Self::get_exchange_fee_amounts(&path, &amounts),
+			price_impact: Self::get_price_impact(&path, &amounts)?,
+			path,
+			amounts,
+		})
+	}
+
+	/// Get the info of all enabled and provisioning trading pairs, used by the RPC.
+	pub fn get_trading_pairs() -> Vec<TradingPairInfo<Balance>> {
+		TradingPairStatuses::<T>::iter()
+			.filter_map(|(trading_pair, status)| {
+				let state = match status {
+					TradingPairStatus::Enabled => TradingPairState::Enabled,
+					TradingPairStatus::Provisioning(_) => TradingPairState::Provisioning,
+					TradingPairStatus::Disabled => return None,
+				};
+				let (pool_0, pool_1) = Self::get_trading_pair_reserves(trading_pair);
+				Some(TradingPairInfo {
+					trading_pair,
+					state,
+					pool_0,
+					pool_1,
+					exchange_fee: Self::get_exchange_fee(trading_pair),
+					total_share: T::Currency::total_issuance(trading_pair.dex_share_currency_id()),
+				})
+			})
+			.collect()
+	}
+
+	/// Get the dex shares of `who` and the amounts of liquidity pool they're worth, used by
+	/// the RPC. `deposited_share` returns the share of the dex share currency deposited
+	/// elsewhere (e.g. incentives) which is still owned by `who`.
+	pub fn get_liquidity_shares(
+		who: &T::AccountId,
+		deposited_share: impl Fn(CurrencyId) -> Balance,
+	) -> Vec<LiquidityShareInfo<Balance>> {
+		TradingPairStatuses::<T>::iter_keys()
+			.filter_map(|trading_pair| {
+				let lp_currency_id = trading_pair.dex_share_currency_id();
+				let share =
+					T::Currency::total_balance(lp_currency_id, who).saturating_add(deposited_share(lp_currency_id));
+				if share.is_zero() {
+					return None;
+				}
+				let (amount_0, amount_1) = Self::get_liquidity_share_value(trading_pair, share);
+				Some(LiquidityShareInfo {
+					trading_pair,
+					share,
+					total_share: T::Currency::total_issuance(lp_currency_id),
+					amount_0,
+					amount_1,
+				})
+			})
+			.collect()
+	}
+
 	fn _swap(
 		supply_currency_id: CurrencyId,
 		target_currency_id: CurrencyId,
@@ -1132,29 +1714,107 @@ impl<T: Config> Pallet<T> {
 		target_decrement: Balance,
 	) -> DispatchResult {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(supply_currency_id, target_currency_id) {
+			ensure!(!Self::flash_swap_locks(trading_pair), Error::<T>::FlashSwapLocked);
+			let swap_curve = Self::get_swap_curve(supply_currency_id, target_currency_id);
+			let exchange_fee = Self::get_exchange_fee(trading_pair);
+
+			// the exchange fee is charged on the supply increment, the protocol share of it is
+			// taken out of the liquidity pool.
+			let fee_amount = Self::get_exchange_fee_amount(supply_increment, exchange_fee);
+			let protocol_fee_amount = Self::collect_exchange_fee(trading_pair, supply_currency_id, fee_amount)?;
+			let pool_increment = supply_increment.saturating_sub(protocol_fee_amount);
+
 			LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
 				let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+				let (supply_pool, target_pool) = if supply_currency_id == trading_pair.first() {
+					(*pool_0, *pool_1)
+				} else {
+					(*pool_1, *pool_0)
+				};
 
 				if supply_currency_id == trading_pair.first() {
-					*pool_0 = pool_0.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
+					*pool_0 = pool_0.checked_add(pool_increment).ok_or(ArithmeticError::Overflow)?;
 					*pool_1 = pool_1.checked_sub(target_decrement).ok_or(ArithmeticError::Underflow)?;
 				} else {
 					*pool_0 = pool_0.checked_sub(target_decrement).ok_or(ArithmeticError::Underflow)?;
-					*pool_1 = pool_1.checked_add(supply_increment).ok_or(ArithmeticError::Overflow)?;
+					*pool_1 = pool_1.checked_add(pool_increment).ok_or(ArithmeticError::Overflow)?;
 				}
 
-				// invariant check to ensure the constant product formulas (k = x * y)
-				let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
-				ensure!(
-					invariant_after_swap >= invariant_before_swap,
-					Error::<T>::InvariantCheckFailed,
-				);
+				match swap_curve {
+					SwapCurve::ConstantProduct => {
+						// invariant check to ensure the constant product formulas (k = x * y)
+						let invariant_after_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
+						ensure!(
+							invariant_after_swap >= invariant_before_swap,
+							Error::<T>::InvariantCheckFailed,
+						);
+					}
+					_ => {
+						// the pool must not pay more than the curve quotes for the supply increment
+						ensure!(
+							target_decrement
+								<= Self::get_target_amount(
+									swap_curve,
+									supply_pool,
+									target_pool,
+									supply_increment,
+									exchange_fee
+								),
+							Error::<T>::InvariantCheckFailed,
+						);
+					}
+				}
+
+				// the liquidity pool in storage is not changed until the closure returns
+				Self::update_price_cumulatives(trading_pair);
 				Ok(())
 			})?;
 		}
 		Ok(())
 	}
 
+	/// Get the exchange fee amount charged on the supply amount.
+	fn get_exchange_fee_amount(supply_amount: Balance, exchange_fee: (u32, u32)) -> Balance {
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		U256::from(supply_amount)
+			.saturating_mul(U256::from(fee_numerator))
+			.checked_div(U256::from(fee_denominator))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Transfer the protocol share of exchange fee to `ProtocolFeeReceiver`, the rest is
+	/// left to liquidity providers. Returns the protocol fee amount.
+	fn collect_exchange_fee(
+		trading_pair: TradingPair,
+		currency_id: CurrencyId,
+		fee_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		if fee_amount.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		let protocol_fee_amount = T::ProtocolFeeShare::get()
+			.saturating_mul_int(fee_amount)
+			.min(fee_amount);
+		if !protocol_fee_amount.is_zero() {
+			T::Currency::transfer(
+				currency_id,
+				&Self::account_id(),
+				&T::ProtocolFeeReceiver::get(),
+				protocol_fee_amount,
+			)?;
+		}
+
+		Self::deposit_event(Event::CollectExchangeFee(
+			trading_pair,
+			currency_id,
+			fee_amount.saturating_sub(protocol_fee_amount),
+			protocol_fee_amount,
+		));
+		Ok(protocol_fee_amount)
+	}
+
 	fn _swap_by_path(path: &[CurrencyId], amounts: &[Balance]) -> DispatchResult {
 		let mut i: usize = 0;
 		while i + 1 < path.len() {
@@ -1215,6 +1875,530 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Swap(who.clone(), path.to_vec(), amounts));
 		Ok(actual_supply_amount)
 	}
+
+	/// Get the cumulative prices of trading pair at block `now`, the prices of the current
+	/// liquidity pool are accumulated counterfactually since the last update.
+	fn get_price_cumulatives(trading_pair: TradingPair, now: T::BlockNumber) -> (U256, U256) {
+		let (mut price_0_cumulative, mut price_1_cumulative, last_updated) = Self::price_cumulatives(trading_pair);
+		let (pool_0, pool_1) = Self::liquidity_pool(trading_pair);
+
+		if now > last_updated && !pool_0.is_zero() && !pool_1.is_zero() {
+			let elapsed = U256::from(now.saturating_sub(last_updated).saturated_into::<u128>());
+			let price_0 = ExchangeRate::checked_from_rational(pool_1, pool_0).unwrap_or_else(ExchangeRate::max_value);
+			let price_1 = ExchangeRate::checked_from_rational(pool_0, pool_1).unwrap_or_else(ExchangeRate::max_value);
+			price_0_cumulative =
+				price_0_cumulative.saturating_add(U256::from(price_0.into_inner()).saturating_mul(elapsed));
+			price_1_cumulative =
+				price_1_cumulative.saturating_add(U256::from(price_1.into_inner()).saturating_mul(elapsed));
+		}
+
+		(price_0_cumulative, price_1_cumulative)
+	}
+
+	/// Accumulate the prices of trading pair before its liquidity pool changes, and record
+	/// the price observation if it's the first update in this `PriceObservationPeriod`.
+	fn update_price_cumulatives(trading_pair: TradingPair) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let maybe_last_updated = PriceCumulatives::<T>::try_get(trading_pair)
+			.ok()
+			.map(|(_, _, last_updated)| last_updated);
+		let (price_0_cumulative, price_1_cumulative) = Self::get_price_cumulatives(trading_pair, now);
+		PriceCumulatives::<T>::insert(trading_pair, (price_0_cumulative, price_1_cumulative, now));
+
+		let period = T::PriceObservationPeriod::get().max(One::one());
+		if maybe_last_updated.map_or(true, |last_updated| last_updated / period < now / period) {
+			PriceObservations::<T>::mutate(trading_pair, |observations| {
+				if !observations.is_empty() && observations.len() >= T::MaxPriceObservations::get().saturated_into() {
+					observations.remove(0);
+				}
+				// only fails if `MaxPriceObservations` is zero
+				let _ = observations.try_push((now, price_0_cumulative, price_1_cumulative));
+			});
+		}
+	}
+
+	/// Get the time-weighted average price of `currency_id_a` in `currency_id_b` over at
+	/// least `window` blocks, which is the average since the latest price observation that
+	/// is not later than `window` blocks ago.
+	pub fn get_time_weighted_average_price(
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		window: T::BlockNumber,
+	) -> Option<ExchangeRate> {
+		let trading_pair = TradingPair::from_currency_ids(currency_id_a, currency_id_b)?;
+		if window.is_zero() {
+			return None;
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		let window_start = now.checked_sub(&window)?;
+		let (observed_at, observed_price_0_cumulative, observed_price_1_cumulative) =
+			Self::price_observations(trading_pair)
+				.iter()
+				.rev()
+				.find(|(observed_at, _, _)| *observed_at <= window_start)
+				.cloned()?;
+
+		let (price_0_cumulative, price_1_cumulative) = Self::get_price_cumulatives(trading_pair, now);
+		let price_cumulative_increment = if currency_id_a == trading_pair.first() {
+			price_0_cumulative.checked_sub(observed_price_0_cumulative)?
+		} else {
+			price_1_cumulative.checked_sub(observed_price_1_cumulative)?
+		};
+		let elapsed = U256::from(now.saturating_sub(observed_at).saturated_into::<u128>());
+
+		price_cumulative_increment
+			.checked_div(elapsed)
+			.and_then(|n| TryInto::<u128>::try_into(n).ok())
+			.filter(|n| !n.is_zero())
+			.map(ExchangeRate::from_inner)
+	}
+
+	/// Get the trading paths from `supply_currency_id` to `target_currency_id` through the
+	/// Enabled trading pairs, the length of paths is limited by `TradingPathLimit` and the
+	/// currencies in a path are not repeated. Shorter paths come first.
+	///
+	/// The search starts from the Enabled trading pairs of `supply_currency_id` and
+	/// `target_currency_id`, reads the Enabled trading pairs of at most
+	/// `MAX_SEARCH_TRADING_PAIRS` currencies and returns at most `MAX_SEARCH_TRADING_PATHS`
+	/// paths.
+	pub fn get_trading_paths(supply_currency_id: CurrencyId, target_currency_id: CurrencyId) -> Vec<Vec<CurrencyId>> {
+		let mut trading_paths: Vec<Vec<CurrencyId>> = vec![];
+		if supply_currency_id == target_currency_id {
+			return trading_paths;
+		}
+
+		// the last hop of a path must be a currency paired with the target
+		let target_pair_currencies = Self::enabled_trading_pair_currencies(target_currency_id);
+		let mut pair_currencies: BTreeMap<CurrencyId, Vec<CurrencyId>> = BTreeMap::new();
+		let mut reads_remaining = MAX_SEARCH_TRADING_PAIRS.saturating_sub(1);
+		let mut path = vec![supply_currency_id];
+		// search the paths level by level, so that the shorter paths are kept once the limit is reached
+		for path_length in 2..=T::TradingPathLimit::get().saturated_into() {
+			Self::search_trading_paths(
+				&target_pair_currencies,
+				&mut pair_currencies,
+				&mut reads_remaining,
+				target_currency_id,
+				path_length,
+				&mut path,
+				&mut trading_paths,
+			);
+		}
+
+		trading_paths
+	}
+
+	/// Depth-first search the trading paths of `path_length` which extend `path` to
+	/// `target_currency_id`, stop when `MAX_SEARCH_TRADING_PATHS` paths are found. The Enabled
+	/// trading pairs of the currencies are read into `pair_currencies` until `reads_remaining`
+	/// runs out.
+	fn search_trading_paths(
+		target_pair_currencies: &[CurrencyId],
+		pair_currencies: &mut BTreeMap<CurrencyId, Vec<CurrencyId>>,
+		reads_remaining: &mut u32,
+		target_currency_id: CurrencyId,
+		path_length: usize,
+		path: &mut Vec<CurrencyId>,
+		trading_paths: &mut Vec<Vec<CurrencyId>>,
+	) {
+		if trading_paths.len() >= MAX_SEARCH_TRADING_PATHS.saturated_into() {
+			return;
+		}
+		let last_currency_id = path[path.len() - 1];
+		if path.len() + 1 == path_length {
+			if target_pair_currencies.contains(&last_currency_id) {
+				path.push(target_currency_id);
+				trading_paths.push(path.clone());
+				path.pop();
+			}
+			return;
+		}
+
+		let next_currency_ids = match pair_currencies.get(&last_currency_id) {
+			Some(currency_ids) => currency_ids.clone(),
+			None => {
+				if reads_remaining.is_zero() {
+					return;
+				}
+				*reads_remaining -= 1;
+				let currency_ids = Self::enabled_trading_pair_currencies(last_currency_id);
+				pair_currencies.insert(last_currency_id, currency_ids.clone());
+				currency_ids
+			}
+		};
+		for next_currency_id in next_currency_ids {
+			if next_currency_id == target_currency_id || path.contains(&next_currency_id) {
+				continue;
+			}
+
+			path.push(next_currency_id);
+			Self::search_trading_paths(
+				target_pair_currencies,
+				pair_currencies,
+				reads_remaining,
+				target_currency_id,
+				path_length,
+				path,
+				trading_paths,
+			);
+			path.pop();
+			if trading_paths.len() >= MAX_SEARCH_TRADING_PATHS.saturated_into() {
+				return;
+			}
+		}
+	}
+
+	/// Add the currencies of an Enabled `trading_pair` to the trading pairs of each other.
+	pub(crate) fn insert_enabled_trading_pair(trading_pair: TradingPair) {
+		for (currency_id, pair_currency_id) in [
+			(trading_pair.first(), trading_pair.second()),
+			(trading_pair.second(), trading_pair.first()),
+		]
+		.iter()
+		{
+			EnabledTradingPairCurrencies::<T>::mutate(currency_id, |currency_ids| {
+				if !currency_ids.contains(pair_currency_id) {
+					currency_ids.push(*pair_currency_id);
+				}
+			});
+		}
+	}
+
+	/// Remove the currencies of a no longer Enabled `trading_pair` from the trading pairs of
+	/// each other.
+	fn remove_enabled_trading_pair(trading_pair: TradingPair) {
+		for (currency_id, pair_currency_id) in [
+			(trading_pair.first(), trading_pair.second()),
+			(trading_pair.second(), trading_pair.first()),
+		]
+		.iter()
+		{
+			EnabledTradingPairCurrencies::<T>::mutate_exists(currency_id, |maybe_currency_ids| {
+				if let Some(currency_ids) = maybe_currency_ids {
+					currency_ids.retain(|id| id != pair_currency_id);
+					if currency_ids.is_empty() {
+						*maybe_currency_ids = None;
+					}
+				}
+			});
+		}
+	}
+
+	/// Get the index of trading path that gets the most target amount for `supply_amount`,
+	/// and the liquidity change list of it.
+	fn get_best_target_amounts(
+		trading_paths: &[Vec<CurrencyId>],
+		supply_amount: Balance,
+	) -> Option<(usize, Vec<Balance>)> {
+		let mut best: Option<(usize, Vec<Balance>)> = None;
+		for (index, path) in trading_paths.iter().enumerate() {
+			if let Ok(amounts) = Self::get_target_amounts(path, supply_amount) {
+				match best {
+					Some((_, ref best_amounts))
+						if best_amounts[best_amounts.len() - 1] >= amounts[amounts.len() - 1] => {}
+					_ => best = Some((index, amounts)),
+				}
+			}
+		}
+		best
+	}
+
+	/// Get the index of trading path that costs the least supply amount for `target_amount`,
+	/// and the liquidity change list of it.
+	fn get_best_supply_amounts(
+		trading_paths: &[Vec<CurrencyId>],
+		target_amount: Balance,
+	) -> Option<(usize, Vec<Balance>)> {
+		let mut best: Option<(usize, Vec<Balance>)> = None;
+		for (index, path) in trading_paths.iter().enumerate() {
+			if let Ok(amounts) = Self::get_supply_amounts(path, target_amount) {
+				match best {
+					Some((_, ref best_amounts)) if best_amounts[0] <= amounts[0] => {}
+					_ => best = Some((index, amounts)),
+				}
+			}
+		}
+		best
+	}
+
+	/// Split `amount` into `SWAP_SPLIT_PARTS` parts, or not split it if `max_paths` is 1.
+	fn split_swap_amount(amount: Balance, max_paths: u32) -> Vec<Balance> {
+		let parts: Balance = if max_paths > 1 {
+			SWAP_SPLIT_PARTS.into()
+		} else {
+			One::one()
+		};
+		let part_amount = amount / parts;
+		let mut part_amounts: Vec<Balance> = vec![part_amount; parts.saturated_into()];
+		part_amounts[0] = amount.saturating_sub(part_amount.saturating_mul(parts.saturating_sub(One::one())));
+		part_amounts.retain(|part_amount| !part_amount.is_zero());
+		part_amounts
+	}
+
+	/// Allocate `supply_amount` to at most `max_paths` trading paths from `supply_currency_id`
+	/// to `target_currency_id`. The amount is split into parts, each part goes through the
+	/// trading path that gets the most target amount after the previous parts were swapped, so
+	/// the paths sharing the same liquidity pools are priced correctly. The swaps are
+	/// simulated in a storage transaction which is always rolled back.
+	///
+	/// Returns the list of trading path and its supply amount.
+	pub fn get_best_swap_paths_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		max_paths: u32,
+	) -> Option<Vec<(Vec<CurrencyId>, Balance)>> {
+		if supply_amount.is_zero() || max_paths.is_zero() || max_paths > MAX_SWAP_SPLIT_PATHS {
+			return None;
+		}
+		let trading_paths = Self::get_trading_paths(supply_currency_id, target_currency_id);
+
+		with_transaction(|| {
+			let mut allocations: Vec<(Vec<CurrencyId>, Balance)> = vec![];
+			for part_amount in Self::split_swap_amount(supply_amount, max_paths) {
+				// the remaining parts can only go through the allocated paths once the limit is reached
+				let candidate_paths: Vec<Vec<CurrencyId>> = if allocations.len() < max_paths.saturated_into() {
+					trading_paths.clone()
+				} else {
+					allocations.iter().map(|(path, _)| path.clone()).collect()
+				};
+				let (index, amounts) = match Self::get_best_target_amounts(&candidate_paths, part_amount) {
+					Some(best) => best,
+					None => return TransactionOutcome::Rollback(None),
+				};
+				if Self::_swap_by_path(&candidate_paths[index], &amounts).is_err() {
+					return TransactionOutcome::Rollback(None);
+				}
+
+				match allocations.iter_mut().find(|(path, _)| *path == candidate_paths[index]) {
+					Some((_, amount)) => *amount = amount.saturating_add(part_amount),
+					None => allocations.push((candidate_paths[index].clone(), part_amount)),
+				}
+			}
+			TransactionOutcome::Rollback(Some(allocations))
+		})
+	}
+
+	/// Allocate `target_amount` to at most `max_paths` trading paths from `supply_currency_id`
+	/// to `target_currency_id`. The amount is split into parts, each part goes through the
+	/// trading path that costs the least supply amount after the previous parts were swapped.
+	/// The swaps are simulated in a storage transaction which is always rolled back.
+	///
+	/// Returns the list of trading path and its target amount.
+	pub fn get_best_swap_paths_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		max_paths: u32,
+	) -> Option<Vec<(Vec<CurrencyId>, Balance)>> {
+		if target_amount.is_zero() || max_paths.is_zero() || max_paths > MAX_SWAP_SPLIT_PATHS {
+			return None;
+		}
+		let trading_paths = Self::get_trading_paths(supply_currency_id, target_currency_id);
+
+		with_transaction(|| {
+			let mut allocations: Vec<(Vec<CurrencyId>, Balance)> = vec![];
+			for part_amount in Self::split_swap_amount(target_amount, max_paths) {
+				// the remaining parts can only go through the allocated paths once the limit is reached
+				let candidate_paths: Vec<Vec<CurrencyId>> = if allocations.len() < max_paths.saturated_into() {
+					trading_paths.clone()
+				} else {
+					allocations.iter().map(|(path, _)| path.clone()).collect()
+				};
+				let (index, amounts) = match Self::get_best_supply_amounts(&candidate_paths, part_amount) {
+					Some(best) => best,
+					None => return TransactionOutcome::Rollback(None),
+				};
+				if Self::_swap_by_path(&candidate_paths[index], &amounts).is_err() {
+					return TransactionOutcome::Rollback(None);
+				}
+
+				match allocations.iter_mut().find(|(path, _)| *path == candidate_paths[index]) {
+					Some((_, amount)) => *amount = amount.saturating_add(part_amount),
+					None => allocations.push((candidate_paths[index].clone(), part_amount)),
+				}
+			}
+			TransactionOutcome::Rollback(Some(allocations))
+		})
+	}
+
+	/// Ensured atomic.
+	#[transactional]
+	fn do_swap_with_exact_supply_by_best_paths(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+		min_target_amount: Balance,
+		max_paths: u32,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		ensure!(
+			!max_paths.is_zero() && max_paths <= MAX_SWAP_SPLIT_PATHS,
+			Error::<T>::InvalidSplitPathsNumber
+		);
+		let allocations = Self::get_best_swap_paths_with_exact_supply(
+			supply_currency_id,
+			target_currency_id,
+			supply_amount,
+			max_paths,
+		)
+		.ok_or(Error::<T>::NoAvailableTradingPath)?;
+
+		let mut actual_target_amount: Balance = Zero::zero();
+		for (path, amount) in allocations {
+			let target_amount = Self::do_swap_with_exact_supply(who, &path, amount, Zero::zero())?;
+			actual_target_amount = actual_target_amount.saturating_add(target_amount);
+		}
+		ensure!(
+			actual_target_amount >= min_target_amount,
+			Error::<T>::InsufficientTargetAmount
+		);
+
+		Ok(actual_target_amount)
+	}
+
+	/// Ensured atomic.
+	#[transactional]
+	fn do_swap_with_exact_target_by_best_paths(
+		who: &T::AccountId,
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+		max_supply_amount: Balance,
+		max_paths: u32,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		ensure!(
+			!max_paths.is_zero() && max_paths <= MAX_SWAP_SPLIT_PATHS,
+			Error::<T>::InvalidSplitPathsNumber
+		);
+		let allocations = Self::get_best_swap_paths_with_exact_target(
+			supply_currency_id,
+			target_currency_id,
+			target_amount,
+			max_paths,
+		)
+		.ok_or(Error::<T>::NoAvailableTradingPath)?;
+
+		let mut actual_supply_amount: Balance = Zero::zero();
+		for (path, amount) in allocations {
+			let supply_amount = Self::do_swap_with_exact_target(
+				who,
+				&path,
+				amount,
+				max_supply_amount.saturating_sub(actual_supply_amount),
+			)?;
+			actual_supply_amount = actual_supply_amount.saturating_add(supply_amount);
+		}
+
+		Ok(actual_supply_amount)
+	}
+
+	/// Get the repay amount of flash loan, the fee is charged in the same way as swap:
+	/// repay_amount = borrow_amount * fee_denominator / (fee_denominator - fee_numerator)
+	fn get_flash_loan_repay_amount(borrow_amount: Balance, exchange_fee: (u32, u32)) -> Option<Balance> {
+		let (fee_numerator, fee_denominator) = exchange_fee;
+		let numerator: U256 = U256::from(borrow_amount).saturating_mul(U256::from(fee_denominator));
+		let denominator: U256 = U256::from(fee_denominator.saturating_sub(fee_numerator));
+		if denominator.is_zero() {
+			return None;
+		}
+
+		// round up the repay amount
+		numerator
+			.checked_add(denominator.saturating_sub(U256::one()))
+			.and_then(|n| n.checked_div(denominator))
+			.and_then(|n| TryInto::<Balance>::try_into(n).ok())
+	}
+
+	/// Ensured atomic.
+	#[transactional]
+	fn do_flash_swap<F: FnOnce() -> DispatchResult>(
+		who: &T::AccountId,
+		borrow_currency_id: CurrencyId,
+		paired_currency_id: CurrencyId,
+		borrow_amount: Balance,
+		repay_currency_id: CurrencyId,
+		max_repay_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let trading_pair = TradingPair::from_currency_ids(borrow_currency_id, paired_currency_id)
+			.ok_or(Error::<T>::InvalidCurrencyId)?;
+		ensure!(
+			matches!(
+				Self::trading_pair_statuses(trading_pair),
+				TradingPairStatus::<_, _>::Enabled
+			),
+			Error::<T>::MustBeEnabled
+		);
+		ensure!(
+			repay_currency_id == borrow_currency_id || repay_currency_id == paired_currency_id,
+			Error::<T>::InvalidCurrencyId
+		);
+		ensure!(!borrow_amount.is_zero(), Error::<T>::ZeroTargetAmount);
+		ensure!(!Self::flash_swap_locks(trading_pair), Error::<T>::FlashSwapLocked);
+		let (borrow_pool, _) = Self::get_liquidity(borrow_currency_id, paired_currency_id);
+		ensure!(borrow_amount < borrow_pool, Error::<T>::InsufficientLiquidity);
+
+		let module_account_id = Self::account_id();
+		T::Currency::transfer(borrow_currency_id, &module_account_id, who, borrow_amount)?;
+
+		// the liquidity pool is not backed by the balance of module account until it's
+		// repaid, lock it so that the callback can not swap with or withdraw from it.
+		FlashSwapLocks::<T>::insert(trading_pair, true);
+		callback()?;
+		FlashSwapLocks::<T>::remove(trading_pair);
+
+		// the liquidity pool was locked during the callback, so the repay amount is
+		// calculated on the same liquidity as borrowing.
+		let repay_amount = if repay_currency_id == borrow_currency_id {
+			Self::get_flash_loan_repay_amount(borrow_amount, Self::get_exchange_fee(trading_pair))
+				.ok_or(ArithmeticError::Overflow)?
+		} else {
+			let (borrow_pool, paired_pool) = Self::get_liquidity(borrow_currency_id, paired_currency_id);
+			ensure!(borrow_amount < borrow_pool, Error::<T>::InsufficientLiquidity);
+			Self::get_supply_amount(
+				Self::get_swap_curve(paired_currency_id, borrow_currency_id),
+				paired_pool,
+				borrow_pool,
+				borrow_amount,
+				Self::get_exchange_fee(trading_pair),
+			)
+		};
+		ensure!(!repay_amount.is_zero(), Error::<T>::ZeroSupplyAmount);
+		ensure!(repay_amount <= max_repay_amount, Error::<T>::ExcessiveSupplyAmount);
+		T::Currency::transfer(repay_currency_id, who, &module_account_id, repay_amount)?;
+
+		// the fee of flash loan is added to the liquidity pool, and the invariant check
+		// of swap is also applied to flash swap.
+		if repay_currency_id == borrow_currency_id {
+			let fee_amount = repay_amount.saturating_sub(borrow_amount);
+			let protocol_fee_amount = Self::collect_exchange_fee(trading_pair, borrow_currency_id, fee_amount)?;
+			let pool_increment = fee_amount.saturating_sub(protocol_fee_amount);
+			LiquidityPool::<T>::try_mutate(trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+				let pool = if borrow_currency_id == trading_pair.first() {
+					pool_0
+				} else {
+					pool_1
+				};
+				*pool = pool.checked_add(pool_increment).ok_or(ArithmeticError::Overflow)?;
+
+				// the liquidity pool in storage is not changed until the closure returns
+				Self::update_price_cumulatives(trading_pair);
+				Ok(())
+			})?;
+		} else {
+			Self::_swap(paired_currency_id, borrow_currency_id, repay_amount, borrow_amount)?;
+		}
+
+		Self::deposit_event(Event::FlashSwap(
+			who.clone(),
+			borrow_currency_id,
+			borrow_amount,
+			repay_currency_id,
+			repay_amount,
+		));
+		Ok(repay_amount)
+	}
 }
 
 impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
@@ -1239,6 +2423,28 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 			.map(|amounts| amounts[0])
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		let trading_paths = Self::get_trading_paths(supply_currency_id, target_currency_id);
+		Self::get_best_target_amounts(&trading_paths, supply_amount).map(|(index, _)| trading_paths[index].clone())
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		let trading_paths = Self::get_trading_paths(supply_currency_id, target_currency_id);
+		Self::get_best_supply_amounts(&trading_paths, target_amount).map(|(index, _)| trading_paths[index].clone())
+	}
+
+	fn get_twap(currency_id_a: CurrencyId, currency_id_b: CurrencyId, window: u32) -> Option<ExchangeRate> {
+		Self::get_time_weighted_average_price(currency_id_a, currency_id_b, window.into())
+	}
+
 	fn swap_with_exact_supply(
 		who: &T::AccountId,
 		path: &[CurrencyId],
@@ -1257,6 +2463,26 @@ impl<T: Config> DEXManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		Self::do_swap_with_exact_target(who, path, target_amount, max_supply_amount)
 	}
 
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		who: &T::AccountId,
+		borrow_currency_id: CurrencyId,
+		paired_currency_id: CurrencyId,
+		borrow_amount: Balance,
+		repay_currency_id: CurrencyId,
+		max_repay_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		Self::do_flash_swap(
+			who,
+			borrow_currency_id,
+			paired_currency_id,
+			borrow_amount,
+			repay_currency_id,
+			max_repay_amount,
+			callback,
+		)
+	}
+
 	// `do_add_liquidity` is used in genesis_build,
 	// but transactions are not supported by BasicExternalities,
 	// put `transactional` here
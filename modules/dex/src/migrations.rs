@@ -0,0 +1,80 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, EnabledTradingPairCurrencies, Pallet, TradingPairStatus, TradingPairStatuses, Weight};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, StorageVersion},
+};
+
+pub mod v1 {
+	use super::*;
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"dex already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"dex storage version not set."
+		);
+		assert!(
+			TradingPairStatuses::<T>::iter()
+				.filter(|(_, status)| matches!(status, TradingPairStatus::<_, _>::Enabled))
+				.all(|(trading_pair, _)| {
+					EnabledTradingPairCurrencies::<T>::get(trading_pair.first()).contains(&trading_pair.second())
+						&& EnabledTradingPairCurrencies::<T>::get(trading_pair.second()).contains(&trading_pair.first())
+				}),
+			"enabled trading pair is not indexed."
+		);
+		Ok(())
+	}
+
+	/// Index the currencies of the existing Enabled trading pairs in
+	/// `EnabledTradingPairCurrencies`.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "dex", "Migrating dex v1");
+
+		let mut count: u64 = 0;
+		let mut enabled: u64 = 0;
+		for (trading_pair, status) in TradingPairStatuses::<T>::iter() {
+			count += 1;
+			if matches!(status, TradingPairStatus::<_, _>::Enabled) {
+				enabled += 1;
+				Pallet::<T>::insert_enabled_trading_pair(trading_pair);
+			}
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "dex", "Completed dex migration to v1");
+
+		T::DbWeight::get().reads_writes(
+			count.saturating_add(enabled.saturating_mul(2)).saturating_add(1),
+			enabled.saturating_mul(2).saturating_add(1),
+		)
+	}
+}
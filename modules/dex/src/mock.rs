@@ -36,6 +36,7 @@ pub type AccountId = u128;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 10;
 pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 pub const BTC: CurrencyId = CurrencyId::Token(TokenSymbol::RENBTC);
 pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
@@ -45,6 +46,7 @@ parameter_types! {
 	pub static AUSDBTCPair: TradingPair = TradingPair::from_currency_ids(AUSD, BTC).unwrap();
 	pub static AUSDDOTPair: TradingPair = TradingPair::from_currency_ids(AUSD, DOT).unwrap();
 	pub static DOTBTCPair: TradingPair = TradingPair::from_currency_ids(DOT, BTC).unwrap();
+	pub static DEXProtocolFeeShare: Ratio = Ratio::zero();
 }
 
 mod dex {
@@ -117,7 +119,11 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
+	pub const DEXProtocolFeeReceiver: AccountId = TREASURY;
 	pub const TradingPathLimit: u32 = 3;
+	pub const PriceObservationPeriod: BlockNumber = 10;
+	pub const MaxPriceObservations: u32 = 3;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 20;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -125,12 +131,18 @@ impl Config for Runtime {
 	type Event = Event;
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = DEXProtocolFeeReceiver;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = ();
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -23,8 +23,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	AUSDBTCPair, AUSDDOTPair, DexModule, Event, ExtBuilder, ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE,
-	AUSD, BOB, BTC, DOT,
+	AUSDBTCPair, AUSDDOTPair, Call as MockCall, DEXProtocolFeeShare, DexModule, Event, ExtBuilder, GetExchangeFee,
+	ListingOrigin, Origin, Runtime, System, Tokens, ACA, ALICE, AUSD, BOB, BTC, DOT, TREASURY,
 };
 use orml_traits::MultiReservableCurrency;
 use sp_runtime::traits::BadOrigin;
@@ -44,6 +44,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			BadOrigin
 		);
@@ -61,6 +62,7 @@ fn list_provisioning_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -83,6 +85,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			Error::<Runtime>::InvalidCurrencyId
 		);
@@ -97,6 +100,7 @@ fn list_provisioning_work() {
 				5_000_000_000_000u128,
 				2_000_000_000_000u128,
 				10,
+				SwapCurve::ConstantProduct,
 			),
 			Error::<Runtime>::MustBeDisabled
 		);
@@ -145,6 +149,7 @@ fn update_provisioning_parameters_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -184,7 +189,7 @@ fn enable_diabled_trading_pair_work() {
 		System::set_block_number(1);
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT),
+			DexModule::enable_trading_pair(Origin::signed(ALICE), AUSD, DOT, SwapCurve::ConstantProduct),
 			BadOrigin
 		);
 
@@ -195,7 +200,8 @@ fn enable_diabled_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -204,12 +210,81 @@ fn enable_diabled_trading_pair_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), DOT, AUSD),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				SwapCurve::ConstantProduct
+			),
 			Error::<Runtime>::AlreadyEnabled
 		);
 	});
 }
 
+#[test]
+fn enable_trading_pair_with_swap_curve_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::Weighted(50, 60)
+			),
+			Error::<Runtime>::InvalidSwapCurve
+		);
+		assert_noop!(
+			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, DOT, SwapCurve::Amplified(0)),
+			Error::<Runtime>::InvalidSwapCurve
+		);
+
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			DOT,
+			AUSD,
+			SwapCurve::Weighted(80, 20),
+		));
+		assert_eq!(
+			DexModule::trading_pair_swap_curves(AUSDDOTPair::get()),
+			SwapCurve::Weighted(20, 80)
+		);
+		assert_eq!(DexModule::get_swap_curve(DOT, AUSD), SwapCurve::Weighted(80, 20));
+		assert_eq!(DexModule::get_swap_curve(AUSD, DOT), SwapCurve::Weighted(20, 80));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			200_000,
+			800_000,
+			0,
+			false,
+		));
+		assert_ok!(DexModule::disable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT
+		));
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::ConstantProduct
+			),
+			Error::<Runtime>::SwapCurveChangeNotAllowed
+		);
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::Weighted(20, 80),
+		));
+	});
+}
+
 #[test]
 fn enable_provisioning_without_provision_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -224,6 +299,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::list_provisioning(
 			Origin::signed(ListingOrigin::get()),
@@ -234,6 +310,7 @@ fn enable_provisioning_without_provision_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -255,7 +332,8 @@ fn enable_provisioning_without_provision_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -264,7 +342,12 @@ fn enable_provisioning_without_provision_work() {
 		System::assert_last_event(Event::DexModule(crate::Event::EnableTradingPair(AUSDDOTPair::get())));
 
 		assert_noop!(
-			DexModule::enable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				BTC,
+				SwapCurve::ConstantProduct
+			),
 			Error::<Runtime>::StillProvisioning
 		);
 	});
@@ -284,6 +367,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -304,6 +388,7 @@ fn end_provisioning_trading_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_ok!(DexModule::add_provision(
 			Origin::signed(ALICE),
@@ -373,6 +458,207 @@ fn end_provisioning_trading_work() {
 	});
 }
 
+#[test]
+fn abort_provisioning_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			DexModule::abort_provisioning(Origin::signed(ALICE), AUSD, DOT),
+			Error::<Runtime>::MustBeProvisioning
+		);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			10,
+			SwapCurve::ConstantProduct,
+		));
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			BTC,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			10,
+			SwapCurve::ConstantProduct,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(ALICE),
+			AUSD,
+			BTC,
+			5_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		));
+
+		// not expired
+		System::set_block_number(30);
+		assert_noop!(
+			DexModule::abort_provisioning(Origin::signed(BOB), AUSD, DOT),
+			Error::<Runtime>::UnqualifiedAbort
+		);
+
+		// met the target
+		System::set_block_number(31);
+		assert_noop!(
+			DexModule::abort_provisioning(Origin::signed(BOB), AUSD, BTC),
+			Error::<Runtime>::UnqualifiedAbort
+		);
+
+		assert_ok!(DexModule::abort_provisioning(Origin::signed(BOB), AUSD, DOT));
+		System::assert_last_event(Event::DexModule(crate::Event::ProvisioningAborted(
+			AUSDDOTPair::get(),
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		)));
+		assert_eq!(
+			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
+			TradingPairStatus::<_, _>::Disabled
+		);
+		assert_eq!(
+			DexModule::provisioning_pool(AUSDDOTPair::get(), ALICE),
+			(1_000_000_000_000_000u128, 200_000_000_000_000u128)
+		);
+	});
+}
+
+#[test]
+fn refund_provision_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			10,
+			SwapCurve::ConstantProduct,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			1_000_000_000_000_000u128,
+			200_000_000_000_000u128,
+		));
+		assert_ok!(DexModule::add_provision(
+			Origin::signed(BOB),
+			AUSD,
+			DOT,
+			0,
+			100_000_000_000_000u128,
+		));
+
+		assert_noop!(
+			DexModule::refund_provision(Origin::signed(ALICE), ALICE, AUSD, DOT),
+			Error::<Runtime>::NotAllowedRefund
+		);
+
+		System::set_block_number(31);
+		assert_ok!(DexModule::abort_provisioning(Origin::signed(ALICE), AUSD, DOT));
+		assert_noop!(
+			DexModule::claim_dex_share(Origin::signed(ALICE), ALICE, AUSD, DOT),
+			Error::<Runtime>::ProvisioningAborted
+		);
+
+		let alice_ref_count_0 = System::consumers(&ALICE);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		// partial refund
+		assert_ok!(DexModule::refund_provision(Origin::signed(BOB), ALICE, AUSD, DOT));
+		System::assert_last_event(Event::DexModule(crate::Event::RefundProvision(
+			ALICE,
+			AUSD,
+			1_000_000_000_000_000u128,
+			DOT,
+			200_000_000_000_000u128,
+		)));
+		assert_eq!(DexModule::provisioning_pool(AUSDDOTPair::get(), ALICE), (0, 0));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
+		assert_eq!(
+			Tokens::free_balance(DOT, &DexModule::account_id()),
+			100_000_000_000_000u128
+		);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+
+		// refund again changes nothing
+		assert_ok!(DexModule::refund_provision(Origin::signed(BOB), ALICE, AUSD, DOT));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000_000_000_000_000_000u128);
+
+		// can not list again before the provision is all refunded
+		assert_noop!(
+			DexModule::list_provisioning(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				5_000_000_000_000u128,
+				1_000_000_000_000u128,
+				5_000_000_000_000_000u128,
+				1_000_000_000_000_000u128,
+				10,
+				SwapCurve::ConstantProduct,
+			),
+			Error::<Runtime>::NotAllowedList
+		);
+
+		// can not enable before the provision is all refunded
+		assert_noop!(
+			DexModule::enable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				SwapCurve::ConstantProduct,
+			),
+			Error::<Runtime>::ProvisionNotRefunded
+		);
+
+		// complete refund
+		assert_ok!(DexModule::refund_provision(Origin::signed(BOB), BOB, AUSD, DOT));
+		System::assert_last_event(Event::DexModule(crate::Event::RefundProvision(
+			BOB,
+			AUSD,
+			0,
+			DOT,
+			100_000_000_000_000u128,
+		)));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_000_000u128);
+		assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+
+		assert_ok!(DexModule::list_provisioning(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			5_000_000_000_000u128,
+			1_000_000_000_000u128,
+			5_000_000_000_000_000u128,
+			1_000_000_000_000_000u128,
+			10,
+			SwapCurve::ConstantProduct,
+		));
+	});
+}
+
 #[test]
 fn disable_trading_pair_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -381,7 +667,8 @@ fn disable_trading_pair_work() {
 		assert_ok!(DexModule::enable_trading_pair(
 			Origin::signed(ListingOrigin::get()),
 			AUSD,
-			DOT
+			DOT,
+			SwapCurve::ConstantProduct,
 		));
 		assert_eq!(
 			DexModule::trading_pair_statuses(AUSDDOTPair::get()),
@@ -418,6 +705,7 @@ fn disable_trading_pair_work() {
 			5_000_000_000_000u128,
 			2_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 		assert_noop!(
 			DexModule::disable_trading_pair(Origin::signed(ListingOrigin::get()), AUSD, BTC),
@@ -451,6 +739,7 @@ fn add_provision_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			10,
+			SwapCurve::ConstantProduct,
 		));
 
 		assert_noop!(
@@ -533,6 +822,7 @@ fn claim_dex_share_work() {
 			5_000_000_000_000_000u128,
 			1_000_000_000_000_000u128,
 			0,
+			SwapCurve::ConstantProduct,
 		));
 
 		assert_ok!(DexModule::add_provision(
@@ -623,35 +913,160 @@ fn get_liquidity_work() {
 #[test]
 fn get_target_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_target_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 1, 1000000), 0);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 10000), 9949);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 0, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 0, 20000, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 20000, 0, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 1, 1000000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 20000, 10000, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
 #[test]
 fn get_supply_amount_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(DexModule::get_supply_amount(10000, 0, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(0, 20000, 1000), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 0), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 1, 1), 0);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 9949), 9999);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 9999), 9949);
-		assert_eq!(DexModule::get_supply_amount(10000, 20000, 1801), 1000);
-		assert_eq!(DexModule::get_target_amount(10000, 20000, 1000), 1801);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 10000, 0, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 0, 20000, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 10000, 20000, 0, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 10000, 1, 1, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 10000, 20000, 9949, GetExchangeFee::get()),
+			9999
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 20000, 9999, GetExchangeFee::get()),
+			9949
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::ConstantProduct, 10000, 20000, 1801, GetExchangeFee::get()),
+			1000
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::ConstantProduct, 10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
 	});
 }
 
 #[test]
-fn get_target_amounts_work() {
-	ExtBuilder::default()
-		.initialize_enabled_trading_pairs()
-		.build()
-		.execute_with(|| {
+fn get_target_amount_with_swap_curves_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Weighted(80, 20), 10000, 0, 1000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Amplified(100), 10000, 20000, 0, GetExchangeFee::get()),
+			0
+		);
+
+		// equal weights work the same as constant product
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Weighted(50, 50), 10000, 20000, 1000, GetExchangeFee::get()),
+			1801
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Weighted(80, 20), 10000, 20000, 1000, GetExchangeFee::get()),
+			6289
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Weighted(20, 80), 10000, 20000, 1000, GetExchangeFee::get()),
+			466
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Amplified(100), 10000, 20000, 1000, GetExchangeFee::get()),
+			996
+		);
+		assert_eq!(
+			DexModule::get_target_amount(
+				SwapCurve::ConstantProduct,
+				1_000_000,
+				1_000_000,
+				10000,
+				GetExchangeFee::get()
+			),
+			9802
+		);
+		assert_eq!(
+			DexModule::get_target_amount(
+				SwapCurve::Amplified(100),
+				1_000_000,
+				1_000_000,
+				10000,
+				GetExchangeFee::get()
+			),
+			9899
+		);
+	});
+}
+
+#[test]
+fn get_supply_amount_with_swap_curves_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::Weighted(80, 20), 10000, 20000, 20000, GetExchangeFee::get()),
+			0
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::Amplified(100), 10000, 20000, 20000, GetExchangeFee::get()),
+			0
+		);
+
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::Weighted(80, 20), 10000, 20000, 6289, GetExchangeFee::get()),
+			1001
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Weighted(80, 20), 10000, 20000, 1001, GetExchangeFee::get()),
+			6289
+		);
+		assert_eq!(
+			DexModule::get_supply_amount(SwapCurve::Amplified(100), 10000, 20000, 996, GetExchangeFee::get()),
+			1001
+		);
+		assert_eq!(
+			DexModule::get_target_amount(SwapCurve::Amplified(100), 10000, 20000, 1001, GetExchangeFee::get()),
+			996
+		);
+	});
+}
+
+#[test]
+fn get_target_amounts_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
 			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
 			LiquidityPool::<Runtime>::insert(AUSDBTCPair::get(), (100000, 10));
 			assert_noop!(
@@ -694,17 +1109,21 @@ fn calculate_amount_for_big_number_work() {
 		);
 		assert_eq!(
 			DexModule::get_supply_amount(
+				SwapCurve::ConstantProduct,
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				1_000_000_000_000_000_000_000
+				1_000_000_000_000_000_000_000,
+				GetExchangeFee::get()
 			),
 			3_140_495_867_768_595_041_323
 		);
 		assert_eq!(
 			DexModule::get_target_amount(
+				SwapCurve::ConstantProduct,
 				171_000_000_000_000_000_000_000,
 				56_000_000_000_000_000_000_000,
-				3_140_495_867_768_595_041_323
+				3_140_495_867_768_595_041_323,
+				GetExchangeFee::get()
 			),
 			1_000_000_000_000_000_000_000
 		);
@@ -750,6 +1169,65 @@ fn get_supply_amounts_work() {
 		});
 }
 
+#[test]
+fn get_swap_quote_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			LiquidityPool::<Runtime>::insert(AUSDBTCPair::get(), (100000, 10));
+
+			assert_eq!(
+				DexModule::get_exchange_fee_amounts(&[DOT, AUSD], &[10000, 24874]),
+				vec![100]
+			);
+			assert_eq!(
+				DexModule::get_exchange_fee_amounts(&[DOT, AUSD, BTC], &[10000, 24874, 1]),
+				vec![100, 248]
+			);
+			assert_eq!(
+				DexModule::get_price_impact(&[DOT, AUSD], &[10000, 24874]),
+				Some(Ratio::from_inner(497_494_949_494_949_495))
+			);
+			assert_eq!(DexModule::get_price_impact(&[DOT, ACA], &[10000, 24874]), None);
+		});
+}
+
+#[test]
+fn get_trading_pair_reserves_and_liquidity_share_value_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			LiquidityPool::<Runtime>::insert(AUSDDOTPair::get(), (50000, 10000));
+			TradingPairStatuses::<Runtime>::insert(
+				AUSDBTCPair::get(),
+				TradingPairStatus::Provisioning(ProvisioningParameters {
+					min_contribution: (1, 1),
+					target_provision: (1000, 1000),
+					accumulated_provision: (200, 30),
+					not_before: 10,
+				}),
+			);
+
+			assert_eq!(DexModule::get_trading_pair_reserves(AUSDDOTPair::get()), (50000, 10000));
+			assert_eq!(DexModule::get_trading_pair_reserves(AUSDBTCPair::get()), (200, 30));
+
+			assert_eq!(DexModule::get_liquidity_share_value(AUSDDOTPair::get(), 1000), (0, 0));
+			assert_ok!(Tokens::deposit(
+				AUSDDOTPair::get().dex_share_currency_id(),
+				&ALICE,
+				1000
+			));
+			assert_ok!(Tokens::deposit(AUSDDOTPair::get().dex_share_currency_id(), &BOB, 3000));
+			assert_eq!(
+				DexModule::get_liquidity_share_value(AUSDDOTPair::get(), 1000),
+				(12500, 2500)
+			);
+		});
+}
+
 #[test]
 fn _swap_work() {
 	ExtBuilder::default()
@@ -1320,6 +1798,852 @@ fn do_swap_with_exact_target_work() {
 		});
 }
 
+#[test]
+fn swap_with_weighted_curve_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			SwapCurve::Weighted(80, 20),
+		));
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			AUSD,
+			DOT,
+			800_000,
+			200_000,
+			0,
+			false,
+		));
+		assert_eq!(DexModule::get_liquidity(AUSD, DOT), (800_000, 200_000));
+
+		assert_eq!(DexModule::get_swap_target_amount(&[AUSD, DOT], 10_000), Some(9_601));
+		assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[AUSD, DOT], 10_000, 9_601));
+		assert_eq!(DexModule::get_liquidity(AUSD, DOT), (810_000, 190_399));
+
+		assert_eq!(DexModule::get_swap_supply_amount(&[DOT, AUSD], 10_000), Some(9_798));
+		assert_ok!(DexModule::do_swap_with_exact_target(&BOB, &[DOT, AUSD], 10_000, 9_798));
+		assert_eq!(DexModule::get_liquidity(AUSD, DOT), (800_000, 200_197));
+
+		// the pool must not pay more than the curve quotes
+		assert_noop!(
+			DexModule::_swap(AUSD, DOT, 10_000, 9_611),
+			Error::<Runtime>::InvariantCheckFailed
+		);
+	});
+}
+
+#[test]
+fn get_trading_paths_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(DexModule::get_trading_paths(AUSD, DOT), Vec::<Vec<CurrencyId>>::new());
+	});
+
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				DexModule::get_trading_paths(AUSD, DOT),
+				vec![vec![AUSD, DOT], vec![AUSD, BTC, DOT]]
+			);
+			assert_eq!(
+				DexModule::get_trading_paths(BTC, AUSD),
+				vec![vec![BTC, AUSD], vec![BTC, DOT, AUSD]]
+			);
+			assert_eq!(DexModule::get_trading_paths(AUSD, AUSD), Vec::<Vec<CurrencyId>>::new());
+			assert_eq!(DexModule::get_trading_paths(AUSD, ACA), Vec::<Vec<CurrencyId>>::new());
+
+			assert_ok!(DexModule::disable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				BTC
+			));
+			assert_eq!(DexModule::get_trading_paths(AUSD, DOT), vec![vec![AUSD, DOT]]);
+		});
+}
+
+#[test]
+fn get_trading_paths_with_many_enabled_trading_pairs_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			// more Enabled trading pairs than the currencies read by the search
+			for i in 0..40 {
+				let erc20 = CurrencyId::Erc20(H160::from_low_u64_be(i));
+				for currency_id in [ACA, AUSD].iter() {
+					assert_ok!(DexModule::enable_trading_pair(
+						Origin::signed(ListingOrigin::get()),
+						*currency_id,
+						erc20,
+						SwapCurve::ConstantProduct,
+					));
+				}
+			}
+			assert_eq!(DexModule::enabled_trading_pair_currencies(ACA).len(), 40);
+			assert_eq!(DexModule::enabled_trading_pair_currencies(AUSD).len(), 42);
+
+			// the direct pair is always found
+			assert_eq!(
+				DexModule::get_trading_paths(AUSD, DOT),
+				vec![vec![AUSD, DOT], vec![AUSD, BTC, DOT]]
+			);
+			let erc20 = CurrencyId::Erc20(H160::from_low_u64_be(39));
+			assert_eq!(DexModule::get_trading_paths(erc20, DOT), vec![vec![erc20, AUSD, DOT]]);
+			assert_eq!(
+				DexModule::get_trading_paths(AUSD, ACA).len(),
+				MAX_SEARCH_TRADING_PATHS as usize
+			);
+
+			assert_ok!(DexModule::disable_trading_pair(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				erc20
+			));
+			assert!(!DexModule::enabled_trading_pair_currencies(AUSD).contains(&erc20));
+			assert_eq!(DexModule::enabled_trading_pair_currencies(erc20), vec![ACA]);
+			assert_eq!(DexModule::get_trading_paths(erc20, DOT), Vec::<Vec<CurrencyId>>::new());
+		});
+}
+
+#[test]
+fn get_best_swap_path_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_eq!(DexModule::get_best_swap_path_with_exact_supply(AUSD, DOT, 100), None);
+			assert_eq!(DexModule::get_best_swap_path_with_exact_target(AUSD, DOT, 100), None);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				100_000,
+				100_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+
+			// the direct path is better for the small amount
+			assert_eq!(
+				DexModule::get_best_swap_path_with_exact_supply(AUSD, DOT, 100),
+				Some(vec![AUSD, DOT])
+			);
+			assert_eq!(
+				DexModule::get_best_swap_path_with_exact_target(AUSD, DOT, 100),
+				Some(vec![AUSD, DOT])
+			);
+
+			// the path through the deeper pools is better for the big amount
+			assert_eq!(DexModule::get_swap_target_amount(&[AUSD, DOT], 10_000), Some(9_008));
+			assert_eq!(
+				DexModule::get_swap_target_amount(&[AUSD, BTC, DOT], 10_000),
+				Some(9_610)
+			);
+			assert_eq!(
+				DexModule::get_best_swap_path_with_exact_supply(AUSD, DOT, 10_000),
+				Some(vec![AUSD, BTC, DOT])
+			);
+			assert_eq!(DexModule::get_swap_supply_amount(&[AUSD, DOT], 10_000), Some(11_224));
+			assert_eq!(
+				DexModule::get_swap_supply_amount(&[AUSD, BTC, DOT], 10_000),
+				Some(10_414)
+			);
+			assert_eq!(
+				DexModule::get_best_swap_path_with_exact_target(AUSD, DOT, 10_000),
+				Some(vec![AUSD, BTC, DOT])
+			);
+
+			assert_eq!(DexModule::get_best_swap_path_with_exact_supply(AUSD, ACA, 100), None);
+			assert_eq!(DexModule::get_best_swap_path_with_exact_target(AUSD, ACA, 100), None);
+		});
+}
+
+#[test]
+fn swap_with_exact_supply_by_best_paths_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+
+			assert_eq!(
+				DexModule::get_best_swap_paths_with_exact_supply(AUSD, DOT, 100_000, 1),
+				Some(vec![(vec![AUSD, DOT], 100_000)])
+			);
+			assert_eq!(
+				DexModule::get_best_swap_paths_with_exact_supply(AUSD, DOT, 100_000, 2),
+				Some(vec![(vec![AUSD, DOT], 70_000), (vec![AUSD, BTC, DOT], 30_000)])
+			);
+			assert_eq!(
+				DexModule::get_best_swap_paths_with_exact_supply(AUSD, DOT, 100_000, 0),
+				None
+			);
+			// the simulated swaps are rolled back
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_000_000, 1_000_000));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_000_000, 1_000_000));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (1_000_000, 1_000_000));
+
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_paths(Origin::signed(BOB), AUSD, DOT, 100_000, 0, 0),
+				Error::<Runtime>::InvalidSplitPathsNumber
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_paths(
+					Origin::signed(BOB),
+					AUSD,
+					DOT,
+					100_000,
+					0,
+					MAX_SWAP_SPLIT_PATHS + 1
+				),
+				Error::<Runtime>::InvalidSplitPathsNumber
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_paths(Origin::signed(BOB), AUSD, ACA, 100_000, 0, 2),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_supply_by_best_paths(Origin::signed(BOB), AUSD, DOT, 100_000, 92_570, 2),
+				Error::<Runtime>::InsufficientTargetAmount
+			);
+
+			assert_ok!(DexModule::swap_with_exact_supply_by_best_paths(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				100_000,
+				92_569,
+				2
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![70_000, 64_808],
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, BTC, DOT],
+				vec![30_000, 28_843, 27_761],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_070_000, 935_192));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_030_000, 971_157));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (972_239, 1_028_843));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_999_999_900_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_092_569);
+		});
+}
+
+#[test]
+fn swap_with_exact_target_by_best_paths_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				DOT,
+				BTC,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+
+			assert_eq!(
+				DexModule::get_best_swap_paths_with_exact_target(AUSD, DOT, 100_000, 1),
+				Some(vec![(vec![AUSD, DOT], 100_000)])
+			);
+			assert_eq!(
+				DexModule::get_best_swap_paths_with_exact_target(AUSD, DOT, 100_000, 2),
+				Some(vec![(vec![AUSD, DOT], 70_000), (vec![AUSD, BTC, DOT], 30_000)])
+			);
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_000_000, 1_000_000));
+
+			assert_noop!(
+				DexModule::swap_with_exact_target_by_best_paths(Origin::signed(BOB), AUSD, DOT, 100_000, 200_000, 0),
+				Error::<Runtime>::InvalidSplitPathsNumber
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target_by_best_paths(Origin::signed(BOB), AUSD, ACA, 100_000, 200_000, 2),
+				Error::<Runtime>::NoAvailableTradingPath
+			);
+			assert_noop!(
+				DexModule::swap_with_exact_target_by_best_paths(Origin::signed(BOB), AUSD, DOT, 100_000, 108_604, 2),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+
+			assert_ok!(DexModule::swap_with_exact_target_by_best_paths(
+				Origin::signed(BOB),
+				AUSD,
+				DOT,
+				100_000,
+				108_605,
+				2
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![76_030, 70_000],
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, BTC, DOT],
+				vec![32_575, 31_241, 30_000],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_076_030, 930_000));
+			assert_eq!(DexModule::get_liquidity(AUSD, BTC), (1_032_575, 968_759));
+			assert_eq!(DexModule::get_liquidity(DOT, BTC), (970_000, 1_031_241));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_999_999_891_395);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_100_000);
+		});
+}
+
+#[test]
+fn flash_swap_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			let remark = Box::new(MockCall::System(frame_system::Call::remark { remark: vec![] }));
+
+			assert_noop!(
+				DexModule::flash_swap(Origin::signed(BOB), DOT, ACA, 100_000, ACA, 200_000, remark.clone()),
+				Error::<Runtime>::MustBeEnabled
+			);
+			assert_noop!(
+				DexModule::flash_swap(Origin::signed(BOB), DOT, AUSD, 100_000, BTC, 200_000, remark.clone()),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::flash_swap(Origin::signed(BOB), DOT, AUSD, 0, AUSD, 200_000, remark.clone()),
+				Error::<Runtime>::ZeroTargetAmount
+			);
+			assert_noop!(
+				DexModule::flash_swap(Origin::signed(BOB), DOT, AUSD, 1_000_000, AUSD, 200_000, remark.clone()),
+				Error::<Runtime>::InsufficientLiquidity
+			);
+			assert_noop!(
+				DexModule::flash_swap(Origin::signed(BOB), DOT, AUSD, 100_000, AUSD, 112_233, remark.clone()),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+			assert_noop!(
+				DexModule::flash_swap(
+					Origin::signed(BOB),
+					DOT,
+					AUSD,
+					100_000,
+					AUSD,
+					200_000,
+					Box::new(MockCall::System(frame_system::Call::set_heap_pages { pages: 0 }))
+				),
+				BadOrigin
+			);
+
+			assert_ok!(DexModule::flash_swap(
+				Origin::signed(BOB),
+				DOT,
+				AUSD,
+				100_000,
+				AUSD,
+				112_234,
+				remark
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::FlashSwap(
+				BOB, DOT, 100_000, AUSD, 112_234,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_112_234, 900_000));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_999_999_887_766);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_100_000);
+		});
+}
+
+#[test]
+fn flash_swap_locks_liquidity_pool_during_callback() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			let swap = Box::new(MockCall::DexModule(crate::Call::swap_with_exact_supply {
+				path: vec![DOT, AUSD],
+				supply_amount: 100_000,
+				min_target_amount: 0,
+			}));
+			let remove_liquidity = Box::new(MockCall::DexModule(crate::Call::remove_liquidity {
+				currency_id_a: AUSD,
+				currency_id_b: DOT,
+				remove_share: 100_000,
+				min_withdrawn_a: 0,
+				min_withdrawn_b: 0,
+				by_unstake: false,
+			}));
+			let flash_swap = Box::new(MockCall::DexModule(crate::Call::flash_swap {
+				borrow_currency_id: DOT,
+				paired_currency_id: AUSD,
+				borrow_amount: 100_000,
+				repay_currency_id: AUSD,
+				max_repay_amount: 200_000,
+				callback: Box::new(MockCall::System(frame_system::Call::remark { remark: vec![] })),
+			}));
+
+			for callback in vec![swap, remove_liquidity, flash_swap] {
+				assert_noop!(
+					DexModule::flash_swap(Origin::signed(BOB), DOT, AUSD, 100_000, AUSD, 200_000, callback),
+					Error::<Runtime>::FlashSwapLocked
+				);
+			}
+
+			assert_ok!(DexModule::flash_swap(
+				Origin::signed(BOB),
+				DOT,
+				AUSD,
+				100_000,
+				AUSD,
+				112_234,
+				Box::new(MockCall::System(frame_system::Call::remark { remark: vec![] }))
+			));
+			assert!(!DexModule::flash_swap_locks(AUSDDOTPair::get()));
+		});
+}
+
+#[test]
+fn flash_loan_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+
+			assert_noop!(
+				DexModule::do_flash_swap(&BOB, DOT, AUSD, 100_000, DOT, 101_010, || Ok(())),
+				Error::<Runtime>::ExcessiveSupplyAmount
+			);
+			assert_noop!(
+				DexModule::do_flash_swap(&BOB, DOT, AUSD, 100_000, DOT, 101_011, || Err(
+					Error::<Runtime>::InvariantCheckFailed.into()
+				)),
+				Error::<Runtime>::InvariantCheckFailed
+			);
+
+			assert_eq!(
+				DexModule::do_flash_swap(&BOB, DOT, AUSD, 100_000, DOT, 101_011, || {
+					// the borrowed amount has been received when callback is invoked
+					assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_100_000);
+					Ok(())
+				}),
+				Ok(101_011)
+			);
+			System::assert_last_event(Event::DexModule(crate::Event::FlashSwap(
+				BOB, DOT, 100_000, DOT, 101_011,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_000_000, 1_001_011));
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 1_000_000_000_000_000_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 999_999_999_999_998_989);
+		});
+}
+
+#[test]
+fn set_exchange_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ALICE), AUSD, DOT, Some((3, 1000))),
+				BadOrigin
+			);
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, AUSD, Some((3, 1000))),
+				Error::<Runtime>::InvalidCurrencyId
+			);
+			assert_noop!(
+				DexModule::set_exchange_fee(Origin::signed(ListingOrigin::get()), AUSD, DOT, Some((1000, 1000))),
+				Error::<Runtime>::InvalidExchangeFee
+			);
+
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (1, 100));
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				DOT,
+				AUSD,
+				Some((3, 1000))
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::SetExchangeFee(
+				AUSDDOTPair::get(),
+				(3, 1000),
+			)));
+			assert_eq!(
+				DexModule::trading_pair_exchange_fees(AUSDDOTPair::get()),
+				Some((3, 1000))
+			);
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (3, 1000));
+			assert_eq!(DexModule::get_exchange_fee(AUSDBTCPair::get()), (1, 100));
+
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				None
+			));
+			System::assert_last_event(Event::DexModule(crate::Event::SetExchangeFee(
+				AUSDDOTPair::get(),
+				(1, 100),
+			)));
+			assert_eq!(DexModule::trading_pair_exchange_fees(AUSDDOTPair::get()), None);
+			assert_eq!(DexModule::get_exchange_fee(AUSDDOTPair::get()), (1, 100));
+		});
+}
+
+#[test]
+fn swap_with_exchange_fee_of_trading_pair_and_protocol_fee_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			DEXProtocolFeeShare::set(Ratio::saturating_from_rational(20, 100));
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((3, 1000))
+			));
+			assert_eq!(DexModule::get_swap_target_amount(&[AUSD, DOT], 100_000), Some(90_661));
+
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				100_000,
+				0,
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::CollectExchangeFee(
+				AUSDDOTPair::get(),
+				AUSD,
+				240,
+				60,
+			)));
+			System::assert_last_event(Event::DexModule(crate::Event::Swap(
+				BOB,
+				vec![AUSD, DOT],
+				vec![100_000, 90_661],
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_099_940, 909_339));
+			assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 60);
+			assert_eq!(Tokens::free_balance(AUSD, &BOB), 999_999_999_999_900_000);
+			assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000_000_000_090_661);
+		});
+}
+
+#[test]
+fn protocol_fee_accrual_and_payout_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				1_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::set_exchange_fee(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some((3, 1000))
+			));
+
+			// no protocol fee share, all the fee is left to liquidity providers
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![AUSD, DOT],
+				100_000,
+				0,
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::CollectExchangeFee(
+				AUSDDOTPair::get(),
+				AUSD,
+				300,
+				0,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_100_000, 909_339));
+			assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 0);
+
+			// the protocol fee is paid in the supply currency of swaps
+			DEXProtocolFeeShare::set(Ratio::saturating_from_rational(20, 100));
+			assert_ok!(DexModule::swap_with_exact_supply(
+				Origin::signed(BOB),
+				vec![DOT, AUSD],
+				50_000,
+				0,
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::CollectExchangeFee(
+				AUSDDOTPair::get(),
+				DOT,
+				120,
+				30,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_042_832, 959_309));
+			assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 0);
+			assert_eq!(Tokens::free_balance(DOT, &TREASURY), 30);
+
+			// the protocol fee of flash loan accrues in the same way
+			assert_ok!(DexModule::flash_swap(
+				Origin::signed(BOB),
+				DOT,
+				AUSD,
+				100_000,
+				DOT,
+				100_301,
+				Box::new(MockCall::System(frame_system::Call::remark { remark: vec![] }))
+			));
+			System::assert_has_event(Event::DexModule(crate::Event::CollectExchangeFee(
+				AUSDDOTPair::get(),
+				DOT,
+				241,
+				60,
+			)));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (1_042_832, 959_550));
+			assert_eq!(Tokens::free_balance(DOT, &TREASURY), 90);
+
+			// the fees left in pool are paid out to liquidity providers on removal
+			let alice_share = Tokens::free_balance(AUSDDOTPair::get().dex_share_currency_id(), &ALICE);
+			assert_ok!(DexModule::remove_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				alice_share,
+				0,
+				0,
+				false,
+			));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (0, 0));
+			assert_eq!(Tokens::free_balance(AUSD, &DexModule::account_id()), 0);
+			assert_eq!(Tokens::free_balance(DOT, &DexModule::account_id()), 0);
+		});
+}
+
+#[test]
+fn get_time_weighted_average_price_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 1), None);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				1_000_000,
+				2_000_000,
+				0,
+				false,
+			));
+			assert_eq!(
+				DexModule::price_cumulatives(AUSDDOTPair::get()),
+				(U256::zero(), U256::zero(), 1)
+			);
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get()).into_inner(),
+				vec![(1, U256::zero(), U256::zero())]
+			);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 0), None);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 1), None);
+
+			System::set_block_number(6);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 5),
+				Some(ExchangeRate::saturating_from_integer(2))
+			);
+			assert_eq!(
+				DexModule::get_twap(DOT, AUSD, 5),
+				Some(ExchangeRate::saturating_from_rational(1, 2))
+			);
+
+			System::set_block_number(11);
+			assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[DOT, AUSD], 2_000_000, 0));
+			assert_eq!(DexModule::get_liquidity(AUSD, DOT), (502_513, 4_000_000));
+			assert_eq!(
+				DexModule::price_cumulatives(AUSDDOTPair::get()),
+				(
+					U256::from(20_000_000_000_000_000_000u128),
+					U256::from(5_000_000_000_000_000_000u128),
+					11
+				)
+			);
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get()).into_inner(),
+				vec![
+					(1, U256::zero(), U256::zero()),
+					(
+						11,
+						U256::from(20_000_000_000_000_000_000u128),
+						U256::from(5_000_000_000_000_000_000u128)
+					)
+				]
+			);
+			// the price moved in this block is not accumulated yet
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 10),
+				Some(ExchangeRate::saturating_from_integer(2))
+			);
+
+			System::set_block_number(21);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 10),
+				Some(ExchangeRate::from_inner(7_959_993_074_806_024_918))
+			);
+			assert_eq!(
+				DexModule::get_twap(DOT, AUSD, 10),
+				Some(ExchangeRate::from_inner(125_628_250_000_000_000))
+			);
+			assert_eq!(
+				DexModule::get_twap(AUSD, DOT, 20),
+				Some(ExchangeRate::from_inner(4_979_996_537_403_012_459))
+			);
+			assert_eq!(
+				DexModule::get_twap(DOT, AUSD, 20),
+				Some(ExchangeRate::from_inner(312_814_125_000_000_000))
+			);
+
+			// only the latest `MaxPriceObservations` observations are kept
+			assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[AUSD, DOT], 1_000, 0));
+			System::set_block_number(31);
+			assert_ok!(DexModule::do_swap_with_exact_supply(&BOB, &[AUSD, DOT], 1_000, 0));
+			assert_eq!(
+				DexModule::price_observations(AUSDDOTPair::get())
+					.iter()
+					.map(|(observed_at, _, _)| *observed_at)
+					.collect::<Vec<_>>(),
+				vec![11, 21, 31]
+			);
+			assert_eq!(DexModule::get_twap(AUSD, DOT, 30), None);
+			assert!(DexModule::get_twap(AUSD, DOT, 20).is_some());
+		});
+}
+
 #[test]
 fn initialize_added_liquidity_pools_genesis_work() {
 	ExtBuilder::default()
@@ -52,6 +52,8 @@ pub trait WeightInfo {
 	fn list_provisioning() -> Weight;
 	fn update_provisioning_parameters() -> Weight;
 	fn end_provisioning() -> Weight;
+	fn abort_provisioning() -> Weight;
+	fn refund_provision() -> Weight;
 	fn add_liquidity() -> Weight;
 	fn add_liquidity_and_stake() -> Weight;
 	fn add_provision() -> Weight;
@@ -60,6 +62,10 @@ pub trait WeightInfo {
 	fn remove_liquidity_by_unstake() -> Weight;
 	fn swap_with_exact_supply(u: u32, ) -> Weight;
 	fn swap_with_exact_target(u: u32, ) -> Weight;
+	fn swap_with_exact_supply_by_best_paths(u: u32, p: u32, ) -> Weight;
+	fn swap_with_exact_target_by_best_paths(u: u32, p: u32, ) -> Weight;
+	fn flash_swap() -> Weight;
+	fn set_exchange_fee() -> Weight;
 }
 
 /// Weights for module_dex using the Acala node and recommended hardware.
@@ -67,7 +73,7 @@ pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	fn disable_trading_pair() -> Weight {
@@ -90,6 +96,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(32_846_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn refund_provision() -> Weight {
+		(108_325_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
@@ -134,13 +150,44 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	fn swap_with_exact_supply_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(612_354_000 as Weight)
+			.saturating_add((131_776_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((118_412_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(628_930_000 as Weight)
+			.saturating_add((134_052_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((121_073_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap() -> Weight {
+		(172_684_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_386_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	fn enable_trading_pair() -> Weight {
 		(28_975_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	fn disable_trading_pair() -> Weight {
@@ -163,6 +210,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(32_846_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn refund_provision() -> Weight {
+		(108_325_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 	fn add_liquidity() -> Weight {
 		(197_944_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
@@ -207,4 +264,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
+	fn swap_with_exact_supply_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(612_354_000 as Weight)
+			.saturating_add((131_776_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((118_412_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(628_930_000 as Weight)
+			.saturating_add((134_052_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((121_073_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap() -> Weight {
+		(172_684_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_386_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
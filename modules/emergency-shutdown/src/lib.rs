@@ -155,8 +155,8 @@ pub mod module {
 			T::ShutdownOrigin::ensure_origin(origin)?;
 			ensure!(Self::is_shutdown(), Error::<T>::MustAfterShutdown); // must after shutdown
 
-			// Ensure all debits of CDPs have been settled, and all collateral auction has
-			// been done or canceled. Settle all collaterals type CDPs which have debit,
+			// Ensure all debits of CDPs have been settled, and all collateral, surplus and
+			// debit auctions have been done or canceled. Settle all collaterals type CDPs which have debit,
 			// cancel all collateral auctions in forward stage and wait for all collateral
 			// auctions in reverse stage to be ended.
 			let collateral_currency_ids = T::CollateralCurrencyIds::get();
@@ -173,6 +173,16 @@ pub mod module {
 				);
 			}
 
+			// there's no surplus or debit auction
+			ensure!(
+				<T as Config>::AuctionManagerHandler::get_total_surplus_in_auction().is_zero(),
+				Error::<T>::ExistPotentialSurplus,
+			);
+			ensure!(
+				<T as Config>::AuctionManagerHandler::get_total_debit_in_auction().is_zero(),
+				Error::<T>::ExistUnhandledDebit,
+			);
+
 			// Open refund stage
 			CanRefund::<T>::put(true);
 			Self::deposit_event(Event::OpenRefund(<frame_system::Pallet<T>>::block_number()));
@@ -177,6 +177,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		unimplemented!()
 	}
 
+	fn new_debit_auction(_amount: Self::Balance, _fix: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		unimplemented!()
 	}
@@ -188,6 +196,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 ord_parameter_types! {
@@ -211,6 +227,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdownModule;
 	type WeightInfo = ();
 }
 
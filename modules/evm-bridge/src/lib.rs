@@ -30,7 +30,10 @@ use primitive_types::H256;
 use sp_core::{H160, U256};
 use sp_runtime::SaturatedConversion;
 use sp_std::vec::Vec;
-use support::{EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, EVM};
+use support::{
+	EVMBridge as EVMBridgeTrait, ExecutionMode, InvokeContext, LiquidationEvmBridge, EVM,
+	LIQUIDATION_CONTRACT_GAS_LIMIT,
+};
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::EVM as EVM<AccountIdOf<T>>>::Balance;
@@ -45,6 +48,8 @@ pub enum Action {
 	TotalSupply = "totalSupply()",
 	BalanceOf = "balanceOf(address)",
 	Transfer = "transfer(address,uint256)",
+	Liquidate = "liquidate(address,address,uint256,uint256)",
+	OnCollateralTransfer = "onCollateralTransfer(address,uint256)",
 }
 
 mod mock;
@@ -201,6 +206,66 @@ impl<T: Config> EVMBridgeTrait<AccountIdOf<T>, BalanceOf<T>> for Pallet<T> {
 	}
 }
 
+impl<T: Config> LiquidationEvmBridge<BalanceOf<T>> for Pallet<T> {
+	// Calls the liquidate method on a liquidation contract using the given
+	// context.
+	fn liquidate(
+		context: InvokeContext,
+		collateral: H160,
+		repay_dest: H160,
+		amount: BalanceOf<T>,
+		min_repayment: BalanceOf<T>,
+	) -> DispatchResult {
+		// LiquidationContract.liquidate method hash
+		let mut input = Into::<u32>::into(Action::Liquidate).to_be_bytes().to_vec();
+		// append collateral ERC20 address
+		input.extend_from_slice(H256::from(collateral).as_bytes());
+		// append repay destination address
+		input.extend_from_slice(H256::from(repay_dest).as_bytes());
+		// append collateral amount
+		input.extend_from_slice(H256::from_uint(&U256::from(amount.saturated_into::<u128>())).as_bytes());
+		// append min repayment
+		input.extend_from_slice(H256::from_uint(&U256::from(min_repayment.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() { 0 } else { 1_000 };
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			LIQUIDATION_CONTRACT_GAS_LIMIT,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Self::handle_exit_reason(info.exit_reason)
+	}
+
+	// Calls the onCollateralTransfer method on a liquidation contract using
+	// the given context.
+	fn on_collateral_transfer(context: InvokeContext, collateral: H160, amount: BalanceOf<T>) -> DispatchResult {
+		// LiquidationContract.onCollateralTransfer method hash
+		let mut input = Into::<u32>::into(Action::OnCollateralTransfer).to_be_bytes().to_vec();
+		// append collateral ERC20 address
+		input.extend_from_slice(H256::from(collateral).as_bytes());
+		// append collateral amount
+		input.extend_from_slice(H256::from_uint(&U256::from(amount.saturated_into::<u128>())).as_bytes());
+
+		let storage_limit = if context.origin == Default::default() { 0 } else { 1_000 };
+
+		let info = T::EVM::execute(
+			context,
+			input,
+			Default::default(),
+			LIQUIDATION_CONTRACT_GAS_LIMIT,
+			storage_limit,
+			ExecutionMode::Execute,
+		)?;
+
+		Self::handle_exit_reason(info.exit_reason)
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	fn handle_exit_reason(exit_reason: ExitReason) -> Result<(), DispatchError> {
 		match exit_reason {
@@ -25,12 +25,52 @@ pub use frame_benchmarking::{account, benchmarks};
 pub use frame_support::traits::Get;
 pub use frame_system::RawOrigin;
 
+use frame_support::{assert_ok, dispatch::DispatchResult};
+use sp_trie::{MemoryDB, TrieDBMut, TrieMut};
+
 pub struct Module<T: Config>(crate::Pallet<T>);
 
 const SEED: u32 = 0;
 
+/// Build a relaychain state containing the staking ledger of the sub-account with the given
+/// total, and an unrelated value of `n` bytes to scale the size of the proof.
+fn relaychain_staking_ledger_proof<T: Config>(total: Balance, n: u32) -> (H256, Vec<Vec<u8>>) {
+	let ledger = RelayChainStakingLedger {
+		stash: T::SovereignSubAccount::get(),
+		total,
+		active: total,
+		unlocking: vec![],
+		claimed_rewards: vec![],
+	};
+
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = H256::default();
+	{
+		let mut trie = TrieDBMut::<Layout<BlakeTwo256>>::new(&mut db, &mut root);
+		trie.insert(&crate::Pallet::<T>::relaychain_staking_ledger_key(), &ledger.encode())
+			.expect("insert into an in-memory trie should not fail");
+		trie.insert(b"unrelated key", &vec![0u8; n as usize])
+			.expect("insert into an in-memory trie should not fail");
+	}
+	let proof = db.drain().into_iter().map(|(_, (node, _))| node).collect();
+	(root, proof)
+}
+
+/// Fill the redeem request queue with `n` requests, ahead of the requests with the default fee.
+fn fill_redeem_request_queue<T: Config>(n: u32) -> DispatchResult {
+	let amount = 1_000_000_000_000;
+	for i in 0..n {
+		let redeemer: T::AccountId = account("redeemer", i, SEED);
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &redeemer, amount)?;
+		crate::Pallet::<T>::request_redeem(RawOrigin::Signed(redeemer).into(), amount, Permill::from_percent(10))?;
+	}
+	Ok(())
+}
+
 benchmarks! {
 	mint {
+		let n in 0 .. T::MaxRedeemRequests::get();
+		fill_redeem_request_queue::<T>(n)?;
 		let amount = 1_000_000_000_000;
 		let caller: T::AccountId = account("caller", 0, SEED);
 		<T as module::Config>::Currency::deposit(T::StakingCurrencyId::get(), &caller, amount)?;
@@ -38,6 +78,8 @@ benchmarks! {
 	}: _(RawOrigin::Signed(caller), amount)
 
 	mint_for_requests {
+		let n in 0 .. T::MaxRedeemRequests::get().saturating_sub(3);
+		fill_redeem_request_queue::<T>(n)?;
 		let amount = 1_000_000_000_000;
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let caller1: T::AccountId = account("callera", 0, SEED);
@@ -69,6 +111,8 @@ benchmarks! {
 	}: _(RawOrigin::Root, 1_000_000_000)
 
 	request_redeem {
+		let n in 0 .. T::MaxRedeemRequests::get().saturating_sub(1);
+		fill_redeem_request_queue::<T>(n)?;
 		let amount = 1_000_000_000_000_000;
 		let caller: T::AccountId = account("caller", 0, SEED);
 		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &caller, amount)?;
@@ -91,6 +135,56 @@ benchmarks! {
 	xcm_unbond {}: {
 		let _ = crate::Pallet::<T>::process_scheduled_unbond(1_000_000_000_000_000);
 	}
+
+	redeem_by_dex {
+		let n in 0 .. T::MaxRedeemRequests::get().saturating_sub(1);
+		fill_redeem_request_queue::<T>(n)?;
+		let amount = 1_000_000_000_000_000;
+		let pool_amount = 10_000_000_000_000_000;
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let provider: T::AccountId = account("provider", 0, SEED);
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &caller, amount)?;
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &provider, pool_amount)?;
+		<T as module::Config>::Currency::deposit(T::StakingCurrencyId::get(), &provider, pool_amount / 10)?;
+		let _ = T::DEX::add_liquidity(&provider, T::LiquidCurrencyId::get(), T::StakingCurrencyId::get(), pool_amount, pool_amount / 10, 0, false);
+	}: _(RawOrigin::Signed(caller), amount, Permill::from_percent(1), Permill::default())
+
+	update_total_staking_currency_from_proof {
+		let n in 0 .. 100_000;
+		crate::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000)?;
+		let bonded = 1_000_000_000_000u128.saturating_sub(crate::Pallet::<T>::available_staking_balance());
+		let (root, proof) = relaychain_staking_ledger_proof::<T>(bonded, n);
+		frame_support::storage::unhashed::put(BENCHMARK_RELAYCHAIN_STORAGE_ROOT_KEY, &root);
+	}: {
+		assert_ok!(crate::Pallet::<T>::update_total_staking_currency_from_proof(RawOrigin::Root.into(), proof));
+	}
+	verify {
+		assert_eq!(crate::Pallet::<T>::total_staking_currency(), 1_000_000_000_000);
+	}
+
+	on_initialize {
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &account("caller", 0, SEED), 10_000_000_000_000)?;
+		crate::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000)?;
+		crate::Pallet::<T>::set_reward_rates(RawOrigin::Root.into(), T::MaxRewardPerEra::get(), Permill::from_percent(10))?;
+		LastRewardAccrual::<T>::put(<T as frame_system::Config>::BlockNumber::one());
+	}: {
+		crate::Pallet::<T>::accrue_staking_reward(T::EraLength::get().saturating_add(One::one()));
+	}
+
+	set_reward_rates {
+		crate::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000)?;
+		LastRewardAccrual::<T>::put(<T as frame_system::Config>::BlockNumber::one());
+	}: _(RawOrigin::Root, T::MaxRewardPerEra::get(), Permill::from_percent(10))
+
+	report_slash {
+		let n in 1 .. 10;
+		let slashes = (0..n).map(|i| SlashInfo {
+			validator: account("validator", i, SEED),
+			relaychain_token_amount: 1_000_000_000,
+		}).collect::<Vec<_>>();
+		<T as module::Config>::Currency::deposit(T::LiquidCurrencyId::get(), &T::InsuranceAccount::get(), 10_000_000_000_000)?;
+		crate::Pallet::<T>::set_total_staking_currency(RawOrigin::Root.into(), 1_000_000_000_000)?;
+	}: _(RawOrigin::Root, 0, slashes)
 }
 
 #[cfg(test)]
@@ -172,4 +266,34 @@ mod tests {
 			assert_ok!(Pallet::<Runtime>::test_benchmark_xcm_unbond());
 		});
 	}
+	#[test]
+	fn test_redeem_by_dex() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_redeem_by_dex());
+		});
+	}
+	#[test]
+	fn test_update_total_staking_currency_from_proof() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_update_total_staking_currency_from_proof());
+		});
+	}
+	#[test]
+	fn test_on_initialize() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_on_initialize());
+		});
+	}
+	#[test]
+	fn test_set_reward_rates() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_set_reward_rates());
+		});
+	}
+	#[test]
+	fn test_report_slash() {
+		ExtBuilder::default().build().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::test_benchmark_report_slash());
+		});
+	}
 }
@@ -20,20 +20,28 @@
 #![allow(clippy::unused_unit)]
 
 pub mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
 
-use frame_support::{log, pallet_prelude::*, transactional, weights::Weight, BoundedVec};
+use frame_support::{
+	log, pallet_prelude::*, traits::StorageVersion, transactional, weights::Weight, BoundedVec, StorageHasher,
+};
 use frame_system::{ensure_signed, pallet_prelude::*};
 
-use module_support::{CallBuilder, ExchangeRate, ExchangeRateProvider, Ratio};
+use module_homa_validator_list::{SlashInfo, SlashValidatorGuarantees};
+use module_support::{CallBuilder, DEXManager, ExchangeRate, ExchangeRateProvider, Ratio};
 use orml_traits::{
 	arithmetic::Signed, BalanceStatus, MultiCurrency, MultiCurrencyExtended, MultiReservableCurrency, XcmTransfer,
 };
-use primitives::{Balance, CurrencyId};
+use primitives::{Balance, CurrencyId, EraIndex};
+use sp_core::H256;
 use sp_runtime::{
-	traits::{BlockNumberProvider, Bounded, Saturating, Zero},
+	helpers_128bit::multiply_by_rational,
+	traits::{
+		BlakeTwo256, BlockNumberProvider, Bounded, One, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+	},
 	ArithmeticError, FixedPointNumber, Permill,
 };
 use sp_std::{
@@ -42,11 +50,48 @@ use sp_std::{
 	ops::Mul,
 	prelude::*,
 };
+use sp_trie::{read_trie_value, Layout, StorageProof};
 use xcm::latest::prelude::*;
 
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The number of bisection steps used to find the largest amount that can be redeemed through DEX.
+pub const DEX_REDEEM_SEARCH_STEPS: u32 = 16;
+
+/// A chunk of the staking ledger that is unlocking on the relaychain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RelayChainUnlockChunk {
+	/// Amount of funds to be unlocked.
+	#[codec(compact)]
+	pub value: Balance,
+	/// Era number at which point it'll be unlocked.
+	#[codec(compact)]
+	pub era: u32,
+}
+
+/// The storage key of the relaychain storage root set by the benchmarks, which overrides
+/// `Config::RelayChainStorageRoot` because there is no relay parent in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub const BENCHMARK_RELAYCHAIN_STORAGE_ROOT_KEY: &[u8] = b":homa_lite:benchmark_relaychain_storage_root";
+
+/// The staking ledger of a controller account on the relaychain, as stored in `Staking::Ledger`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RelayChainStakingLedger<AccountId> {
+	/// The stash account whose balance is actually locked and at stake.
+	pub stash: AccountId,
+	/// The total amount of the stash's balance, including the unlocking chunks.
+	#[codec(compact)]
+	pub total: Balance,
+	/// The total amount of the stash's balance that will be at stake in any forthcoming rounds.
+	#[codec(compact)]
+	pub active: Balance,
+	/// Any balance that is becoming free.
+	pub unlocking: Vec<RelayChainUnlockChunk>,
+	/// List of eras for which the stakers behind a validator have claimed rewards.
+	pub claimed_rewards: Vec<u32>,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -138,6 +183,45 @@ pub mod module {
 		/// Maximum number of scheduled unbonds allowed
 		#[pallet::constant]
 		type MaxScheduledUnbonds: Get<u32>;
+
+		/// DEX to swap Liquid currency into Staking currency for instant redeem.
+		type DEX: DEXManager<Self::AccountId, CurrencyId, Balance>;
+
+		/// Maximum number of redeem requests allowed in the queue
+		#[pallet::constant]
+		type MaxRedeemRequests: Get<u32>;
+
+		/// The account ID of the Homa Lite sub-account on the relaychain, whose staking ledger is
+		/// read from relaychain state proofs.
+		#[pallet::constant]
+		type SovereignSubAccount: Get<Self::AccountId>;
+
+		/// The storage root of the relay parent block, relaychain state proofs are verified
+		/// against it.
+		type RelayChainStorageRoot: Get<Option<H256>>;
+
+		/// The maximum difference allowed between the Staking total proven from the relaychain
+		/// state and the current `TotalStakingCurrency`, in percentage of the latter.
+		#[pallet::constant]
+		type MaxStakingLedgerDeviation: Get<Permill>;
+
+		/// The number of relaychain blocks per staking era, rewards are accrued once per era.
+		#[pallet::constant]
+		type EraLength: Get<Self::BlockNumber>;
+
+		/// The account that receives the protocol commission of staking rewards, as Liquid
+		/// currency.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// The account holding Liquid currency as the insurance buffer, which is burned to cover
+		/// slashes on the relaychain before the loss is socialised.
+		#[pallet::constant]
+		type InsuranceAccount: Get<Self::AccountId>;
+
+		/// The guarantees of relaychain validators, slashed first to cover the slashes of their
+		/// validators.
+		type ValidatorGuarantees: SlashValidatorGuarantees<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -158,6 +242,20 @@ pub mod module {
 		TooManyScheduledUnbonds,
 		/// The xcm operation have failed
 		XcmFailed,
+		/// Too many redeem requests in the queue
+		TooManyRedeemRequests,
+		/// The storage root of the relay parent is not available.
+		RelayChainStorageRootNotFound,
+		/// The relaychain state proof is invalid or incomplete.
+		InvalidStorageProof,
+		/// The staking ledger of the sub-account is not found in the relaychain state.
+		StakingLedgerNotFound,
+		/// The staking ledger of the sub-account cannot be decoded.
+		InvalidStakingLedger,
+		/// The estimated reward rate exceeds the max reward per era.
+		ExceededMaxRewardPerEra,
+		/// The slash of the validator in the era has already been reported.
+		SlashAlreadyReported,
 	}
 
 	#[pallet::event]
@@ -203,6 +301,33 @@ pub mod module {
 		/// The amount of the staking currency available to be redeemed is set.
 		/// \[total_available_staking_balance\]
 		AvailableStakingBalanceSet(Balance),
+
+		/// The user has redeemed some Liquid currency by swapping it on DEX.
+		/// \[user, staking_amount_received, liquid_amount_swapped\]
+		RedeemedByDex(T::AccountId, Balance, Balance),
+
+		/// The redeem request has been filled, fully if there's no remaining amount.
+		/// \[who, liquid_amount_filled, liquid_amount_remaining\]
+		RedeemRequestFilled(T::AccountId, Balance, Balance),
+
+		/// The Staking total proven from the relaychain state diverges from the current total by
+		/// more than allowed, and is not applied. \[expected_staking_total,
+		/// reported_staking_total\]
+		TotalStakingCurrencyDiverged(Balance, Balance),
+
+		/// The estimated reward rate per era and the commission rate are set.
+		/// \[reward_rate_per_era, commission_rate\]
+		RewardRatesSet(Permill, Permill),
+
+		/// The estimated staking rewards are accrued, and the commission is minted to the
+		/// treasury. \[staking_reward, liquid_commission_minted, new_exchange_rate\]
+		StakingRewardAccrued(Balance, Balance, Ratio),
+
+		/// Slashes on the relaychain are reported, and the loss not covered by the guarantees and
+		/// the insurance buffer is socialised. \[staking_amount_slashed,
+		/// liquid_amount_burned_from_guarantees, liquid_amount_burned_from_insurance,
+		/// new_exchange_rate\]
+		Slashed(Balance, Balance, Balance, Ratio),
 	}
 
 	/// The total amount of the staking currency on the relaychain.
@@ -243,11 +368,74 @@ pub mod module {
 	pub type ScheduledUnbond<T: Config> =
 		StorageValue<_, BoundedVec<(Balance, RelayChainBlockNumberOf<T>), T::MaxScheduledUnbonds>, ValueQuery>;
 
+	/// The queue of redeem requests. Requests are ordered by the additional fee in descending
+	/// order, then by the time they are requested. Requests in the front are matched first.
+	/// RedeemRequestQueue: value: BoundedVec<(redeemer: AccountId, additional_fee: Permill)>
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_request_queue)]
+	pub type RedeemRequestQueue<T: Config> =
+		StorageValue<_, BoundedVec<(T::AccountId, Permill), T::MaxRedeemRequests>, ValueQuery>;
+
+	/// The total amount of Liquid currency filled from redeem requests, and the block number
+	/// since when it's accumulated. This info is used to estimate the waiting time of requests.
+	/// RedeemRequestsFilled: value: (liquid_amount: Balance, since: BlockNumber)
+	#[pallet::storage]
+	#[pallet::getter(fn redeem_requests_filled)]
+	pub type RedeemRequestsFilled<T: Config> = StorageValue<_, (Balance, T::BlockNumber), OptionQuery>;
+
+	/// The estimated rate of staking rewards earned per era on the relaychain.
+	/// EstimatedRewardRatePerEra: value: Permill
+	#[pallet::storage]
+	#[pallet::getter(fn estimated_reward_rate_per_era)]
+	pub type EstimatedRewardRatePerEra<T: Config> = StorageValue<_, Permill, ValueQuery>;
+
+	/// The percentage of the staking rewards taken as protocol commission.
+	/// CommissionRate: value: Permill
+	#[pallet::storage]
+	#[pallet::getter(fn commission_rate)]
+	pub type CommissionRate<T: Config> = StorageValue<_, Permill, ValueQuery>;
+
+	/// The relaychain block number of the era rewards were last accrued at.
+	/// LastRewardAccrual: value: RelayChainBlockNumber
+	#[pallet::storage]
+	#[pallet::getter(fn last_reward_accrual)]
+	pub type LastRewardAccrual<T: Config> = StorageValue<_, RelayChainBlockNumberOf<T>, ValueQuery>;
+
+	/// The estimated staking rewards accrued since the total staking currency was last set or
+	/// proven.
+	/// UnprovenStakingReward: value: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn unproven_staking_reward)]
+	pub type UnprovenStakingReward<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The validators whose slashes on the relaychain have been reported, by era.
+	/// ReportedSlashes: double_map EraIndex, AccountId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn reported_slashes)]
+	pub type ReportedSlashes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// The current storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Reserve the weight of accruing the staking rewards in `on_finalize`.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			<T as Config>::WeightInfo::on_initialize()
+		}
+
+		/// Accrue the estimated staking rewards of the eras passed on the relaychain. This is
+		/// done on finalize because the relaychain block number is only known after the
+		/// validation data of the block is set.
+		fn on_finalize(_n: T::BlockNumber) {
+			Self::accrue_staking_reward(T::RelayChainBlockNumber::current_block_number());
+		}
+
 		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
 			let mut current_weight = 0;
 			// If enough weight, process the next XCM unbond.
@@ -302,7 +490,7 @@ pub mod module {
 		///
 		/// Parameters:
 		/// - `amount`: The amount of Staking currency to be exchanged.
-		#[pallet::weight(< T as Config >::WeightInfo::mint())]
+		#[pallet::weight(< T as Config >::WeightInfo::mint(T::MaxRedeemRequests::get()))]
 		#[transactional]
 		pub fn mint(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
 			let minter = ensure_signed(origin)?;
@@ -323,6 +511,7 @@ pub mod module {
 			ensure!(!staking_total.is_zero(), Error::<T>::InvalidTotalStakingCurrency);
 
 			TotalStakingCurrency::<T>::put(staking_total);
+			UnprovenStakingReward::<T>::kill();
 			Self::deposit_event(Event::<T>::TotalStakingCurrencySet(staking_total));
 
 			Ok(())
@@ -404,7 +593,7 @@ pub mod module {
 		/// Parameters:
 		/// - `amount`: The amount of Staking currency to be exchanged.
 		/// - `requests`: The redeem requests that are prioritized to match.
-		#[pallet::weight(< T as Config >::WeightInfo::mint_for_requests())]
+		#[pallet::weight(< T as Config >::WeightInfo::mint_for_requests(T::MaxRedeemRequests::get()))]
 		#[transactional]
 		pub fn mint_for_requests(
 			origin: OriginFor<T>,
@@ -419,10 +608,13 @@ pub mod module {
 		/// Put in an request to redeem Staking currencies used to mint Liquid currency.
 		/// The redemption will happen after the currencies are unbonded on the relaychain.
 		///
+		/// Requests are matched in the order of the additional fee, then the time they are
+		/// requested. Replacing a request moves it behind the requests with the same fee.
+		///
 		/// Parameters:
 		/// - `liquid_amount`: The amount of liquid currency to be redeemed into Staking currency.
 		/// - `additional_fee`: Percentage of the fee to be awarded to the minter.
-		#[pallet::weight(< T as Config >::WeightInfo::request_redeem())]
+		#[pallet::weight(< T as Config >::WeightInfo::request_redeem(T::MaxRedeemRequests::get()))]
 		#[transactional]
 		pub fn request_redeem(
 			origin: OriginFor<T>,
@@ -431,107 +623,58 @@ pub mod module {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			if liquid_amount.is_zero() {
-				// If the amount is zero, cancel previous redeem request.
-				if let Some((request_amount, _)) = RedeemRequests::<T>::take(&who) {
-					// Unreserve the liquid fee and remove the redeem request.
-					let unreserved = T::Currency::unreserve(T::LiquidCurrencyId::get(), &who, request_amount);
-					ensure!(unreserved.is_zero(), Error::<T>::InsufficientReservedBalances);
+			Self::do_request_redeem(&who, liquid_amount, additional_fee)
+		}
 
-					Self::deposit_event(Event::<T>::RedeemRequestCancelled(who, request_amount));
-				}
-				return Ok(());
-			}
+		/// Redeem Liquid currency instantly by swapping it into Staking currency on DEX. Only the
+		/// amount that can be swapped at a rate within `max_slippage` of the Homa Lite exchange
+		/// rate is swapped, the remainder is put in the redeem request queue.
+		///
+		/// Parameters:
+		/// - `liquid_amount`: The amount of liquid currency to be redeemed into Staking currency.
+		/// - `max_slippage`: The maximum percentage the DEX rate can be below the exchange rate.
+		/// - `additional_fee`: Percentage of the fee to be awarded to the minter, for the amount
+		///   queued.
+		#[pallet::weight(< T as Config >::WeightInfo::redeem_by_dex(T::MaxRedeemRequests::get()))]
+		#[transactional]
+		pub fn redeem_by_dex(
+			origin: OriginFor<T>,
+			#[pallet::compact] liquid_amount: Balance,
+			max_slippage: Permill,
+			additional_fee: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
 			// Redeem amount must be above a certain limit.
 			ensure!(
 				Self::liquid_amount_is_above_minimum_threshold(liquid_amount),
 				Error::<T>::AmountBelowMinimumThreshold
 			);
+			T::Currency::ensure_can_withdraw(T::LiquidCurrencyId::get(), &who, liquid_amount)?;
+
+			// Swap as much as possible on DEX within the slippage.
+			let (swap_amount, min_staking_amount) = Self::get_dex_redeem_amount(liquid_amount, max_slippage)?;
+			if !swap_amount.is_zero() {
+				let staking_amount = T::DEX::swap_with_exact_supply(
+					&who,
+					&[T::LiquidCurrencyId::get(), T::StakingCurrencyId::get()],
+					swap_amount,
+					min_staking_amount,
+				)?;
 
-			RedeemRequests::<T>::try_mutate(&who, |request| -> DispatchResult {
-				let old_amount = request.take().map(|(amount, _)| amount).unwrap_or_default();
-
-				let diff_amount = liquid_amount.saturating_sub(old_amount);
-
-				let base_withdraw_fee = T::BaseWithdrawFee::get().mul(diff_amount);
-				if !base_withdraw_fee.is_zero() {
-					// Burn withdraw fee for increased amount
-					let slash_amount = T::Currency::slash(T::LiquidCurrencyId::get(), &who, base_withdraw_fee);
-					ensure!(slash_amount.is_zero(), Error::<T>::InsufficientLiquidBalance);
-				}
-
-				// Deduct BaseWithdrawFee from the liquid amount.
-				let liquid_amount = liquid_amount.saturating_sub(base_withdraw_fee);
-
-				// If there are available_staking_balances, redeem immediately with no additional fee.
-				let available_staking_balance = Self::available_staking_balance();
-				let actual_liquid_amount = min(
-					liquid_amount,
-					Self::convert_staking_to_liquid(available_staking_balance)?,
-				);
-
-				let mut liquid_remaining = liquid_amount;
-				if Self::convert_liquid_to_staking(actual_liquid_amount)? > T::XcmUnbondFee::get() {
-					// Immediately redeem from the available_staking_balances
-					let actual_staking_amount = Self::convert_liquid_to_staking(actual_liquid_amount)?;
-
-					// Redeem from the available_staking_balances costs no extra fee.
-					T::Currency::deposit(
-						T::StakingCurrencyId::get(),
-						&who,
-						actual_staking_amount.saturating_sub(T::XcmUnbondFee::get()),
-					)?;
-					let slash_amount = T::Currency::slash(T::LiquidCurrencyId::get(), &who, actual_liquid_amount);
-					ensure!(slash_amount.is_zero(), Error::<T>::InsufficientLiquidBalance);
-
-					// Update the available_staking_balance
-					let available_staking_balance = available_staking_balance.saturating_sub(actual_staking_amount);
-					AvailableStakingBalance::<T>::put(available_staking_balance);
-
-					Self::deposit_event(Event::<T>::Redeemed(
-						who.clone(),
-						actual_staking_amount,
-						actual_liquid_amount,
-					));
-					liquid_remaining = liquid_remaining.saturating_sub(actual_liquid_amount);
-				}
-
-				// Unredeemed requests are added to a queue.
-				if Self::liquid_amount_is_above_minimum_threshold(liquid_remaining) {
-					// Check if there's already a queued redeem request.
-					let (request_amount, _) = Self::redeem_requests(&who).unwrap_or((0, Permill::default()));
-
-					match liquid_remaining.cmp(&request_amount) {
-						// Lock more liquid currency.
-						Ordering::Greater => T::Currency::reserve(
-							T::LiquidCurrencyId::get(),
-							&who,
-							liquid_remaining.saturating_sub(request_amount),
-						),
-						Ordering::Less => {
-							T::Currency::unreserve(
-								T::LiquidCurrencyId::get(),
-								&who,
-								request_amount.saturating_sub(liquid_remaining),
-							);
-							Ok(())
-						}
-						_ => Ok(()),
-					}?;
-
-					// Insert/replace the new redeem request into storage.
-					*request = Some((liquid_remaining, additional_fee));
+				Self::deposit_event(Event::<T>::RedeemedByDex(who.clone(), staking_amount, swap_amount));
+			}
 
-					Self::deposit_event(Event::<T>::RedeemRequested(
-						who.clone(),
-						liquid_remaining,
-						additional_fee,
-					));
-				}
+			// The remainder is added to the redeem request queue.
+			let liquid_remaining = liquid_amount.saturating_sub(swap_amount);
+			if Self::liquid_amount_is_above_minimum_threshold(liquid_remaining) {
+				let request_amount = Self::redeem_requests(&who)
+					.map(|(amount, _)| amount)
+					.unwrap_or_default();
+				Self::do_request_redeem(&who, request_amount.saturating_add(liquid_remaining), additional_fee)?;
+			}
 
-				Ok(())
-			})
+			Ok(())
 		}
 
 		/// Request staking currencies to be unbonded from the RelayChain.
@@ -635,6 +778,144 @@ pub mod module {
 			let _ = Self::process_redeem_requests_with_available_staking_balance(max_num_matches)?;
 			Ok(())
 		}
+
+		/// Update the total amount of the Staking currency from a state proof of the sub-account's
+		/// staking ledger on the relaychain, verified against the storage root of the relay parent.
+		/// The proven total is the ledger's total, plus the `AvailableStakingBalance` already
+		/// withdrawn from the relaychain but not yet redeemed.
+		///
+		/// The Staking currency minted through XCM but not yet bonded on the relaychain is not in
+		/// the ledger, so the proof should be submitted when no such transfer is in flight.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// If the proven total differs from the current total by more than
+		/// `T::MaxStakingLedgerDeviation` plus the estimated rewards accrued since the total was
+		/// last set or proven, it is not applied and `TotalStakingCurrencyDiverged` is
+		/// emitted instead, for governance to investigate.
+		///
+		/// Parameters:
+		/// - `proof`: The trie nodes of the relaychain state proving `Staking::Ledger` of the
+		///   sub-account.
+		#[pallet::weight(< T as Config >::WeightInfo::update_total_staking_currency_from_proof(
+			proof.iter().fold(0u32, |size, node| size.saturating_add(node.len() as u32))
+		))]
+		#[transactional]
+		pub fn update_total_staking_currency_from_proof(origin: OriginFor<T>, proof: Vec<Vec<u8>>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let ledger = Self::read_relaychain_staking_ledger(proof)?;
+			let reported = ledger.total.saturating_add(Self::available_staking_balance());
+			ensure!(!reported.is_zero(), Error::<T>::InvalidTotalStakingCurrency);
+
+			let expected = Self::total_staking_currency();
+			let deviation = reported.max(expected).saturating_sub(reported.min(expected));
+			// The estimated rewards may not match the actual rewards on the relaychain.
+			let max_deviation = T::MaxStakingLedgerDeviation::get()
+				.mul(expected)
+				.saturating_add(Self::unproven_staking_reward());
+			if deviation > max_deviation {
+				Self::deposit_event(Event::<T>::TotalStakingCurrencyDiverged(expected, reported));
+			} else {
+				TotalStakingCurrency::<T>::put(reported);
+				UnprovenStakingReward::<T>::kill();
+				Self::deposit_event(Event::<T>::TotalStakingCurrencySet(reported));
+			}
+
+			Ok(())
+		}
+
+		/// Set the estimated staking reward rate per era and the protocol commission rate.
+		/// Rewards are accrued at the old rates before the update.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `reward_rate_per_era`: The estimated rate of rewards earned per era, must not exceed
+		///   `T::MaxRewardPerEra`.
+		/// - `commission_rate`: The percentage of rewards minted to the treasury as Liquid
+		///   currency.
+		#[pallet::weight(< T as Config >::WeightInfo::set_reward_rates())]
+		#[transactional]
+		pub fn set_reward_rates(
+			origin: OriginFor<T>,
+			reward_rate_per_era: Permill,
+			commission_rate: Permill,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				reward_rate_per_era <= T::MaxRewardPerEra::get(),
+				Error::<T>::ExceededMaxRewardPerEra
+			);
+
+			Self::accrue_staking_reward(T::RelayChainBlockNumber::current_block_number());
+			EstimatedRewardRatePerEra::<T>::put(reward_rate_per_era);
+			CommissionRate::<T>::put(commission_rate);
+			Self::deposit_event(Event::<T>::RewardRatesSet(reward_rate_per_era, commission_rate));
+
+			Ok(())
+		}
+
+		/// Report slashes of validators on the relaychain, and reduce the total amount of the
+		/// Staking currency by the slashed amount. Liquid currency worth the loss at the current
+		/// exchange rate is burned, first from the guarantees of the slashed validators, then from
+		/// the insurance buffer. The rest of the loss is socialised through the exchange rate.
+		/// Where `T::ValidatorGuarantees` is `()`, there are no guarantees and the loss is covered
+		/// by the insurance buffer only.
+		///
+		/// The slash of a validator can be reported once per era. Guarantees already burned for
+		/// the same slash are counted, not burned again.
+		/// Requires `T::GovernanceOrigin`
+		///
+		/// Parameters:
+		/// - `era`: The relaychain era the validators are slashed in.
+		/// - `slashes`: The slashed validators and the amount of Staking currency slashed.
+		#[pallet::weight(< T as Config >::WeightInfo::report_slash(slashes.len() as u32))]
+		#[transactional]
+		pub fn report_slash(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slashes: Vec<SlashInfo<Balance, T::AccountId>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			for slash in slashes.iter() {
+				ensure!(
+					!ReportedSlashes::<T>::contains_key(era, &slash.validator),
+					Error::<T>::SlashAlreadyReported
+				);
+				ReportedSlashes::<T>::insert(era, &slash.validator, ());
+			}
+
+			let staking_amount_slashed = slashes.iter().fold(Balance::zero(), |total, slash| {
+				total.saturating_add(slash.relaychain_token_amount)
+			});
+			let staking_total = Self::total_staking_currency();
+			// ensure TotalStakingCurrency doesn't become 0
+			ensure!(
+				staking_amount_slashed < staking_total,
+				Error::<T>::InvalidTotalStakingCurrency
+			);
+
+			// The Liquid currency worth the loss at the exchange rate before the slash.
+			let liquid_loss = Self::convert_staking_to_liquid(staking_amount_slashed)?;
+			let liquid_from_guarantees = T::ValidatorGuarantees::slash_guarantees(era, slashes);
+
+			let insurance_account = T::InsuranceAccount::get();
+			let insurance_balance = T::Currency::free_balance(T::LiquidCurrencyId::get(), &insurance_account);
+			let liquid_from_insurance = liquid_loss
+				.saturating_sub(liquid_from_guarantees)
+				.min(insurance_balance);
+			T::Currency::withdraw(T::LiquidCurrencyId::get(), &insurance_account, liquid_from_insurance)?;
+
+			TotalStakingCurrency::<T>::put(staking_total.saturating_sub(staking_amount_slashed));
+			Self::deposit_event(Event::<T>::Slashed(
+				staking_amount_slashed,
+				liquid_from_guarantees,
+				liquid_from_insurance,
+				Self::get_exchange_rate(),
+			));
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -658,6 +939,167 @@ pub mod module {
 				.ok_or(DispatchError::Arithmetic(ArithmeticError::Overflow))
 		}
 
+		/// Put in an request to redeem Staking currencies used to mint Liquid currency. Redeem
+		/// immediately from `AvailableStakingBalance` if possible, the remaining amount is queued.
+		/// A zero `liquid_amount` cancels the existing redeem request.
+		///
+		/// Parameters:
+		/// - `liquid_amount`: The amount of liquid currency to be redeemed into Staking currency.
+		/// - `additional_fee`: Percentage of the fee to be awarded to the minter.
+		fn do_request_redeem(who: &T::AccountId, liquid_amount: Balance, additional_fee: Permill) -> DispatchResult {
+			if liquid_amount.is_zero() {
+				// If the amount is zero, cancel previous redeem request.
+				if let Some((request_amount, _)) = RedeemRequests::<T>::take(who) {
+					// Unreserve the liquid fee and remove the redeem request.
+					let unreserved = T::Currency::unreserve(T::LiquidCurrencyId::get(), who, request_amount);
+					ensure!(unreserved.is_zero(), Error::<T>::InsufficientReservedBalances);
+					Self::dequeue_redeem_request(who);
+
+					Self::deposit_event(Event::<T>::RedeemRequestCancelled(who.clone(), request_amount));
+				}
+				return Ok(());
+			}
+
+			// Redeem amount must be above a certain limit.
+			ensure!(
+				Self::liquid_amount_is_above_minimum_threshold(liquid_amount),
+				Error::<T>::AmountBelowMinimumThreshold
+			);
+
+			RedeemRequests::<T>::try_mutate(who, |request| -> DispatchResult {
+				let old_amount = request.take().map(|(amount, _)| amount).unwrap_or_default();
+
+				let diff_amount = liquid_amount.saturating_sub(old_amount);
+
+				let base_withdraw_fee = T::BaseWithdrawFee::get().mul(diff_amount);
+				if !base_withdraw_fee.is_zero() {
+					// Burn withdraw fee for increased amount
+					let slash_amount = T::Currency::slash(T::LiquidCurrencyId::get(), who, base_withdraw_fee);
+					ensure!(slash_amount.is_zero(), Error::<T>::InsufficientLiquidBalance);
+				}
+
+				// Deduct BaseWithdrawFee from the liquid amount.
+				let liquid_amount = liquid_amount.saturating_sub(base_withdraw_fee);
+
+				// If there are available_staking_balances, redeem immediately with no additional fee.
+				let available_staking_balance = Self::available_staking_balance();
+				let actual_liquid_amount = min(
+					liquid_amount,
+					Self::convert_staking_to_liquid(available_staking_balance)?,
+				);
+
+				let mut liquid_remaining = liquid_amount;
+				if Self::convert_liquid_to_staking(actual_liquid_amount)? > T::XcmUnbondFee::get() {
+					// Immediately redeem from the available_staking_balances
+					let actual_staking_amount = Self::convert_liquid_to_staking(actual_liquid_amount)?;
+
+					// Redeem from the available_staking_balances costs no extra fee.
+					T::Currency::deposit(
+						T::StakingCurrencyId::get(),
+						who,
+						actual_staking_amount.saturating_sub(T::XcmUnbondFee::get()),
+					)?;
+					let slash_amount = T::Currency::slash(T::LiquidCurrencyId::get(), who, actual_liquid_amount);
+					ensure!(slash_amount.is_zero(), Error::<T>::InsufficientLiquidBalance);
+
+					// Update the available_staking_balance
+					let available_staking_balance = available_staking_balance.saturating_sub(actual_staking_amount);
+					AvailableStakingBalance::<T>::put(available_staking_balance);
+
+					Self::deposit_event(Event::<T>::Redeemed(
+						who.clone(),
+						actual_staking_amount,
+						actual_liquid_amount,
+					));
+					liquid_remaining = liquid_remaining.saturating_sub(actual_liquid_amount);
+				}
+
+				// Unredeemed requests are added to a queue.
+				if Self::liquid_amount_is_above_minimum_threshold(liquid_remaining) {
+					// Check if there's already a queued redeem request.
+					let (request_amount, _) = Self::redeem_requests(who).unwrap_or((0, Permill::default()));
+
+					match liquid_remaining.cmp(&request_amount) {
+						// Lock more liquid currency.
+						Ordering::Greater => T::Currency::reserve(
+							T::LiquidCurrencyId::get(),
+							who,
+							liquid_remaining.saturating_sub(request_amount),
+						),
+						Ordering::Less => {
+							T::Currency::unreserve(
+								T::LiquidCurrencyId::get(),
+								who,
+								request_amount.saturating_sub(liquid_remaining),
+							);
+							Ok(())
+						}
+						_ => Ok(()),
+					}?;
+
+					// Insert/replace the new redeem request into storage.
+					*request = Some((liquid_remaining, additional_fee));
+					Self::enqueue_redeem_request(who, additional_fee)?;
+
+					Self::deposit_event(Event::<T>::RedeemRequested(
+						who.clone(),
+						liquid_remaining,
+						additional_fee,
+					));
+				} else {
+					Self::dequeue_redeem_request(who);
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Find the largest amount of Liquid currency, up to `liquid_amount`, that can be swapped
+		/// into Staking currency on DEX at a rate within `max_slippage` of the Homa Lite exchange
+		/// rate.
+		///
+		/// return:
+		/// 	Result<(Balance, Balance), DispatchError>: The Liquid amount to swap, and the minimum
+		/// 	Staking amount to receive for it.
+		fn get_dex_redeem_amount(
+			liquid_amount: Balance,
+			max_slippage: Permill,
+		) -> Result<(Balance, Balance), DispatchError> {
+			let path = [T::LiquidCurrencyId::get(), T::StakingCurrencyId::get()];
+			let min_staking_amount_for = |amount: Balance| -> Result<Balance, DispatchError> {
+				let staking_amount = Self::convert_liquid_to_staking(amount)?;
+				Ok(Permill::one().saturating_sub(max_slippage).mul(staking_amount))
+			};
+			let is_acceptable = |amount: Balance, min_staking_amount: Balance| -> bool {
+				T::DEX::get_swap_target_amount(&path, amount)
+					.map_or(false, |target| !target.is_zero() && target >= min_staking_amount)
+			};
+
+			let min_staking_amount = min_staking_amount_for(liquid_amount)?;
+			if is_acceptable(liquid_amount, min_staking_amount) {
+				return Ok((liquid_amount, min_staking_amount));
+			}
+
+			// The DEX rate worsens as the swap amount grows, bisect for the largest acceptable amount.
+			let (mut low, mut high) = (Balance::zero(), liquid_amount);
+			for _ in 0..DEX_REDEEM_SEARCH_STEPS {
+				let mid = low.saturating_add(high.saturating_sub(low) / 2);
+				if mid == low {
+					break;
+				}
+				if is_acceptable(mid, min_staking_amount_for(mid)?) {
+					low = mid;
+				} else {
+					high = mid;
+				}
+			}
+
+			if low.is_zero() {
+				return Ok((Zero::zero(), Zero::zero()));
+			}
+			Ok((low, min_staking_amount_for(low)?))
+		}
+
 		/// Match a redeem request with a mint request. Attempt to redeem as much as possible.
 		/// Transfer a reduced amount of Staking currency from the Minter to the Redeemer.
 		/// Transfer the full amount of Liquid currency from Redeemer to Minter.
@@ -779,21 +1221,23 @@ pub mod module {
 			new_balances.clear();
 
 			let mut redeem_requests_limit_remaining = T::MaximumRedeemRequestMatchesForMint::get();
-			// Iterate all remaining redeem requests now.
-			for (redeemer, (request_amount, extra_fee)) in RedeemRequests::<T>::iter() {
+			// Iterate all remaining redeem requests now, in the order of the queue.
+			for (redeemer, _) in Self::redeem_request_queue().into_inner() {
 				// If all the currencies are minted, return.
 				if liquid_remaining.is_zero() || redeem_requests_limit_remaining.is_zero() {
 					break;
 				}
-				Self::match_mint_with_redeem_request(
-					minter,
-					&redeemer,
-					request_amount,
-					extra_fee,
-					&mut liquid_remaining,
-					&mut new_balances,
-				)?;
-				redeem_requests_limit_remaining -= 1;
+				if let Some((request_amount, extra_fee)) = Self::redeem_requests(&redeemer) {
+					Self::match_mint_with_redeem_request(
+						minter,
+						&redeemer,
+						request_amount,
+						extra_fee,
+						&mut liquid_remaining,
+						&mut new_balances,
+					)?;
+					redeem_requests_limit_remaining -= 1;
+				}
 			}
 
 			// Update storage to the new balances. Remove Redeem requests that have been filled.
@@ -865,8 +1309,9 @@ pub mod module {
 			Ok(())
 		}
 
-		/// Iterate through all redeem requests, then match them with available_staking_balance.
-		/// This should be called when new available_staking_balance becomes available.
+		/// Iterate through all redeem requests in the order of the queue, then match them with
+		/// available_staking_balance. This should be called when new available_staking_balance
+		/// becomes available.
 		///
 		/// params:
 		/// 	- `max_num_matches`: Maximum number of redeem requests to be matched.
@@ -887,7 +1332,11 @@ pub mod module {
 
 			let mut new_balances: Vec<(T::AccountId, Balance, Permill)> = vec![];
 			let mut num_matched = 0u32;
-			for (redeemer, (request_amount, extra_fee)) in RedeemRequests::<T>::iter() {
+			for (redeemer, _) in Self::redeem_request_queue().into_inner() {
+				let (request_amount, extra_fee) = match Self::redeem_requests(&redeemer) {
+					Some(request) => request,
+					None => continue,
+				};
 				let actual_liquid_amount = min(
 					request_amount,
 					Self::convert_staking_to_liquid(available_staking_balance)?,
@@ -932,20 +1381,221 @@ pub mod module {
 		}
 
 		/// Update the RedeemRequests storage with the new balances.
-		/// Remove Redeem requests that are dust, or have been filled.
+		/// Remove Redeem requests that are dust, or have been filled. Partially filled requests
+		/// keep their position in the queue.
 		#[allow(clippy::ptr_arg)]
 		fn update_redeem_requests(new_balances: &Vec<(T::AccountId, Balance, Permill)>) {
+			let mut total_filled = Balance::zero();
 			for (redeemer, new_balance, extra_fee) in new_balances {
-				if Self::liquid_amount_is_above_minimum_threshold(*new_balance) {
+				let old_balance = Self::redeem_requests(&redeemer)
+					.map(|(amount, _)| amount)
+					.unwrap_or_default();
+				let liquid_amount_filled = old_balance.saturating_sub(*new_balance);
+				total_filled = total_filled.saturating_add(liquid_amount_filled);
+
+				let liquid_amount_remaining = if Self::liquid_amount_is_above_minimum_threshold(*new_balance) {
 					RedeemRequests::<T>::insert(&redeemer, (*new_balance, *extra_fee));
+					*new_balance
 				} else {
 					if !new_balance.is_zero() {
 						// Unlock the dust and remove the request.
 						T::Currency::unreserve(T::LiquidCurrencyId::get(), redeemer, *new_balance);
 					}
 					RedeemRequests::<T>::remove(&redeemer);
+					Self::dequeue_redeem_request(redeemer);
+					Zero::zero()
+				};
+
+				Self::deposit_event(Event::<T>::RedeemRequestFilled(
+					redeemer.clone(),
+					liquid_amount_filled,
+					liquid_amount_remaining,
+				));
+			}
+
+			if !total_filled.is_zero() {
+				RedeemRequestsFilled::<T>::mutate(|filled| {
+					let (amount, _) = filled.get_or_insert((Zero::zero(), frame_system::Pallet::<T>::block_number()));
+					*amount = amount.saturating_add(total_filled);
+				});
+			}
+		}
+
+		/// Put the redeem request of `redeemer` into the queue, behind all requests with the same
+		/// or higher additional fee. The previous position of `redeemer` is dropped.
+		///
+		/// If the queue is full, the last request, which has the lowest fee, is cancelled to make
+		/// room when the new request pays a higher fee, so the queue can't be blocked by the
+		/// requests with the minimum amount and fee.
+		fn enqueue_redeem_request(redeemer: &T::AccountId, additional_fee: Permill) -> DispatchResult {
+			RedeemRequestQueue::<T>::try_mutate(|queue| -> DispatchResult {
+				let mut requests = queue.to_vec();
+				requests.retain(|(who, _)| who != redeemer);
+				let index = requests.partition_point(|(_, fee)| *fee >= additional_fee);
+				if requests.len() >= T::MaxRedeemRequests::get() as usize {
+					ensure!(index < requests.len(), Error::<T>::TooManyRedeemRequests);
+					if let Some((evicted, _)) = requests.pop() {
+						if let Some((request_amount, _)) = RedeemRequests::<T>::take(&evicted) {
+							let unreserved =
+								T::Currency::unreserve(T::LiquidCurrencyId::get(), &evicted, request_amount);
+							ensure!(unreserved.is_zero(), Error::<T>::InsufficientReservedBalances);
+							Self::deposit_event(Event::<T>::RedeemRequestCancelled(evicted, request_amount));
+						}
+					}
+				}
+				requests.insert(index, (redeemer.clone(), additional_fee));
+
+				*queue = BoundedVec::try_from(requests).map_err(|_| Error::<T>::TooManyRedeemRequests)?;
+				Ok(())
+			})
+		}
+
+		/// Remove the redeem request of `redeemer` from the queue.
+		fn dequeue_redeem_request(redeemer: &T::AccountId) {
+			RedeemRequestQueue::<T>::mutate(|queue| queue.retain(|(who, _)| who != redeemer));
+		}
+
+		/// Get the number of redeem requests in the queue, and the total amount of Liquid currency
+		/// requested by them.
+		pub fn redeem_queue_depth() -> (u32, Balance) {
+			let queue = Self::redeem_request_queue();
+			let liquid_amount = queue
+				.iter()
+				.filter_map(|(redeemer, _)| Self::redeem_requests(redeemer))
+				.fold(Balance::zero(), |total, (amount, _)| total.saturating_add(amount));
+			(queue.len() as u32, liquid_amount)
+		}
+
+		/// Get the position of the redeem request of `who` in the queue, and the total amount of
+		/// Liquid currency requested by the requests ahead of it.
+		pub fn redeem_request_position(who: &T::AccountId) -> Option<(u32, Balance)> {
+			let queue = Self::redeem_request_queue();
+			let position = queue.iter().position(|(redeemer, _)| redeemer == who)?;
+			let liquid_amount_ahead = queue[..position]
+				.iter()
+				.filter_map(|(redeemer, _)| Self::redeem_requests(redeemer))
+				.fold(Balance::zero(), |total, (amount, _)| total.saturating_add(amount));
+			Some((position as u32, liquid_amount_ahead))
+		}
+
+		/// Estimate the number of blocks needed to fill `liquid_amount` of redeem requests, by the
+		/// average rate redeem requests have been filled so far. Returns `None` if no redeem
+		/// request has been filled yet.
+		pub fn estimate_redeem_wait(liquid_amount: Balance) -> Option<T::BlockNumber> {
+			let (filled, since) = Self::redeem_requests_filled()?;
+			if filled.is_zero() {
+				return None;
+			}
+
+			let elapsed: u128 = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(since)
+				.unique_saturated_into();
+			let blocks = multiply_by_rational(liquid_amount, elapsed, filled).ok()?;
+			Some(T::BlockNumber::unique_saturated_from(blocks))
+		}
+
+		/// Accrue the estimated staking rewards of the bonded Staking currency, compounded per era,
+		/// for the whole eras passed since the last accrual. The commission is minted to the
+		/// treasury as Liquid currency, so that it is worth `CommissionRate` of the rewards at
+		/// the new exchange rate.
+		pub(crate) fn accrue_staking_reward(now: RelayChainBlockNumberOf<T>) {
+			// The relaychain block number is unknown.
+			if now.is_zero() {
+				return;
+			}
+
+			let last_accrual = Self::last_reward_accrual();
+			// Start counting eras from the first block seen.
+			if last_accrual.is_zero() {
+				LastRewardAccrual::<T>::put(now);
+				return;
+			}
+
+			let era_length = T::EraLength::get().max(One::one());
+			let eras = now.saturating_sub(last_accrual) / era_length;
+			if eras.is_zero() {
+				return;
+			}
+			LastRewardAccrual::<T>::put(last_accrual.saturating_add(eras.saturating_mul(era_length)));
+
+			let staking_total = Self::total_staking_currency();
+			// Only the bonded amount earns rewards, the available staking balance is unbonded.
+			let staking_bonded = staking_total.saturating_sub(Self::available_staking_balance());
+			let staking_reward = Ratio::from(Self::estimated_reward_rate_per_era())
+				.saturating_add(Ratio::one())
+				.saturating_pow(eras.unique_saturated_into())
+				.saturating_sub(Ratio::one())
+				.saturating_mul_int(staking_bonded);
+			if staking_reward.is_zero() {
+				return;
+			}
+
+			let new_staking_total = staking_total.saturating_add(staking_reward);
+			let commission = Self::commission_rate().mul(staking_reward);
+			let liquid_total = T::Currency::total_issuance(T::LiquidCurrencyId::get());
+			// liquid_commission / (liquid_total + liquid_commission) = commission / new_staking_total
+			let mut liquid_commission = if liquid_total.is_zero() {
+				Zero::zero()
+			} else {
+				multiply_by_rational(liquid_total, commission, new_staking_total.saturating_sub(commission))
+					.unwrap_or_default()
+			};
+
+			if !liquid_commission.is_zero() {
+				if let Err(e) = T::Currency::deposit(
+					T::LiquidCurrencyId::get(),
+					&T::TreasuryAccount::get(),
+					liquid_commission,
+				) {
+					log::warn!(
+						target: "homa-lite",
+						"accrue_staking_reward: failed to mint commission {:?}: {:?}. \
+						This is unexpected but should be safe",
+						liquid_commission, e
+					);
+					liquid_commission = Zero::zero();
 				}
 			}
+
+			TotalStakingCurrency::<T>::put(new_staking_total);
+			UnprovenStakingReward::<T>::mutate(|reward| *reward = reward.saturating_add(staking_reward));
+			Self::deposit_event(Event::<T>::StakingRewardAccrued(
+				staking_reward,
+				liquid_commission,
+				Self::get_exchange_rate(),
+			));
+		}
+
+		/// The storage key of `Staking::Ledger` of the sub-account in the relaychain state.
+		pub fn relaychain_staking_ledger_key() -> Vec<u8> {
+			let mut key = frame_support::storage::storage_prefix(b"Staking", b"Ledger").to_vec();
+			key.extend(T::SovereignSubAccount::get().using_encoded(Blake2_128Concat::hash));
+			key
+		}
+
+		/// Read the staking ledger of the sub-account from a relaychain state proof, verified
+		/// against the storage root of the relay parent.
+		fn read_relaychain_staking_ledger(
+			proof: Vec<Vec<u8>>,
+		) -> Result<RelayChainStakingLedger<T::AccountId>, DispatchError> {
+			let storage_root = Self::relaychain_storage_root().ok_or(Error::<T>::RelayChainStorageRootNotFound)?;
+			let db = StorageProof::new(proof).into_memory_db::<BlakeTwo256>();
+			let encoded_ledger =
+				read_trie_value::<Layout<BlakeTwo256>, _>(&db, &storage_root, &Self::relaychain_staking_ledger_key())
+					.map_err(|_| Error::<T>::InvalidStorageProof)?
+					.ok_or(Error::<T>::StakingLedgerNotFound)?;
+			RelayChainStakingLedger::decode(&mut &encoded_ledger[..])
+				.map_err(|_| Error::<T>::InvalidStakingLedger.into())
+		}
+
+		/// The storage root of the relay parent block, or the one set by the benchmarks.
+		fn relaychain_storage_root() -> Option<H256> {
+			#[cfg(feature = "runtime-benchmarks")]
+			let benchmark_root: Option<H256> = frame_support::storage::unhashed::get(BENCHMARK_RELAYCHAIN_STORAGE_ROOT_KEY);
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			let benchmark_root: Option<H256> = None;
+
+			benchmark_root.or_else(T::RelayChainStorageRoot::get)
 		}
 
 		fn liquid_amount_is_above_minimum_threshold(liquid_amount: Balance) -> bool {
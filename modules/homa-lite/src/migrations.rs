@@ -0,0 +1,102 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Event, Pallet, RedeemRequestQueue, RedeemRequests, Weight};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, StorageVersion},
+	BoundedVec,
+};
+use orml_traits::MultiReservableCurrency;
+use sp_runtime::traits::Zero;
+use sp_std::{convert::TryFrom, prelude::*};
+
+pub mod v1 {
+	use super::*;
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"homa-lite already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() == 1,
+			"homa-lite storage version not set."
+		);
+		assert!(
+			RedeemRequests::<T>::iter_keys()
+				.all(|redeemer| RedeemRequestQueue::<T>::get().iter().any(|(who, _)| *who == redeemer)),
+			"redeem request is not queued."
+		);
+		Ok(())
+	}
+
+	/// Put the existing redeem requests into `RedeemRequestQueue`, ordered by the additional fee.
+	/// Requests with the same fee have no known request time, they keep the storage order.
+	/// Requests beyond `MaxRedeemRequests` with the lowest fee are cancelled and their reserved
+	/// liquid currency is unreserved.
+	pub fn migrate<T: Config>() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "homa-lite", "Migrating homa-lite v1");
+
+		let mut requests = RedeemRequests::<T>::iter()
+			.map(|(redeemer, (_, additional_fee))| (redeemer, additional_fee))
+			.collect::<Vec<_>>();
+		let count = requests.len() as u64;
+		requests.sort_by(|(_, fee_a), (_, fee_b)| fee_b.cmp(fee_a));
+		let mut cancelled: u64 = 0;
+		if requests.len() > T::MaxRedeemRequests::get() as usize {
+			log::warn!(
+				target: "homa-lite",
+				"{:?} redeem requests exceed the queue limit, the rest are cancelled.",
+				requests.len()
+			);
+			for (redeemer, _) in requests.split_off(T::MaxRedeemRequests::get() as usize) {
+				if let Some((request_amount, _)) = RedeemRequests::<T>::take(&redeemer) {
+					let remaining = T::Currency::unreserve(T::LiquidCurrencyId::get(), &redeemer, request_amount);
+					if !remaining.is_zero() {
+						log::warn!(
+							target: "homa-lite",
+							"unreserve: {:?} of the cancelled redeem request of {:?} is not reserved. \
+							This is unexpected but should be safe",
+							remaining, redeemer
+						);
+					}
+					Pallet::<T>::deposit_event(Event::<T>::RedeemRequestCancelled(redeemer, request_amount));
+					cancelled = cancelled.saturating_add(1);
+				}
+			}
+		}
+		RedeemRequestQueue::<T>::put(BoundedVec::try_from(requests).expect("requests are truncated to the bound; qed"));
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(target: "homa-lite", "Completed homa-lite migration to v1");
+
+		T::DbWeight::get().reads_writes(
+			count.saturating_add(cancelled).saturating_add(1),
+			cancelled.saturating_mul(2).saturating_add(2),
+		)
+	}
+}
@@ -27,16 +27,19 @@ pub use frame_support::{
 };
 pub use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
 pub use module_relaychain::RelayChainCallBuilder;
-pub use module_support::mocks::MockAddressMapping;
+pub use module_support::{mocks::MockAddressMapping, DEXManager};
 pub use orml_traits::{parameter_type_with_key, XcmTransfer};
 pub use primitives::{Amount, TokenSymbol};
-pub use sp_core::H256;
+pub use sp_core::{H160, H256};
 pub use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
+pub use sp_trie::{MemoryDB, TrieDBMut, TrieMut};
 
 pub use cumulus_primitives_core::ParaId;
 pub use xcm::latest::prelude::*;
 pub use xcm_executor::traits::{InvertLocation, WeightBounds};
 
+use std::cell::RefCell;
+
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
 pub use crate as module_homa_lite;
@@ -50,6 +53,9 @@ pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
 pub const BOB: AccountId = AccountId32::new([2u8; 32]);
 pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
 pub const INVALID_CALLER: AccountId = AccountId32::new([254u8; 32]);
+pub const TREASURY: AccountId = AccountId32::new([10u8; 32]);
+pub const GUARANTOR: AccountId = AccountId32::new([11u8; 32]);
+pub const INSURANCE: AccountId = AccountId32::new([12u8; 32]);
 pub const ACALA: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
 pub const KSM: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 pub const LKSM: CurrencyId = CurrencyId::Token(TokenSymbol::LKSM);
@@ -251,6 +257,147 @@ impl module_currencies::Config for Runtime {
 	type OnDust = ();
 }
 
+thread_local! {
+	static LIQUIDITY_POOL: RefCell<(Balance, Balance)> = RefCell::new((0, 0));
+}
+
+/// Set the liquidity pool of the mock DEX.
+pub fn set_liquidity_pool(liquid_pool: Balance, staking_pool: Balance) {
+	LIQUIDITY_POOL.with(|v| *v.borrow_mut() = (liquid_pool, staking_pool));
+}
+
+/// A mock DEX with a single constant product liquidity pool of LKSM/KSM without fee.
+pub struct MockDEX;
+impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
+	fn get_liquidity_pool(currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> (Balance, Balance) {
+		match (currency_id_a, currency_id_b) {
+			(LKSM, KSM) => LIQUIDITY_POOL.with(|v| *v.borrow()),
+			(KSM, LKSM) => LIQUIDITY_POOL.with(|v| {
+				let (liquid_pool, staking_pool) = *v.borrow();
+				(staking_pool, liquid_pool)
+			}),
+			_ => (0, 0),
+		}
+	}
+
+	fn get_liquidity_token_address(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId) -> Option<H160> {
+		unimplemented!()
+	}
+
+	fn get_swap_target_amount(path: &[CurrencyId], supply_amount: Balance) -> Option<Balance> {
+		if path != [LKSM, KSM] {
+			return None;
+		}
+		let (liquid_pool, staking_pool) = LIQUIDITY_POOL.with(|v| *v.borrow());
+		if liquid_pool.is_zero() || staking_pool.is_zero() {
+			return None;
+		}
+		Some(staking_pool * supply_amount / (liquid_pool + supply_amount))
+	}
+
+	fn get_swap_supply_amount(_path: &[CurrencyId], _target_amount: Balance) -> Option<Balance> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_twap(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId, _window: u32) -> Option<ExchangeRate> {
+		unimplemented!()
+	}
+
+	fn swap_with_exact_supply(
+		who: &AccountId,
+		path: &[CurrencyId],
+		supply_amount: Balance,
+		min_target_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		let target_amount =
+			Self::get_swap_target_amount(path, supply_amount).ok_or(DispatchError::Other("invalid swap path"))?;
+		if target_amount < min_target_amount {
+			return Err(DispatchError::Other("insufficient target amount"));
+		}
+
+		Currencies::withdraw(LKSM, who, supply_amount)?;
+		Currencies::deposit(KSM, who, target_amount)?;
+		LIQUIDITY_POOL.with(|v| {
+			let (liquid_pool, staking_pool) = *v.borrow();
+			*v.borrow_mut() = (liquid_pool + supply_amount, staking_pool - target_amount);
+		});
+		Ok(target_amount)
+	}
+
+	fn swap_with_exact_target(
+		_who: &AccountId,
+		_path: &[CurrencyId],
+		_target_amount: Balance,
+		_max_supply_amount: Balance,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		_who: &AccountId,
+		_borrow_currency_id: CurrencyId,
+		_paired_currency_id: CurrencyId,
+		_borrow_amount: Balance,
+		_repay_currency_id: CurrencyId,
+		_max_repay_amount: Balance,
+		_callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
+	fn add_liquidity(
+		who: &AccountId,
+		currency_id_a: CurrencyId,
+		currency_id_b: CurrencyId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+		_min_share_increment: Balance,
+		_stake_increment_share: bool,
+	) -> DispatchResult {
+		let (liquid_amount, staking_amount) = match (currency_id_a, currency_id_b) {
+			(LKSM, KSM) => (max_amount_a, max_amount_b),
+			(KSM, LKSM) => (max_amount_b, max_amount_a),
+			_ => return Err(DispatchError::Other("invalid trading pair")),
+		};
+
+		Currencies::withdraw(LKSM, who, liquid_amount)?;
+		Currencies::withdraw(KSM, who, staking_amount)?;
+		LIQUIDITY_POOL.with(|v| {
+			let (liquid_pool, staking_pool) = *v.borrow();
+			*v.borrow_mut() = (liquid_pool + liquid_amount, staking_pool + staking_amount);
+		});
+		Ok(())
+	}
+
+	fn remove_liquidity(
+		_who: &AccountId,
+		_currency_id_a: CurrencyId,
+		_currency_id_b: CurrencyId,
+		_remove_share: Balance,
+		_min_withdrawn_a: Balance,
+		_min_withdrawn_b: Balance,
+		_by_unstake: bool,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
 parameter_types! {
 	pub const StakingCurrencyId: CurrencyId = KSM;
 	pub const LiquidCurrencyId: CurrencyId = LKSM;
@@ -268,6 +415,13 @@ parameter_types! {
 	pub static MockRelayBlockNumberProvider: u64 = 0;
 	pub const RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub const MaxScheduledUnbonds: u32 = 14;
+	pub const MaxRedeemRequests: u32 = 5;
+	pub const SovereignSubAccount: AccountId = AccountId32::new([100u8; 32]);
+	pub static MockRelayChainStorageRoot: Option<H256> = None;
+	pub const MaxStakingLedgerDeviation: Permill = Permill::from_percent(1);
+	pub const EraLength: BlockNumber = 100;
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const InsuranceAccount: AccountId = INSURANCE;
 	pub const SubAccountIndex: u16 = 0;
 	pub ParachainId: ParaId = ParaId::from(PARACHAIN_ID);
 }
@@ -275,6 +429,42 @@ ord_parameter_types! {
 	pub const Root: AccountId = ROOT;
 }
 
+/// Build a relaychain state containing the staking ledger of the sub-account with the given
+/// total, returns the storage root and the proof of the ledger.
+pub fn relaychain_staking_ledger_proof(total: Balance) -> (H256, Vec<Vec<u8>>) {
+	let ledger = RelayChainStakingLedger {
+		stash: SovereignSubAccount::get(),
+		total,
+		active: total,
+		unlocking: vec![],
+		claimed_rewards: vec![],
+	};
+
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = H256::default();
+	{
+		let mut trie = TrieDBMut::<Layout<BlakeTwo256>>::new(&mut db, &mut root);
+		trie.insert(&HomaLite::relaychain_staking_ledger_key(), &ledger.encode())
+			.unwrap();
+		trie.insert(b"unrelated key", b"unrelated value").unwrap();
+	}
+	let proof = db.drain().into_iter().map(|(_, (node, _))| node).collect();
+	(root, proof)
+}
+
+/// Mock guarantees of validators, all backed by the Liquid currency of `GUARANTOR`.
+pub struct MockValidatorGuarantees;
+impl SlashValidatorGuarantees<AccountId> for MockValidatorGuarantees {
+	fn slash_guarantees(_era: EraIndex, slashes: Vec<SlashInfo<Balance, AccountId>>) -> Balance {
+		let staking_amount: Balance = slashes.iter().map(|slash| slash.relaychain_token_amount).sum();
+		let liquid_amount = HomaLite::convert_staking_to_liquid(staking_amount)
+			.unwrap()
+			.min(Currencies::free_balance(LKSM, &GUARANTOR));
+		Currencies::withdraw(LKSM, &GUARANTOR, liquid_amount).unwrap();
+		liquid_amount
+	}
+}
+
 impl BlockNumberProvider for MockRelayBlockNumberProvider {
 	type BlockNumber = BlockNumber;
 
@@ -306,6 +496,15 @@ impl Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type DEX = MockDEX;
+	type MaxRedeemRequests = MaxRedeemRequests;
+	type SovereignSubAccount = SovereignSubAccount;
+	type RelayChainStorageRoot = MockRelayChainStorageRoot;
+	type MaxStakingLedgerDeviation = MaxStakingLedgerDeviation;
+	type EraLength = EraLength;
+	type TreasuryAccount = TreasuryAccount;
+	type InsuranceAccount = InsuranceAccount;
+	type ValidatorGuarantees = MockValidatorGuarantees;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -378,6 +577,8 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		set_liquidity_pool(0, 0);
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
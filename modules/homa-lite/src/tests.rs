@@ -23,10 +23,11 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
-	dollar, Currencies, Event, ExtBuilder, HomaLite, MockRelayBlockNumberProvider, Origin, Runtime, System, ACALA,
-	ALICE, BOB, CHARLIE, INITIAL_BALANCE, INVALID_CALLER, KSM, LKSM, ROOT,
+	dollar, relaychain_staking_ledger_proof, set_liquidity_pool, Currencies, Event, ExtBuilder, HomaLite,
+	MockRelayBlockNumberProvider, MockRelayChainStorageRoot, Origin, Runtime, System, ACALA, ALICE, BOB, CHARLIE,
+	GUARANTOR, INITIAL_BALANCE, INSURANCE, INVALID_CALLER, KSM, LKSM, ROOT, TREASURY,
 };
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{traits::BadOrigin, AccountId32};
 
 #[test]
 fn mock_initialize_token_works() {
@@ -713,7 +714,7 @@ fn mint_can_handle_dust_redeem_requests() {
 
 		let events = System::events();
 		assert_eq!(
-			events[events.len() - 4].event,
+			events[events.len() - 5].event,
 			Event::Currencies(module_currencies::Event::Transferred(
 				KSM,
 				BOB,
@@ -722,14 +723,18 @@ fn mint_can_handle_dust_redeem_requests() {
 			))
 		);
 		assert_eq!(
-			events[events.len() - 3].event,
+			events[events.len() - 4].event,
 			Event::HomaLite(crate::Event::Redeemed(ALICE, 100_100_100_100_098, 999_999_999_999_990))
 		);
 		// Dust returned to redeemer
 		assert_eq!(
-			events[events.len() - 2].event,
+			events[events.len() - 3].event,
 			Event::Tokens(orml_tokens::Event::Unreserved(LKSM, ALICE, 100_000_010))
 		);
+		assert_eq!(
+			events[events.len() - 2].event,
+			Event::HomaLite(crate::Event::RedeemRequestFilled(ALICE, 999_999_999_999_990, 0))
+		);
 		// total amount minted, with rounding error
 		assert_eq!(
 			events[events.len() - 1].event,
@@ -832,8 +837,16 @@ fn mint_can_match_requested_redeem() {
 		// Minting request can match up to 2 requests at a time. The rest is exchanged via XCM
 		assert_ok!(HomaLite::mint(Origin::signed(CHARLIE), dollar(100)));
 
+		// ROOT and ALICE's requests are matched in the order they are requested.
+		assert_eq!(HomaLite::redeem_requests(ROOT), None);
+		assert_eq!(HomaLite::redeem_requests(ALICE), None);
+		assert_eq!(
+			HomaLite::redeem_requests(BOB),
+			Some((dollar(1998) / 10, Permill::zero()))
+		);
+
 		// XCM will cost some fee
-		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), 993_897_000_000_000);
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), 992_898_000_000_000);
 	});
 }
 
@@ -1157,11 +1170,12 @@ fn on_idle_matches_redeem_based_on_weights() {
 			xcm_weight + redeem
 		);
 		assert_eq!(ScheduledUnbond::<Runtime>::get(), vec![]);
+		// Requests with the same fee are matched in the order they are requested.
+		assert_eq!(RedeemRequests::<Runtime>::get(ROOT), None);
 		assert_eq!(
-			RedeemRequests::<Runtime>::get(ROOT),
+			RedeemRequests::<Runtime>::get(ALICE),
 			Some((dollar(999), Permill::zero()))
 		);
-		assert_eq!(RedeemRequests::<Runtime>::get(ALICE), None);
 
 		// on_idle will match the remaining redeem request, even with no scheduled unbond.
 		assert_ok!(HomaLite::schedule_unbond(Origin::root(), dollar(1_000_000), 10));
@@ -1224,11 +1238,11 @@ fn adjust_available_staking_balance_matches_redeem_based_on_input() {
 
 		// match only one request
 		assert_ok!(HomaLite::adjust_available_staking_balance(Origin::root(), 1i128, 1));
+		assert_eq!(RedeemRequests::<Runtime>::get(ROOT), None);
 		assert_eq!(
-			RedeemRequests::<Runtime>::get(ROOT),
+			RedeemRequests::<Runtime>::get(ALICE),
 			Some((dollar(999), Permill::zero()))
 		);
-		assert_eq!(RedeemRequests::<Runtime>::get(ALICE), None);
 		assert_eq!(
 			RedeemRequests::<Runtime>::get(BOB),
 			Some((dollar(999), Permill::zero()))
@@ -1300,17 +1314,26 @@ fn available_staking_balances_can_handle_rounding_error_dust() {
 		assert_eq!(HomaLite::available_staking_balance(), 1);
 		let events = System::events();
 		assert_eq!(
-			events[events.len() - 3].event,
+			events[events.len() - 4].event,
 			Event::HomaLite(crate::Event::ScheduledUnbondWithdrew(999_999_999_999))
 		);
 		assert_eq!(
-			events[events.len() - 2].event,
+			events[events.len() - 3].event,
 			Event::Tokens(orml_tokens::Event::Unreserved(LKSM, ALICE, 9987632930985))
 		);
 		assert_eq!(
-			events[events.len() - 1].event,
+			events[events.len() - 2].event,
 			Event::HomaLite(crate::Event::Redeemed(ALICE, 999999999998, 9987632930985))
 		);
+		// ALICE's request is partially filled
+		assert_eq!(
+			events[events.len() - 1].event,
+			Event::HomaLite(crate::Event::RedeemRequestFilled(
+				ALICE,
+				9987632930985,
+				4985012367069015
+			))
+		);
 	});
 }
 
@@ -1374,14 +1397,23 @@ fn mint_can_handle_rounding_error_dust() {
 
 		let events = System::events();
 		assert_eq!(
-			events[events.len() - 3].event,
+			events[events.len() - 4].event,
 			Event::Currencies(module_currencies::Event::Transferred(KSM, ROOT, ALICE, 999999999998))
 		);
 		// actual staking transfered is off due to rounding error
 		assert_eq!(
-			events[events.len() - 2].event,
+			events[events.len() - 3].event,
 			Event::HomaLite(crate::Event::Redeemed(ALICE, 999999999998, 9_987_632_930_985))
 		);
+		// ALICE's request is partially filled
+		assert_eq!(
+			events[events.len() - 2].event,
+			Event::HomaLite(crate::Event::RedeemRequestFilled(
+				ALICE,
+				9_987_632_930_985,
+				4_985_012_367_069_015
+			))
+		);
 		// total amount minted includes dust caused by rounding error
 		assert_eq!(
 			events[events.len() - 1].event,
@@ -1389,3 +1421,627 @@ fn mint_can_handle_rounding_error_dust() {
 		);
 	});
 }
+
+#[test]
+fn redeem_by_dex_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_liquidity_pool(dollar(10_000_000), dollar(1_000_000));
+
+		// Redeem amount has to be above a threshold.
+		assert_noop!(
+			HomaLite::redeem_by_dex(
+				Origin::signed(ROOT),
+				dollar(1),
+				Permill::from_percent(1),
+				Permill::zero()
+			),
+			Error::<Runtime>::AmountBelowMinimumThreshold
+		);
+		assert_noop!(
+			HomaLite::redeem_by_dex(
+				Origin::signed(ALICE),
+				dollar(1_000),
+				Permill::from_percent(1),
+				Permill::zero()
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		// The DEX rate is within the slippage, the full amount is swapped.
+		assert_ok!(HomaLite::redeem_by_dex(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+		System::assert_last_event(Event::HomaLite(crate::Event::RedeemedByDex(
+			ROOT,
+			99_990_000_999_900,
+			dollar(1_000),
+		)));
+		assert_eq!(Currencies::free_balance(KSM, &ROOT), 99_990_000_999_900);
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(999_000));
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), 0);
+		assert_eq!(RedeemRequests::<Runtime>::get(&ROOT), None);
+	});
+}
+
+#[test]
+fn redeem_by_dex_queues_remaining_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_liquidity_pool(dollar(10_000_000), dollar(1_000_000));
+
+		// Only the amount within the slippage is swapped, the remainder is queued.
+		assert_ok!(HomaLite::redeem_by_dex(
+			Origin::signed(ROOT),
+			dollar(200_000),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+		System::assert_has_event(Event::HomaLite(crate::Event::RedeemedByDex(
+			ROOT,
+			9_999_703_918_368_481,
+			101_007_080_078_125_000,
+		)));
+		System::assert_last_event(Event::HomaLite(crate::Event::RedeemRequested(
+			ROOT,
+			98_893_927_001_953_125,
+			Permill::zero(),
+		)));
+		assert_eq!(Currencies::free_balance(KSM, &ROOT), 9_999_703_918_368_481);
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(800_000));
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), 98_893_927_001_953_125);
+		assert_eq!(
+			RedeemRequests::<Runtime>::get(&ROOT),
+			Some((98_893_927_001_953_125, Permill::zero()))
+		);
+
+		// Without DEX liquidity, the full amount is added to the existing redeem request.
+		set_liquidity_pool(0, 0);
+		assert_ok!(HomaLite::redeem_by_dex(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::from_percent(1),
+			Permill::from_percent(5)
+		));
+		System::assert_last_event(Event::HomaLite(crate::Event::RedeemRequested(
+			ROOT,
+			99_892_927_001_953_125,
+			Permill::from_percent(5),
+		)));
+		assert_eq!(Currencies::free_balance(LKSM, &ROOT), dollar(799_000));
+		assert_eq!(Currencies::reserved_balance(LKSM, &ROOT), 99_892_927_001_953_125);
+		assert_eq!(
+			RedeemRequests::<Runtime>::get(&ROOT),
+			Some((99_892_927_001_953_125, Permill::from_percent(5)))
+		);
+	});
+}
+
+#[test]
+fn redeem_requests_are_queued_by_fee_and_time() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(LKSM, &ALICE, dollar(1_000)));
+		assert_ok!(Currencies::deposit(LKSM, &BOB, dollar(1_000)));
+		assert_ok!(Currencies::deposit(LKSM, &CHARLIE, dollar(1_000)));
+
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::zero()
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ALICE),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(BOB),
+			dollar(1_000),
+			Permill::zero()
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(CHARLIE),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+		assert_eq!(
+			HomaLite::redeem_request_queue().into_inner(),
+			vec![
+				(ALICE, Permill::from_percent(10)),
+				(CHARLIE, Permill::from_percent(10)),
+				(ROOT, Permill::zero()),
+				(BOB, Permill::zero()),
+			]
+		);
+
+		// Replaced request is moved behind the requests with the same fee.
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ROOT),
+			dollar(500),
+			Permill::zero()
+		));
+		// Cancelled request is removed from the queue.
+		assert_ok!(HomaLite::request_redeem(Origin::signed(ALICE), 0, Permill::zero()));
+		assert_eq!(
+			HomaLite::redeem_request_queue().into_inner(),
+			vec![
+				(CHARLIE, Permill::from_percent(10)),
+				(BOB, Permill::zero()),
+				(ROOT, Permill::zero()),
+			]
+		);
+
+		assert_eq!(HomaLite::redeem_queue_depth(), (3, dollar(2_498)));
+		assert_eq!(HomaLite::redeem_request_position(&ROOT), Some((2, dollar(1_998))));
+		assert_eq!(HomaLite::redeem_request_position(&ALICE), None);
+	});
+}
+
+#[test]
+fn redeem_request_queue_is_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let redeemers = (10u8..16).map(|i| AccountId32::new([i; 32])).collect::<Vec<_>>();
+		for redeemer in redeemers.iter() {
+			assert_ok!(Currencies::deposit(LKSM, redeemer, dollar(1_000)));
+		}
+
+		// MaxRedeemRequests = 5
+		for redeemer in redeemers[..5].iter() {
+			assert_ok!(HomaLite::request_redeem(
+				Origin::signed(redeemer.clone()),
+				dollar(1_000),
+				Permill::zero()
+			));
+		}
+		assert_noop!(
+			HomaLite::request_redeem(Origin::signed(redeemers[5].clone()), dollar(1_000), Permill::zero()),
+			Error::<Runtime>::TooManyRedeemRequests
+		);
+
+		// Existing requests can still be replaced.
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(redeemers[0].clone()),
+			dollar(1_000),
+			Permill::from_percent(1)
+		));
+		assert_eq!(
+			HomaLite::redeem_request_queue()[0],
+			(redeemers[0].clone(), Permill::from_percent(1))
+		);
+
+		// A request with higher fee evicts the last request, which has the lowest fee.
+		let (evicted_amount, _) = HomaLite::redeem_requests(&redeemers[4]).unwrap();
+		assert_eq!(Currencies::reserved_balance(LKSM, &redeemers[4]), evicted_amount);
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(redeemers[5].clone()),
+			dollar(1_000),
+			Permill::from_percent(2)
+		));
+		System::assert_has_event(Event::HomaLite(crate::Event::RedeemRequestCancelled(
+			redeemers[4].clone(),
+			evicted_amount,
+		)));
+		assert_eq!(HomaLite::redeem_requests(&redeemers[4]), None);
+		assert_eq!(Currencies::reserved_balance(LKSM, &redeemers[4]), 0);
+		assert_eq!(HomaLite::redeem_request_queue().len(), 5);
+		assert_eq!(
+			HomaLite::redeem_request_queue()[0],
+			(redeemers[5].clone(), Permill::from_percent(2))
+		);
+		assert!(!HomaLite::redeem_request_queue()
+			.iter()
+			.any(|(redeemer, _)| *redeemer == redeemers[4]));
+	});
+}
+
+#[test]
+fn mint_matches_redeem_requests_by_priority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_minting_cap(Origin::root(), dollar(INITIAL_BALANCE)));
+		assert_ok!(Currencies::deposit(LKSM, &ALICE, dollar(1_000)));
+		assert_ok!(Currencies::deposit(KSM, &CHARLIE, dollar(50)));
+
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ROOT),
+			dollar(1_000),
+			Permill::zero()
+		));
+		assert_ok!(HomaLite::request_redeem(
+			Origin::signed(ALICE),
+			dollar(1_000),
+			Permill::from_percent(10)
+		));
+		assert_eq!(HomaLite::estimate_redeem_wait(dollar(1_000)), None);
+
+		// ALICE's request has higher fee, and is partially filled first.
+		assert_ok!(HomaLite::mint(Origin::signed(CHARLIE), dollar(50)));
+		System::assert_has_event(Event::HomaLite(crate::Event::RedeemRequestFilled(
+			ALICE,
+			dollar(500),
+			dollar(499),
+		)));
+		assert_eq!(
+			HomaLite::redeem_requests(ALICE),
+			Some((dollar(499), Permill::from_percent(10)))
+		);
+		assert_eq!(HomaLite::redeem_requests(ROOT), Some((dollar(999), Permill::zero())));
+		assert_eq!(Currencies::free_balance(KSM, &ALICE), dollar(45));
+		assert_eq!(Currencies::free_balance(LKSM, &CHARLIE), dollar(500));
+
+		// Partially filled request keeps its position.
+		assert_eq!(HomaLite::redeem_request_position(&ALICE), Some((0, 0)));
+		assert_eq!(HomaLite::redeem_request_position(&ROOT), Some((1, dollar(499))));
+		assert_eq!(HomaLite::redeem_queue_depth(), (2, dollar(1_498)));
+
+		// Wait time is estimated by the average fill rate: 500 LKSM in 10 blocks.
+		assert_eq!(HomaLite::redeem_requests_filled(), Some((dollar(500), 1)));
+		System::set_block_number(11);
+		assert_eq!(HomaLite::estimate_redeem_wait(dollar(1_498)), Some(29));
+	});
+}
+
+#[test]
+fn update_total_staking_currency_from_proof_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (root, proof) = relaychain_staking_ledger_proof(dollar(1_000));
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::signed(ALICE), proof.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::root(), proof.clone()),
+			Error::<Runtime>::RelayChainStorageRootNotFound
+		);
+
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_004)));
+		assert_ok!(HomaLite::adjust_available_staking_balance(
+			Origin::root(),
+			dollar(5) as i128,
+			0
+		));
+
+		// The ledger total and the available staking balance are both counted.
+		assert_ok!(HomaLite::update_total_staking_currency_from_proof(
+			Origin::root(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_005));
+		System::assert_last_event(Event::HomaLite(crate::Event::TotalStakingCurrencySet(dollar(1_005))));
+	});
+}
+
+#[test]
+fn update_total_staking_currency_from_proof_reports_divergence() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+
+		// Within 1% of the current total, the proven total is applied.
+		let (root, proof) = relaychain_staking_ledger_proof(dollar(990));
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_ok!(HomaLite::update_total_staking_currency_from_proof(
+			Origin::root(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(990));
+
+		// Beyond the bound, the proven total is reported but not applied.
+		let (root, proof) = relaychain_staking_ledger_proof(dollar(1_010));
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_ok!(HomaLite::update_total_staking_currency_from_proof(
+			Origin::root(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(990));
+		System::assert_last_event(Event::HomaLite(crate::Event::TotalStakingCurrencyDiverged(
+			dollar(990),
+			dollar(1_010),
+		)));
+
+		// A zero total is never applied.
+		let (root, proof) = relaychain_staking_ledger_proof(0);
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::root(), proof),
+			Error::<Runtime>::InvalidTotalStakingCurrency
+		);
+	});
+}
+
+#[test]
+fn update_total_staking_currency_from_proof_rejects_invalid_proof() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(1_000)));
+		let (root, mut proof) = relaychain_staking_ledger_proof(dollar(1_000));
+		MockRelayChainStorageRoot::set(Some(root));
+
+		// Proof of another relaychain state
+		let (_, other_proof) = relaychain_staking_ledger_proof(dollar(2_000));
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::root(), other_proof),
+			Error::<Runtime>::InvalidStorageProof
+		);
+
+		// Incomplete proof
+		proof.pop();
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::root(), proof),
+			Error::<Runtime>::InvalidStorageProof
+		);
+		assert_noop!(
+			HomaLite::update_total_staking_currency_from_proof(Origin::root(), vec![]),
+			Error::<Runtime>::InvalidStorageProof
+		);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(1_000));
+	});
+}
+
+#[test]
+fn set_reward_rates_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			HomaLite::set_reward_rates(
+				Origin::signed(ALICE),
+				Permill::from_percent(1),
+				Permill::from_percent(10)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			HomaLite::set_reward_rates(Origin::root(), Permill::from_percent(2), Permill::from_percent(10)),
+			Error::<Runtime>::ExceededMaxRewardPerEra
+		);
+
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::from_percent(10)
+		));
+		System::assert_last_event(Event::HomaLite(crate::Event::RewardRatesSet(
+			Permill::from_percent(1),
+			Permill::from_percent(10),
+		)));
+		assert_eq!(HomaLite::estimated_reward_rate_per_era(), Permill::from_percent(1));
+		assert_eq!(HomaLite::commission_rate(), Permill::from_percent(10));
+	});
+}
+
+#[test]
+fn staking_reward_accrues_per_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+
+		// Eras are counted from the first block seen.
+		MockRelayBlockNumberProvider::set(100);
+		HomaLite::on_finalize(1);
+		assert_eq!(HomaLite::last_reward_accrual(), 100);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::from_percent(10)
+		));
+
+		// No reward before a whole era has passed.
+		MockRelayBlockNumberProvider::set(199);
+		HomaLite::on_finalize(2);
+		assert_eq!(HomaLite::last_reward_accrual(), 100);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+
+		// Rewards of 2 eras are compounded: 100_000 * (1.01 ^ 2 - 1) = 2_010
+		// 10% commission is minted to the treasury, worth 201 KSM at the new exchange rate.
+		MockRelayBlockNumberProvider::set(350);
+		HomaLite::on_finalize(3);
+		assert_eq!(HomaLite::last_reward_accrual(), 300);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(102_010));
+		assert_eq!(Currencies::free_balance(LKSM, &TREASURY), 1_974_285_181_074_364);
+		System::assert_last_event(Event::HomaLite(crate::Event::StakingRewardAccrued(
+			dollar(2_010),
+			1_974_285_181_074_364,
+			Ratio::saturating_from_rational(101_809, 1_000_000),
+		)));
+		assert_eq!(
+			HomaLite::get_exchange_rate(),
+			Ratio::saturating_from_rational(101_809, 1_000_000)
+		);
+
+		// The rest of the era is carried over.
+		MockRelayBlockNumberProvider::set(400);
+		HomaLite::on_finalize(4);
+		assert_eq!(HomaLite::last_reward_accrual(), 400);
+	});
+}
+
+#[test]
+fn staking_reward_is_not_accrued_without_relaychain_block_number() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+		MockRelayBlockNumberProvider::set(100);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+
+		// The relaychain block number is not known yet on initialize.
+		MockRelayBlockNumberProvider::set(0);
+		assert_eq!(
+			HomaLite::on_initialize(1),
+			<Runtime as crate::Config>::WeightInfo::on_initialize()
+		);
+		assert_eq!(HomaLite::last_reward_accrual(), 100);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+		HomaLite::on_finalize(1);
+		assert_eq!(HomaLite::last_reward_accrual(), 100);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+
+		// The reward is accrued on finalize once the relaychain block number is set.
+		MockRelayBlockNumberProvider::set(0);
+		HomaLite::on_initialize(2);
+		MockRelayBlockNumberProvider::set(200);
+		HomaLite::on_finalize(2);
+		assert_eq!(HomaLite::last_reward_accrual(), 200);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(101_000));
+	});
+}
+
+#[test]
+fn staking_reward_is_accrued_before_rates_change() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+		MockRelayBlockNumberProvider::set(100);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+
+		MockRelayBlockNumberProvider::set(200);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::zero(),
+			Permill::zero()
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(101_000));
+		assert_eq!(Currencies::free_balance(LKSM, &TREASURY), 0);
+
+		// No more reward is accrued at zero rate.
+		MockRelayBlockNumberProvider::set(500);
+		HomaLite::on_finalize(2);
+		assert_eq!(HomaLite::last_reward_accrual(), 500);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(101_000));
+	});
+}
+
+#[test]
+fn staking_reward_accrues_on_bonded_staking_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+		assert_ok!(HomaLite::adjust_available_staking_balance(
+			Origin::root(),
+			dollar(10_000) as i128,
+			0
+		));
+		MockRelayBlockNumberProvider::set(100);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+
+		// The available staking balance is unbonded and earns no reward: 90_000 * 1% = 900
+		MockRelayBlockNumberProvider::set(200);
+		HomaLite::on_finalize(1);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_900));
+		assert_eq!(HomaLite::unproven_staking_reward(), dollar(900));
+	});
+}
+
+#[test]
+fn update_total_staking_currency_from_proof_allows_unproven_staking_reward() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_000)));
+		MockRelayBlockNumberProvider::set(100);
+		assert_ok!(HomaLite::set_reward_rates(
+			Origin::root(),
+			Permill::from_percent(1),
+			Permill::zero()
+		));
+		MockRelayBlockNumberProvider::set(300);
+		HomaLite::on_finalize(1);
+		assert_eq!(HomaLite::total_staking_currency(), dollar(102_010));
+		assert_eq!(HomaLite::unproven_staking_reward(), dollar(2_010));
+
+		// No reward was actually earned, the estimated reward is allowed on top of the 1% bound.
+		let (root, proof) = relaychain_staking_ledger_proof(dollar(100_000));
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_ok!(HomaLite::update_total_staking_currency_from_proof(
+			Origin::root(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+		assert_eq!(HomaLite::unproven_staking_reward(), 0);
+
+		// Once proven, the total is bounded by 1% again.
+		let (root, proof) = relaychain_staking_ledger_proof(dollar(102_010));
+		MockRelayChainStorageRoot::set(Some(root));
+		assert_ok!(HomaLite::update_total_staking_currency_from_proof(
+			Origin::root(),
+			proof
+		));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_000));
+		System::assert_last_event(Event::HomaLite(crate::Event::TotalStakingCurrencyDiverged(
+			dollar(100_000),
+			dollar(102_010),
+		)));
+	});
+}
+
+#[test]
+fn report_slash_draws_from_guarantees_and_insurance_first() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Currencies::deposit(LKSM, &GUARANTOR, dollar(1_000)));
+		assert_ok!(Currencies::deposit(LKSM, &INSURANCE, dollar(2_000)));
+		assert_ok!(HomaLite::set_total_staking_currency(Origin::root(), dollar(100_300)));
+		let slash = |validator, amount| SlashInfo {
+			validator,
+			relaychain_token_amount: amount,
+		};
+
+		assert_noop!(
+			HomaLite::report_slash(Origin::signed(ALICE), 1, vec![slash(ALICE, dollar(100))]),
+			BadOrigin
+		);
+		assert_noop!(
+			HomaLite::report_slash(
+				Origin::root(),
+				1,
+				vec![slash(ALICE, dollar(100_000)), slash(BOB, dollar(300))]
+			),
+			Error::<Runtime>::InvalidTotalStakingCurrency
+		);
+
+		// The loss is fully covered by the guarantees, the exchange rate is unchanged.
+		assert_ok!(HomaLite::report_slash(
+			Origin::root(),
+			1,
+			vec![slash(ALICE, dollar(60))]
+		));
+		System::assert_last_event(Event::HomaLite(crate::Event::Slashed(
+			dollar(60),
+			dollar(600),
+			0,
+			Ratio::saturating_from_rational(1, 10),
+		)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(100_240));
+		assert_eq!(Currencies::free_balance(LKSM, &GUARANTOR), dollar(400));
+		assert_eq!(Currencies::free_balance(LKSM, &INSURANCE), dollar(2_000));
+
+		// The same slash can't be reported twice.
+		assert_noop!(
+			HomaLite::report_slash(Origin::root(), 1, vec![slash(ALICE, dollar(60))]),
+			Error::<Runtime>::SlashAlreadyReported
+		);
+		assert_noop!(
+			HomaLite::report_slash(Origin::root(), 2, vec![slash(BOB, dollar(60)), slash(BOB, dollar(60))]),
+			Error::<Runtime>::SlashAlreadyReported
+		);
+
+		// The guarantees and the insurance buffer are used up, the rest of the loss is socialised.
+		assert_ok!(HomaLite::report_slash(
+			Origin::root(),
+			2,
+			vec![slash(ALICE, dollar(200)), slash(BOB, dollar(240))]
+		));
+		System::assert_last_event(Event::HomaLite(crate::Event::Slashed(
+			dollar(440),
+			dollar(400),
+			dollar(2_000),
+			Ratio::saturating_from_rational(998, 10_000),
+		)));
+		assert_eq!(HomaLite::total_staking_currency(), dollar(99_800));
+		assert_eq!(Currencies::free_balance(LKSM, &GUARANTOR), 0);
+		assert_eq!(Currencies::free_balance(LKSM, &INSURANCE), 0);
+		assert_eq!(Currencies::total_issuance(LKSM), dollar(1_000_000));
+	});
+}
@@ -47,36 +47,43 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for module_homa_lite.
 pub trait WeightInfo {
-	fn mint() -> Weight;
-	fn mint_for_requests() -> Weight;
+	fn mint(n: u32) -> Weight;
+	fn mint_for_requests(n: u32) -> Weight;
 	fn set_total_staking_currency() -> Weight;
 	fn adjust_total_staking_currency() -> Weight;
 	fn adjust_available_staking_balance_with_no_matches() -> Weight;
 	fn set_minting_cap() -> Weight;
 	fn set_xcm_dest_weight() -> Weight;
-	fn request_redeem() -> Weight;
+	fn request_redeem(n: u32) -> Weight;
 	fn schedule_unbond() -> Weight;
 	fn replace_schedule_unbond() -> Weight;
 	fn redeem_with_available_staking_balance() -> Weight;
 	fn xcm_unbond() -> Weight;
+	fn redeem_by_dex(n: u32) -> Weight;
+	fn update_total_staking_currency_from_proof(n: u32) -> Weight;
+	fn on_initialize() -> Weight;
+	fn set_reward_rates() -> Weight;
+	fn report_slash(n: u32) -> Weight;
 }
 
 /// Weights for module_homa_lite using the Acala node and recommended hardware.
 pub struct AcalaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
-	fn mint() -> Weight {
+	fn mint(n: u32) -> Weight {
 		(138_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
-	fn mint_for_requests() -> Weight {
+	fn mint_for_requests(n: u32) -> Weight {
 		(348_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(30 as Weight))
 			.saturating_add(T::DbWeight::get().writes(21 as Weight))
 	}
 	fn set_total_staking_currency() -> Weight {
 		(11_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn adjust_total_staking_currency() -> Weight {
 		(12_000_000 as Weight)
@@ -96,8 +103,9 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(11_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn request_redeem() -> Weight {
+	fn request_redeem(n: u32) -> Weight {
 		(82_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
@@ -120,23 +128,56 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn redeem_by_dex(n: u32) -> Weight {
+		(168_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn update_total_staking_currency_from_proof(n: u32) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_reward_rates() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn report_slash(n: u32) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(41_000_000 as Weight)
+			.saturating_add((36_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn mint() -> Weight {
+	fn mint(n: u32) -> Weight {
 		(138_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
-	fn mint_for_requests() -> Weight {
+	fn mint_for_requests(n: u32) -> Weight {
 		(348_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(RocksDbWeight::get().reads(30 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(21 as Weight))
 	}
 	fn set_total_staking_currency() -> Weight {
 		(11_000_000 as Weight)
-			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
 	fn adjust_total_staking_currency() -> Weight {
 		(12_000_000 as Weight)
@@ -156,8 +197,9 @@ impl WeightInfo for () {
 		(11_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
-	fn request_redeem() -> Weight {
+	fn request_redeem(n: u32) -> Weight {
 		(82_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 	}
@@ -180,4 +222,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	fn redeem_by_dex(n: u32) -> Weight {
+		(168_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn update_total_staking_currency_from_proof(n: u32) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn set_reward_rates() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn report_slash(n: u32) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(41_000_000 as Weight)
+			.saturating_add((36_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
 }
@@ -32,7 +32,7 @@ use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, traits::Contains, transactional};
 use frame_system::pallet_prelude::*;
 use orml_traits::{BasicCurrency, BasicLockableCurrency, Happened, LockIdentifier};
-use primitives::Balance;
+use primitives::{Balance, EraIndex};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -147,9 +147,23 @@ impl<BlockNumber: PartialOrd> Guarantee<BlockNumber> {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct SlashInfo<Balance, RelaychainAccountId> {
 	/// Address of a validator on the relay chain
-	validator: RelaychainAccountId,
+	pub validator: RelaychainAccountId,
 	/// The amount of tokens a validator has in backing on the relay chain
-	relaychain_token_amount: Balance,
+	pub relaychain_token_amount: Balance,
+}
+
+/// Slash the guarantees of relay chain validators.
+pub trait SlashValidatorGuarantees<RelaychainAccountId> {
+	/// Burn the Liquid Token of guarantors to compensate the slashes in `era`, returns the total
+	/// amount burned. A validator already slashed in `era` is not burned again, the amount
+	/// burned before is counted instead.
+	fn slash_guarantees(era: EraIndex, slashes: Vec<SlashInfo<Balance, RelaychainAccountId>>) -> Balance;
+}
+
+impl<RelaychainAccountId> SlashValidatorGuarantees<RelaychainAccountId> for () {
+	fn slash_guarantees(_era: EraIndex, _slashes: Vec<SlashInfo<Balance, RelaychainAccountId>>) -> Balance {
+		Zero::zero()
+	}
 }
 
 /// Validator insurance and frozen status
@@ -247,6 +261,15 @@ pub mod module {
 	pub type ValidatorBackings<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::RelaychainAccountId, ValidatorBacking, OptionQuery>;
 
+	/// The Liquid Token burned from the guarantees of validators slashed in an era, so that the
+	/// same slash is compensated only once.
+	///
+	/// SlashedGuarantees: double_map EraIndex, RelaychainAccountId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn slashed_guarantees)]
+	pub type SlashedGuarantees<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EraIndex, Blake2_128Concat, T::RelaychainAccountId, Balance, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -423,53 +446,76 @@ pub mod module {
 		/// Slash validators on the relay chain.
 		/// Ensures the the caller can perform a slash.
 		///
+		/// - `era`: The relay chain era the validators are slashed in. A validator is slashed at
+		///   most once per era.
 		/// - `slashes`: The SlashInfos of the validators to be slashed
 		#[pallet::weight(T::WeightInfo::slash())]
 		#[transactional]
-		pub fn slash(origin: OriginFor<T>, slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>) -> DispatchResult {
+		pub fn slash(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>,
+		) -> DispatchResult {
 			T::SlashOrigin::ensure_origin(origin)?;
-			let liquid_staking_exchange_rate = T::LiquidStakingExchangeRateProvider::get_exchange_rate();
-			let staking_liquid_exchange_rate = liquid_staking_exchange_rate.reciprocal().unwrap_or_default();
-			let mut actual_total_slashing: Balance = Zero::zero();
-
-			for SlashInfo {
-				validator,
-				relaychain_token_amount,
-			} in slashes
-			{
-				let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
-				let insurance_loss = staking_liquid_exchange_rate
-					.saturating_mul_int(relaychain_token_amount)
-					.min(total_insurance);
-
-				for (guarantor, _) in Guarantees::<T>::iter_prefix(&validator) {
-					// NOTE: ignoring result because the closure will not throw err.
-					let res = Self::update_guarantee(&guarantor, &validator, |guarantee| -> DispatchResult {
-						let should_slashing = Ratio::checked_from_rational(guarantee.total, total_insurance)
-							.unwrap_or_else(Ratio::max_value)
-							.saturating_mul_int(insurance_loss);
-						let gap = T::LiquidTokenCurrency::slash(&guarantor, should_slashing);
-						let actual_slashing = should_slashing.saturating_sub(gap);
-						*guarantee = guarantee.slash(actual_slashing);
-						Self::deposit_event(Event::SlashGuarantee(
-							guarantor.clone(),
-							validator.clone(),
-							actual_slashing,
-						));
-						actual_total_slashing = actual_total_slashing.saturating_add(actual_slashing);
-						Ok(())
-					});
-					debug_assert!(res.is_ok());
-				}
-			}
-
-			T::OnSlash::happened(&actual_total_slashing);
+			Self::do_slash(era, slashes);
 			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Burn the insurance of guarantors of the slashed validators, returns the total amount of
+	/// Liquid Token burned for the slashes, including the amount burned before for validators
+	/// already slashed in `era`.
+	fn do_slash(era: EraIndex, slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>) -> Balance {
+		let liquid_staking_exchange_rate = T::LiquidStakingExchangeRateProvider::get_exchange_rate();
+		let staking_liquid_exchange_rate = liquid_staking_exchange_rate.reciprocal().unwrap_or_default();
+		let mut actual_total_slashing: Balance = Zero::zero();
+		let mut slashed_before: Balance = Zero::zero();
+
+		for SlashInfo {
+			validator,
+			relaychain_token_amount,
+		} in slashes
+		{
+			if let Some(slashed) = Self::slashed_guarantees(era, &validator) {
+				slashed_before = slashed_before.saturating_add(slashed);
+				continue;
+			}
+
+			let mut validator_slashing: Balance = Zero::zero();
+			let ValidatorBacking { total_insurance, .. } = Self::validator_backings(&validator).unwrap_or_default();
+			let insurance_loss = staking_liquid_exchange_rate
+				.saturating_mul_int(relaychain_token_amount)
+				.min(total_insurance);
+
+			for (guarantor, _) in Guarantees::<T>::iter_prefix(&validator) {
+				// NOTE: ignoring result because the closure will not throw err.
+				let res = Self::update_guarantee(&guarantor, &validator, |guarantee| -> DispatchResult {
+					let should_slashing = Ratio::checked_from_rational(guarantee.total, total_insurance)
+						.unwrap_or_else(Ratio::max_value)
+						.saturating_mul_int(insurance_loss);
+					let gap = T::LiquidTokenCurrency::slash(&guarantor, should_slashing);
+					let actual_slashing = should_slashing.saturating_sub(gap);
+					*guarantee = guarantee.slash(actual_slashing);
+					Self::deposit_event(Event::SlashGuarantee(
+						guarantor.clone(),
+						validator.clone(),
+						actual_slashing,
+					));
+					validator_slashing = validator_slashing.saturating_add(actual_slashing);
+					Ok(())
+				});
+				debug_assert!(res.is_ok());
+			}
+			SlashedGuarantees::<T>::insert(era, &validator, validator_slashing);
+			actual_total_slashing = actual_total_slashing.saturating_add(validator_slashing);
+		}
+
+		T::OnSlash::happened(&actual_total_slashing);
+		actual_total_slashing.saturating_add(slashed_before)
+	}
+
 	fn update_guarantee(
 		guarantor: &T::AccountId,
 		validator: &T::RelaychainAccountId,
@@ -541,3 +587,9 @@ impl<T: Config> Contains<T::RelaychainAccountId> for Pallet<T> {
 			>= T::ValidatorInsuranceThreshold::get()
 	}
 }
+
+impl<T: Config> SlashValidatorGuarantees<T::RelaychainAccountId> for Pallet<T> {
+	fn slash_guarantees(era: EraIndex, slashes: Vec<SlashInfo<Balance, T::RelaychainAccountId>>) -> Balance {
+		Self::do_slash(era, slashes)
+	}
+}
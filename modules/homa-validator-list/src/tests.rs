@@ -659,6 +659,7 @@ fn slash_work() {
 		assert_noop!(
 			HomaValidatorListModule::slash(
 				Origin::signed(ALICE),
+				1,
 				vec![
 					SlashInfo {
 						validator: VALIDATOR_1,
@@ -675,6 +676,7 @@ fn slash_work() {
 
 		assert_ok!(HomaValidatorListModule::slash(
 			Origin::signed(10),
+			1,
 			vec![
 				SlashInfo {
 					validator: VALIDATOR_1,
@@ -757,6 +759,25 @@ fn slash_work() {
 			HomaValidatorListModule::total_locked_by_guarantor(BOB).unwrap_or_default(),
 			281
 		);
+		assert_eq!(HomaValidatorListModule::slashed_guarantees(1, VALIDATOR_1), Some(178));
+		assert_eq!(HomaValidatorListModule::slashed_guarantees(1, VALIDATOR_2), Some(100));
+
+		// The same slash is not burned again in the same era.
+		assert_ok!(HomaValidatorListModule::slash(
+			Origin::signed(10),
+			1,
+			vec![SlashInfo {
+				validator: VALIDATOR_1,
+				relaychain_token_amount: 90
+			}]
+		));
+		assert_eq!(
+			HomaValidatorListModule::validator_backings(VALIDATOR_1)
+				.unwrap_or_default()
+				.total_insurance,
+			122
+		);
+		assert_eq!(OrmlTokens::accounts(BOB, LDOT).frozen, 281);
 	});
 }
 
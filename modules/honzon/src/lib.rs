@@ -257,6 +257,20 @@ pub mod module {
 			Self::deposit_event(Event::UnAuthorizationAll(from));
 			Ok(())
 		}
+
+		/// Enable or disable the cross-collateral position of caller, the
+		/// positions of all collateral types are risk checked as a whole under
+		/// cross-collateral position.
+		///
+		/// - `enabled`: whether to use cross-collateral position.
+		#[pallet::weight(<T as Config>::WeightInfo::set_cross_collateral(<T as cdp_engine::Config>::CollateralCurrencyIds::get().len() as u32))]
+		#[transactional]
+		pub fn set_cross_collateral(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!T::EmergencyShutdown::is_shutdown(), Error::<T>::AlreadyShutdown);
+			<cdp_engine::Pallet<T>>::set_cross_collateral(&who, enabled)?;
+			Ok(())
+		}
 	}
 }
 
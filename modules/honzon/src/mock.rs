@@ -28,7 +28,7 @@ use frame_support::{
 };
 use frame_system::{offchain::SendTransactionTypes, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
-use primitives::{Balance, Moment, ReserveIdentifier, TokenSymbol};
+use primitives::{evm::EvmAddress, Balance, Moment, ReserveIdentifier, TokenSymbol};
 use sp_core::H256;
 use sp_runtime::{
 	testing::{Header, TestXt},
@@ -36,7 +36,7 @@ use sp_runtime::{
 	FixedPointNumber,
 };
 use sp_std::cell::RefCell;
-use support::{AuctionManager, ExchangeRate, Price, PriceProvider, Rate, Ratio};
+use support::{AddressMapping, AuctionManager, ExchangeRate, Price, PriceProvider, Rate, Ratio};
 
 mod honzon {
 	pub use super::super::*;
@@ -157,6 +157,33 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 	}
 }
 
+pub struct MockAddressMapping;
+impl AddressMapping<AccountId> for MockAddressMapping {
+	fn get_account_id(evm: &EvmAddress) -> AccountId {
+		let mut data = [0u8; 16];
+		data.copy_from_slice(&evm.as_bytes()[4..20]);
+		AccountId::from_be_bytes(data)
+	}
+
+	fn get_evm_address(account_id: &AccountId) -> Option<EvmAddress> {
+		let mut data = [0u8; 20];
+		data[4..20].copy_from_slice(&account_id.to_be_bytes());
+		Some(EvmAddress::from(data))
+	}
+
+	fn get_or_create_evm_address(account_id: &AccountId) -> EvmAddress {
+		Self::get_evm_address(account_id).unwrap_or_default()
+	}
+
+	fn get_default_evm_address(account_id: &AccountId) -> EvmAddress {
+		Self::get_or_create_evm_address(account_id)
+	}
+
+	fn is_linked(account_id: &AccountId, evm: &EvmAddress) -> bool {
+		Self::get_evm_address(account_id) == Some(*evm)
+	}
+}
+
 pub struct MockAuctionManager;
 impl AuctionManager<AccountId> for MockAuctionManager {
 	type Balance = Balance;
@@ -172,6 +199,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_amount: Self::Balance, _fix: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -183,6 +218,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
 }
 
 thread_local! {
@@ -221,6 +264,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -242,6 +286,10 @@ parameter_types! {
 	pub const MinimumDebitValue: Balance = 2;
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub const UnsignedPriority: u64 = 1 << 20;
+	pub const StabilityFeeAdjustmentPeriod: BlockNumber = 10;
+	pub const IssuanceEpochPeriod: BlockNumber = 10;
+	pub LiquidationContractsAccount: AccountId = PalletId(*b"aca/cdpe").into_account();
+	pub const MaxLiquidationContracts: u32 = 10;
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
 	];
@@ -250,6 +298,9 @@ parameter_types! {
 impl cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = MockPriceSource;
+	type DEX = ();
+	type StabilityFeeAdjustmentPeriod = StabilityFeeAdjustmentPeriod;
+	type IssuanceEpochPeriod = IssuanceEpochPeriod;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -263,6 +314,11 @@ impl cdp_engine::Config for Runtime {
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type LiquidationEvmBridge = ();
+	type EvmAddressMapping = MockAddressMapping;
+	type LiquidationContractsAccount = LiquidationContractsAccount;
+	type GasToWeight = ();
+	type MaxLiquidationContracts = MaxLiquidationContracts;
 	type WeightInfo = ();
 }
 
@@ -98,6 +98,7 @@ fn transfer_loan_from_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_ok!(HonzonModule::authorize(Origin::signed(ALICE), BTC, BOB));
@@ -128,6 +129,7 @@ fn adjust_loan_should_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -165,6 +167,7 @@ fn close_loan_has_debit_by_dex_work() {
 			Change::NewValue(Some(Rate::saturating_from_rational(2, 10))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
 			Change::NewValue(10000),
+			Change::NoChange,
 		));
 		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 100, 50));
 		assert_eq!(LoansModule::positions(BTC, ALICE).collateral, 100);
@@ -180,3 +183,47 @@ fn close_loan_has_debit_by_dex_work() {
 		assert_eq!(LoansModule::positions(BTC, ALICE).debit, 0);
 	});
 }
+
+#[test]
+fn set_cross_collateral_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			BTC,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(3, 2))),
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(9, 5))),
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(CDPEngineModule::set_collateral_params(
+			Origin::signed(1),
+			DOT,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NewValue(10000),
+			Change::NoChange,
+		));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), BTC, 300, 50));
+
+		assert_ok!(HonzonModule::set_cross_collateral(Origin::signed(ALICE), true));
+		System::assert_last_event(Event::LoansModule(loans::Event::CrossCollateralUpdated(ALICE, true)));
+		assert_ok!(HonzonModule::adjust_loan(Origin::signed(ALICE), DOT, 0, 100));
+		assert_eq!(LoansModule::positions(DOT, ALICE).debit, 100);
+
+		assert_noop!(
+			HonzonModule::set_cross_collateral(Origin::signed(ALICE), false),
+			cdp_engine::Error::<Runtime>::BelowLiquidationRatio,
+		);
+
+		mock_shutdown();
+		assert_noop!(
+			HonzonModule::set_cross_collateral(Origin::signed(ALICE), false),
+			Error::<Runtime>::AlreadyShutdown,
+		);
+	});
+}
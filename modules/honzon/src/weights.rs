@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn adjust_loan() -> Weight;
 	fn transfer_loan_from() -> Weight;
 	fn close_loan_has_debit_by_dex(u: u32, ) -> Weight;
+	fn set_cross_collateral(c: u32, ) -> Weight;
 }
 
 /// Weights for module_honzon using the Acala node and recommended hardware.
@@ -90,6 +91,14 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(21 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
+	fn set_cross_collateral(c: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(32_000_000 as Weight)
+			.saturating_add((9_263_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -125,4 +134,12 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(21 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
+	fn set_cross_collateral(c: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(32_000_000 as Weight)
+			.saturating_add((9_263_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
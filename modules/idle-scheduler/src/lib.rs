@@ -77,6 +77,14 @@ pub mod module {
 	#[pallet::getter(fn next_task_id)]
 	pub type NextTaskId<T: Config> = StorageValue<_, Nonce, ValueQuery>;
 
+	/// The last task dispatched before the weight ran out, the next dispatch starts after it so
+	/// that the tasks at the front of the queue can not starve the others.
+	///
+	/// LastDispatchedTask: Option<Nonce>
+	#[pallet::storage]
+	#[pallet::getter(fn last_dispatched_task)]
+	pub type LastDispatchedTask<T: Config> = StorageValue<_, Nonce, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -114,7 +122,9 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Keep dispatching tasks in Storage, until insufficient weight remains.
+	/// Keep dispatching tasks in Storage, until insufficient weight remains. The dispatch
+	/// continues from the task after `LastDispatchedTask`, and starts from the beginning of the
+	/// queue again once the end is reached.
 	pub fn do_dispatch_tasks(total_weight: Weight) -> Weight {
 		let mut weight_remaining = total_weight;
 		if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
@@ -122,8 +132,15 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let mut completed_tasks: Vec<Nonce> = vec![];
+		let mut last_dispatched_task: Option<Nonce> = None;
+
+		let mut tasks_iterator = match Self::last_dispatched_task() {
+			Some(id) => Tasks::<T>::iter_from(Tasks::<T>::hashed_key_for(id)),
+			None => Tasks::<T>::iter(),
+		};
 
-		for (id, task) in Tasks::<T>::iter() {
+		#[allow(clippy::while_let_on_iterator)]
+		while let Some((id, task)) = tasks_iterator.next() {
 			let result = task.dispatch(weight_remaining);
 			weight_remaining = weight_remaining.saturating_sub(result.used_weight);
 			if result.finished {
@@ -132,9 +149,11 @@ impl<T: Config> Pallet<T> {
 
 			// If remaining weight falls below the minimmum, break from the loop.
 			if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+				last_dispatched_task = Some(id);
 				break;
 			}
 		}
+		LastDispatchedTask::<T>::set(last_dispatched_task);
 
 		// Deposit event and remove completed tasks.
 		for id in completed_tasks {
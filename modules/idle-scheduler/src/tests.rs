@@ -91,6 +91,35 @@ fn can_process_tasks_up_to_weight_limit() {
 	});
 }
 
+// can continue dispatching tasks from the last dispatched one
+#[test]
+fn can_continue_from_last_dispatched_task() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(IdleScheduler::schedule_task(
+				Origin::root(),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			));
+		}
+		let task_ids: Vec<Nonce> = Tasks::<Runtime>::iter_keys().collect();
+
+		// Given enough weights for only 1 task: MinimumWeightRemainInBlock::get() + BASE_WEIGHT
+		IdleScheduler::on_idle(0, 100_001_000_000);
+		assert_eq!(IdleScheduler::last_dispatched_task(), Some(task_ids[0]));
+		assert_eq!(Tasks::<Runtime>::get(task_ids[0]), None);
+
+		IdleScheduler::on_idle(0, 100_001_000_000);
+		assert_eq!(IdleScheduler::last_dispatched_task(), Some(task_ids[1]));
+		assert_eq!(Tasks::<Runtime>::get(task_ids[1]), None);
+		assert!(Tasks::<Runtime>::get(task_ids[2]).is_some());
+
+		// the end of queue is reached, start from the beginning next time
+		IdleScheduler::on_idle(0, 100_010_000_000);
+		assert_eq!(IdleScheduler::last_dispatched_task(), None);
+		assert_eq!(Tasks::<Runtime>::get(task_ids[2]), None);
+	});
+}
+
 // can increment next task ID
 #[test]
 fn can_increment_next_task_id() {
@@ -34,7 +34,7 @@ use primitives::{DexShare, TokenSymbol};
 use sp_core::{H160, H256};
 use sp_runtime::{testing::Header, traits::IdentityLookup, AccountId32};
 use sp_std::cell::RefCell;
-pub use support::{CDPTreasury, DEXManager, Price, Ratio};
+pub use support::{CDPTreasury, DEXManager, ExchangeRate, Price, Ratio};
 
 pub type AccountId = AccountId32;
 pub type BlockNumber = u64;
@@ -118,6 +118,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn get_surplus_pool_not_in_auction() -> Balance {
+		unimplemented!()
+	}
+
 	fn get_debit_pool() -> Balance {
 		unimplemented!()
 	}
@@ -150,6 +154,10 @@ impl CDPTreasury<AccountId> for MockCDPTreasury {
 		unimplemented!()
 	}
 
+	fn withdraw_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
 	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
 		unimplemented!()
 	}
@@ -183,6 +191,18 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_swap_path_with_exact_supply(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(_: CurrencyId, _: CurrencyId, _: Balance) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_twap(_: CurrencyId, _: CurrencyId, _: u32) -> Option<ExchangeRate> {
+		unimplemented!()
+	}
+
 	fn swap_with_exact_supply(
 		_: &AccountId,
 		_: &[CurrencyId],
@@ -201,6 +221,18 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		_: &AccountId,
+		_: CurrencyId,
+		_: CurrencyId,
+		_: Balance,
+		_: CurrencyId,
+		_: Balance,
+		_: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
 	fn add_liquidity(
 		_: &AccountId,
 		_: CurrencyId,
@@ -22,6 +22,10 @@
 //!
 //! Loans module manages CDP's collateral assets and the debits backed by these
 //! assets.
+//!
+//! By default the position of each collateral type is an independent CDP. An
+//! account can opt in to cross-collateral position, then all its positions are
+//! risk checked as a whole.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -106,6 +110,8 @@ pub mod module {
 		ConfiscateCollateralAndDebit(T::AccountId, CurrencyId, Balance, Balance),
 		/// Transfer loan. \[from, to, currency_id\]
 		TransferLoan(T::AccountId, T::AccountId, CurrencyId),
+		/// Cross-collateral position of account updated. \[owner, enabled\]
+		CrossCollateralUpdated(T::AccountId, bool),
 	}
 
 	/// The collateralized debit positions, map from
@@ -125,6 +131,13 @@ pub mod module {
 	#[pallet::getter(fn total_positions)]
 	pub type TotalPositions<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Position, ValueQuery>;
 
+	/// The accounts which use cross-collateral position, the positions of all
+	/// collateral types of these accounts are risk checked as a whole.
+	///
+	/// CrossCollateralAccounts: map AccountId => Option<()>
+	#[pallet::storage]
+	pub type CrossCollateralAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -140,6 +153,22 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account()
 	}
 
+	/// Check if `who` uses cross-collateral position.
+	pub fn is_cross_collateral(who: &T::AccountId) -> bool {
+		CrossCollateralAccounts::<T>::contains_key(who)
+	}
+
+	/// Enable or disable cross-collateral position of `who`, the caller should check
+	/// the positions of `who` under the new mode.
+	pub fn set_cross_collateral(who: &T::AccountId, enabled: bool) {
+		if enabled {
+			CrossCollateralAccounts::<T>::insert(who, ());
+		} else {
+			CrossCollateralAccounts::<T>::remove(who);
+		}
+		Self::deposit_event(Event::CrossCollateralUpdated(who.clone(), enabled));
+	}
+
 	/// confiscate collateral and debit to cdp treasury.
 	///
 	/// Ensured atomic.
@@ -204,7 +233,12 @@ impl<T: Config> Pallet<T> {
 
 		if debit_adjustment.is_positive() {
 			// check debit cap when increase debit
-			T::RiskManager::check_debit_cap(currency_id, Self::total_positions(currency_id).debit)?;
+			T::RiskManager::check_debit_cap(
+				who,
+				currency_id,
+				Self::total_positions(currency_id).debit,
+				debit_balance_adjustment,
+			)?;
 
 			// issue debit with collateral backed by cdp treasury
 			T::CDPTreasury::issue_debit(who, T::Convert::convert((currency_id, debit_balance_adjustment)), true)?;
@@ -215,13 +249,13 @@ impl<T: Config> Pallet<T> {
 		}
 
 		// ensure pass risk check
-		let Position { collateral, debit } = Self::positions(currency_id, who);
-		T::RiskManager::check_position_valid(
-			currency_id,
-			collateral,
-			debit,
-			collateral_adjustment.is_negative() || debit_adjustment.is_positive(),
-		)?;
+		let check_required_ratio = collateral_adjustment.is_negative() || debit_adjustment.is_positive();
+		if Self::is_cross_collateral(who) {
+			T::RiskManager::check_cross_collateral_position_valid(who, check_required_ratio)?;
+		} else {
+			let Position { collateral, debit } = Self::positions(currency_id, who);
+			T::RiskManager::check_position_valid(currency_id, collateral, debit, check_required_ratio)?;
+		}
 
 		Self::deposit_event(Event::PositionUpdated(
 			who.clone(),
@@ -233,6 +267,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// transfer whole loan of `from` to `to`
+	///
+	/// Ensured atomic.
+	#[transactional]
 	pub fn transfer_loan(from: &T::AccountId, to: &T::AccountId, currency_id: CurrencyId) -> DispatchResult {
 		// get `from` position data
 		let Position { collateral, debit } = Self::positions(currency_id, from);
@@ -249,7 +286,9 @@ impl<T: Config> Pallet<T> {
 			.expect("existing debit balance cannot overflow; qed");
 
 		// check new position
-		T::RiskManager::check_position_valid(currency_id, new_to_collateral_balance, new_to_debit_balance, true)?;
+		if !Self::is_cross_collateral(to) {
+			T::RiskManager::check_position_valid(currency_id, new_to_collateral_balance, new_to_debit_balance, true)?;
+		}
 
 		// balance -> amount
 		let collateral_adjustment = Self::amount_try_from_balance(collateral)?;
@@ -263,6 +302,19 @@ impl<T: Config> Pallet<T> {
 		)?;
 		Self::update_loan(to, currency_id, collateral_adjustment, debit_adjustment)?;
 
+		// the transferred debit is not new issuance, only the ceiling of `to` is checked
+		if !debit.is_zero() {
+			T::RiskManager::check_account_debit_ceiling(to, currency_id)?;
+		}
+
+		// the positions of cross-collateral account are checked as a whole after the transfer
+		if Self::is_cross_collateral(to) {
+			T::RiskManager::check_cross_collateral_position_valid(to, true)?;
+		}
+		if Self::is_cross_collateral(from) {
+			T::RiskManager::check_cross_collateral_position_valid(from, false)?;
+		}
+
 		Self::deposit_event(Event::TransferLoan(from.clone(), to.clone(), currency_id));
 		Ok(())
 	}
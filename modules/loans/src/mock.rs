@@ -36,7 +36,7 @@ use sp_runtime::{
 };
 use sp_std::cell::RefCell;
 use std::collections::HashMap;
-use support::{AuctionManager, RiskManager};
+use support::{AuctionManager, EmergencyShutdown, RiskManager};
 
 pub type AccountId = u128;
 pub type AuctionId = u32;
@@ -145,6 +145,14 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 		Ok(())
 	}
 
+	fn new_debit_auction(_amount: Self::Balance, _fix: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn new_surplus_auction(_amount: Self::Balance) -> DispatchResult {
+		Ok(())
+	}
+
 	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
 		Ok(())
 	}
@@ -156,6 +164,21 @@ impl AuctionManager<AccountId> for MockAuctionManager {
 	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
 		Default::default()
 	}
+
+	fn get_total_debit_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_surplus_in_auction() -> Self::Balance {
+		Default::default()
+	}
+}
+
+pub struct MockEmergencyShutdown;
+impl EmergencyShutdown for MockEmergencyShutdown {
+	fn is_shutdown() -> bool {
+		false
+	}
 }
 
 ord_parameter_types! {
@@ -179,6 +202,7 @@ impl cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = TreasuryAccount;
+	type EmergencyShutdown = MockEmergencyShutdown;
 	type WeightInfo = ();
 }
 
@@ -218,13 +242,29 @@ impl RiskManager<AccountId, CurrencyId, Balance, Balance> for MockRiskManager {
 		}
 	}
 
-	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: Balance) -> DispatchResult {
+	fn check_debit_cap(
+		_who: &AccountId,
+		currency_id: CurrencyId,
+		total_debit_balance: Balance,
+		_debit_increase: Balance,
+	) -> DispatchResult {
 		match (currency_id, total_debit_balance) {
 			(DOT, 1000) => Err(sp_runtime::DispatchError::Other("mock exceed debit value cap error")),
 			(BTC, 1000) => Err(sp_runtime::DispatchError::Other("mock exceed debit value cap error")),
 			(_, _) => Ok(()),
 		}
 	}
+
+	fn check_account_debit_ceiling(_who: &AccountId, _currency_id: CurrencyId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_cross_collateral_position_valid(who: &AccountId, _check_required_ratio: bool) -> DispatchResult {
+		match *who {
+			ALICE => Ok(()),
+			_ => Err(sp_runtime::DispatchError::Other("mock below liquidation ratio error")),
+		}
+	}
 }
 
 thread_local! {
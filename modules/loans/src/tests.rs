@@ -177,6 +177,72 @@ fn transfer_loan_should_work() {
 	});
 }
 
+#[test]
+fn set_cross_collateral_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert!(!LoansModule::is_cross_collateral(&ALICE));
+
+		LoansModule::set_cross_collateral(&ALICE, true);
+		assert!(LoansModule::is_cross_collateral(&ALICE));
+		System::assert_last_event(Event::LoansModule(crate::Event::CrossCollateralUpdated(ALICE, true)));
+
+		LoansModule::set_cross_collateral(&ALICE, false);
+		assert!(!LoansModule::is_cross_collateral(&ALICE));
+		System::assert_last_event(Event::LoansModule(crate::Event::CrossCollateralUpdated(ALICE, false)));
+	});
+}
+
+#[test]
+fn adjust_position_of_cross_collateral_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		LoansModule::set_cross_collateral(&ALICE, true);
+		LoansModule::set_cross_collateral(&BOB, true);
+
+		// mock can pass cross-collateral check even if the single position can't
+		assert_ok!(LoansModule::adjust_position(&ALICE, DOT, 500, 100));
+		assert_eq!(LoansModule::positions(DOT, &ALICE).collateral, 500);
+		assert_eq!(LoansModule::positions(DOT, &ALICE).debit, 100);
+
+		// mock can't pass cross-collateral check even if the single position can
+		assert_noop!(
+			LoansModule::adjust_position(&BOB, BTC, 500, 100),
+			sp_runtime::DispatchError::Other("mock below liquidation ratio error")
+		);
+	});
+}
+
+#[test]
+fn transfer_loan_of_cross_collateral_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LoansModule::update_loan(&ALICE, DOT, 400, 500));
+		assert_ok!(LoansModule::update_loan(&BOB, DOT, 100, 600));
+
+		// single position of BOB can't pass the check
+		assert_noop!(
+			LoansModule::transfer_loan(&ALICE, &BOB, DOT),
+			sp_runtime::DispatchError::Other("mock below required collateral ratio error")
+		);
+
+		// cross-collateral position of ALICE can pass the check
+		LoansModule::set_cross_collateral(&ALICE, true);
+		assert_ok!(LoansModule::transfer_loan(&BOB, &ALICE, DOT));
+		assert_eq!(LoansModule::positions(DOT, &ALICE).debit, 1100);
+		assert_eq!(LoansModule::positions(DOT, &ALICE).collateral, 500);
+		assert_eq!(LoansModule::positions(DOT, &BOB).debit, 0);
+
+		// cross-collateral position of BOB can't pass the check
+		assert_ok!(LoansModule::update_loan(&BOB, BTC, 100, 100));
+		LoansModule::set_cross_collateral(&BOB, true);
+		assert_noop!(
+			LoansModule::transfer_loan(&ALICE, &BOB, DOT),
+			sp_runtime::DispatchError::Other("mock below liquidation ratio error")
+		);
+	});
+}
+
 #[test]
 fn confiscate_collateral_and_debit_work() {
 	ExtBuilder::default().build().execute_with(|| {
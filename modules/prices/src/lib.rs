@@ -25,18 +25,38 @@
 //!   - specify a fixed price for stable currency
 //!   - feed price in USD or related price bewteen two currencies
 //!   - lock/unlock the price data get from oracle
+//!
+//! Oracle prices older than `MaxPriceAge` are treated as stale. Governance can
+//! set a circuit breaker for a currency, which freezes the currency when its
+//! price moves more than `max_change` within `period` blocks. For a frozen
+//! currency, a price moved too much or a stale price, the price providers
+//! fall back to the locked price, then to the TWAP on DEX, and return none if
+//! neither is available so that liquidations are paused instead of using bad
+//! data. The prices of liquid currency and dex share use the guarded prices of
+//! the currencies they're derived from.
+//!
+//! The price of a currency can also be aggregated from several sources, such
+//! as the oracle, the TWAP on DEX and the liquid staking exchange rate. The
+//! weighted median of the available sources is used, and the deviation between
+//! sources, including each oracle instance, is recorded and reported when it
+//! exceeds `max_deviation`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
-use frame_support::{pallet_prelude::*, transactional};
+use frame_support::{pallet_prelude::*, traits::UnixTime, transactional};
 use frame_system::pallet_prelude::*;
-use orml_traits::{DataFeeder, DataProvider, MultiCurrency};
-use primitives::{Balance, CurrencyId};
+use orml_oracle::TimestampedValue;
+use orml_traits::{DataFeeder, DataProvider, DataProviderExtended, MultiCurrency};
+use primitives::{Balance, CurrencyId, Moment};
+use scale_info::TypeInfo;
 use sp_core::U256;
-use sp_runtime::{traits::CheckedMul, FixedPointNumber};
-use sp_std::{convert::TryInto, marker::PhantomData};
-use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider};
+use sp_runtime::{
+	traits::{Bounded, CheckedDiv, CheckedMul, Saturating, Zero},
+	FixedPointNumber, RuntimeDebug,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, prelude::*};
+use support::{CurrencyIdMapping, DEXManager, ExchangeRateProvider, LockablePrice, Price, PriceProvider, Ratio};
 
 mod mock;
 mod tests;
@@ -45,6 +65,55 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// Parameters of the circuit breaker of a currency.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CircuitBreakerParams<BlockNumber> {
+	/// The max change of price compared to the reference price, the currency
+	/// will be frozen if the price moves more than it.
+	pub max_change: Ratio,
+	/// The period in blocks for which a reference price is kept.
+	pub period: BlockNumber,
+}
+
+/// The source of price which can be aggregated.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum PriceSourceKind {
+	/// The price fed by oracle operators.
+	Oracle,
+	/// The TWAP on DEX in stable currency.
+	DexTwap,
+	/// The price of staking currency multiplied by the liquid staking exchange
+	/// rate, only for liquid currency.
+	LiquidStaking,
+}
+
+/// The individual oracle instances which are medianized into `Config::Source`,
+/// the deviation between them is reported by the price aggregation.
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+pub trait OracleInstances {
+	/// Append the price of the currency from each instance to `prices`.
+	fn collect_prices(currency_id: CurrencyId, prices: &mut Vec<TimestampedValue<Price, Moment>>);
+}
+
+/// An oracle instance, such as the Acala oracle or the Band oracle.
+pub struct OracleInstance<P>(PhantomData<P>);
+impl<P: DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>> OracleInstances for OracleInstance<P> {
+	fn collect_prices(currency_id: CurrencyId, prices: &mut Vec<TimestampedValue<Price, Moment>>) {
+		if let Some(price) = P::get_no_op(&currency_id) {
+			prices.push(price);
+		}
+	}
+}
+
+/// Parameters of the price aggregation of a currency.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct PriceAggregationParams {
+	/// The sources to aggregate and their weights.
+	pub sources: Vec<(PriceSourceKind, u32)>,
+	/// The max deviation between sources, exceeding it will be reported.
+	pub max_deviation: Ratio,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -54,7 +123,12 @@ pub mod module {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
 		/// The data source, such as Oracle.
-		type Source: DataProvider<CurrencyId, Price> + DataFeeder<CurrencyId, Price, Self::AccountId>;
+		type Source: DataProvider<CurrencyId, Price>
+			+ DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>>
+			+ DataFeeder<CurrencyId, Price, Self::AccountId>;
+
+		/// The individual oracle instances medianized into `Source`.
+		type OracleInstances: OracleInstances;
 
 		/// The stable currency id, it should be AUSD in Acala.
 		#[pallet::constant]
@@ -88,6 +162,18 @@ pub mod module {
 		/// Mapping between CurrencyId and ERC20 address so user can use Erc20.
 		type CurrencyIdMapping: CurrencyIdMapping;
 
+		/// Time used for checking the age of oracle prices.
+		type UnixTime: UnixTime;
+
+		/// The max age in milliseconds of oracle prices, older prices are
+		/// stale.
+		#[pallet::constant]
+		type MaxPriceAge: Get<Moment>;
+
+		/// The window in blocks of the TWAP on DEX used as the fallback price.
+		#[pallet::constant]
+		type FallbackTwapWindow: Get<u32>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -98,6 +184,10 @@ pub mod module {
 		AccessPriceFailed,
 		/// There's no locked price
 		NoLockedPrice,
+		/// The price of the currency is not frozen
+		PriceNotFrozen,
+		/// The price aggregation params are invalid
+		InvalidPriceAggregationParams,
 	}
 
 	#[pallet::event]
@@ -107,6 +197,25 @@ pub mod module {
 		LockPrice(CurrencyId, Price),
 		/// Unlock price. \[currency_id\]
 		UnlockPrice(CurrencyId),
+		/// The circuit breaker of currency updated. \[currency_id,
+		/// circuit_breaker_params\]
+		CircuitBreakerUpdated(CurrencyId, Option<CircuitBreakerParams<T::BlockNumber>>),
+		/// The price moved too much and the currency is frozen. \[currency_id,
+		/// reference_price, new_price\]
+		PriceFrozen(CurrencyId, Price, Price),
+		/// The frozen currency is unfrozen. \[currency_id\]
+		PriceUnfrozen(CurrencyId),
+		/// The oracle price of currency is stale. \[currency_id,
+		/// price_timestamp\]
+		PriceStale(CurrencyId, Moment),
+		/// The oracle price of currency is fresh again. \[currency_id\]
+		PriceRecovered(CurrencyId),
+		/// The price aggregation of currency updated. \[currency_id,
+		/// price_aggregation_params\]
+		PriceAggregationUpdated(CurrencyId, Option<PriceAggregationParams>),
+		/// The deviation between price sources exceeds the max deviation.
+		/// \[currency_id, deviation\]
+		PriceSourcesDeviated(CurrencyId, Ratio),
 	}
 
 	/// Mapping from currency id to it's locked price
@@ -116,11 +225,78 @@ pub mod module {
 	#[pallet::getter(fn locked_price)]
 	pub type LockedPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Price, OptionQuery>;
 
+	/// Mapping from currency id to its circuit breaker params
+	///
+	/// CircuitBreakers: map CurrencyId => Option<CircuitBreakerParams>
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breakers)]
+	pub type CircuitBreakers<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, CircuitBreakerParams<T::BlockNumber>, OptionQuery>;
+
+	/// Mapping from currency id to the reference price of circuit breaker and
+	/// the block number it was recorded at
+	///
+	/// ReferencePrice: map CurrencyId => Option<(Price, BlockNumber)>
+	#[pallet::storage]
+	#[pallet::getter(fn reference_price)]
+	pub type ReferencePrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, (Price, T::BlockNumber), OptionQuery>;
+
+	/// Mapping from frozen currency id to the block number it was frozen at
+	///
+	/// FrozenPrice: map CurrencyId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn frozen_price)]
+	pub type FrozenPrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, T::BlockNumber, OptionQuery>;
+
+	/// Mapping from currency id with stale oracle price to the timestamp of
+	/// the stale price, only tracked for currencies with circuit breaker
+	///
+	/// StalePrice: map CurrencyId => Option<Moment>
+	#[pallet::storage]
+	#[pallet::getter(fn stale_price)]
+	pub type StalePrice<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Moment, OptionQuery>;
+
+	/// Mapping from currency id to its price aggregation params
+	///
+	/// PriceAggregations: map CurrencyId => Option<PriceAggregationParams>
+	#[pallet::storage]
+	#[pallet::getter(fn price_aggregations)]
+	pub type PriceAggregations<T: Config> =
+		StorageMap<_, Twox64Concat, CurrencyId, PriceAggregationParams, OptionQuery>;
+
+	/// Mapping from currency id to the latest deviation between its price
+	/// sources, the deviation is (max_price - min_price) / median_price
+	///
+	/// PriceSourceDeviation: map CurrencyId => Option<Ratio>
+	#[pallet::storage]
+	#[pallet::getter(fn price_source_deviation)]
+	pub type PriceSourceDeviation<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Ratio, OptionQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Check the prices of currencies with circuit breaker, freeze the
+		/// currency if its price moved too much and report stale prices. Then
+		/// record the deviation between the price sources of currencies with
+		/// price aggregation.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut count: u32 = 0;
+			for (currency_id, params) in CircuitBreakers::<T>::iter() {
+				Self::check_circuit_breaker(currency_id, &params, now);
+				count += 1;
+			}
+
+			let mut aggregation_count: u32 = 0;
+			for (currency_id, params) in PriceAggregations::<T>::iter() {
+				Self::check_price_sources(currency_id, &params);
+				aggregation_count += 1;
+			}
+
+			T::WeightInfo::on_initialize(count).saturating_add(T::WeightInfo::check_price_sources(aggregation_count))
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -149,6 +325,72 @@ pub mod module {
 			<Pallet<T> as LockablePrice<CurrencyId>>::unlock_price(currency_id)?;
 			Ok(())
 		}
+
+		/// Set or remove the circuit breaker of the currency.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `params`: the circuit breaker params, `None` to remove it.
+		#[pallet::weight((T::WeightInfo::set_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_circuit_breaker(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<CircuitBreakerParams<T::BlockNumber>>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			CircuitBreakers::<T>::mutate_exists(currency_id, |maybe_params| *maybe_params = params);
+			ReferencePrice::<T>::remove(currency_id);
+			if params.is_none() {
+				StalePrice::<T>::remove(currency_id);
+			}
+			Self::deposit_event(Event::CircuitBreakerUpdated(currency_id, params));
+			Ok(())
+		}
+
+		/// Unfreeze the currency frozen by circuit breaker, the reference price
+		/// will be recorded again.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		#[pallet::weight((T::WeightInfo::unfreeze_price(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn unfreeze_price(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			FrozenPrice::<T>::take(currency_id).ok_or(Error::<T>::PriceNotFrozen)?;
+			ReferencePrice::<T>::remove(currency_id);
+			Self::deposit_event(Event::PriceUnfrozen(currency_id));
+			Ok(())
+		}
+
+		/// Set or remove the price aggregation of the currency.
+		///
+		/// The dispatch origin of this call must be `LockOrigin`.
+		///
+		/// - `currency_id`: currency type.
+		/// - `params`: the price aggregation params, `None` to remove it.
+		#[pallet::weight((T::WeightInfo::set_price_aggregation(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_price_aggregation(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			params: Option<PriceAggregationParams>,
+		) -> DispatchResult {
+			T::LockOrigin::ensure_origin(origin)?;
+			if let Some(params) = &params {
+				ensure!(
+					Self::is_valid_price_aggregation(currency_id, params),
+					Error::<T>::InvalidPriceAggregationParams
+				);
+			}
+
+			PriceAggregations::<T>::mutate_exists(currency_id, |maybe_params| *maybe_params = params.clone());
+			PriceSourceDeviation::<T>::remove(currency_id);
+			Self::deposit_event(Event::PriceAggregationUpdated(currency_id, params));
+			Ok(())
+		}
 	}
 }
 
@@ -161,17 +403,22 @@ impl<T: Config> Pallet<T> {
 		let maybe_price = if currency_id == T::GetStableCurrencyId::get() {
 			// if is stable currency, use fixed price
 			Some(T::StableCurrencyFixedPrice::get())
+		} else if let Some(params) = Self::price_aggregations(currency_id) {
+			// directly return the weighted median of the price sources
+			return Self::aggregate_price(currency_id, &params).map(|(price, _)| price);
 		} else if currency_id == T::GetLiquidCurrencyId::get() {
 			// directly return real-time the multiple of the price of StakingCurrencyId and the exchange rate
-			return Self::access_price(T::GetStakingCurrencyId::get())
-				.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()));
+			return Self::liquid_staking_price();
 		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
 			let token_0: CurrencyId = symbol_0.into();
 			let token_1: CurrencyId = symbol_1.into();
 
-			// directly return the fair price
+			// directly return the fair price, the prices of tokens are guarded so that the
+			// frozen or stale token prices fall back
 			return {
-				if let (Some(price_0), Some(price_1)) = (Self::access_price(token_0), Self::access_price(token_1)) {
+				if let (Some(price_0), Some(price_1)) =
+					(Self::access_guarded_price(token_0), Self::access_guarded_price(token_1))
+				{
 					let (pool_0, pool_1) = T::DEX::get_liquidity_pool(token_0, token_1);
 					let total_shares = T::Currency::total_issuance(currency_id);
 					lp_token_fair_price(total_shares, pool_0, pool_1, price_0, price_1)
@@ -181,16 +428,226 @@ impl<T: Config> Pallet<T> {
 			};
 		} else {
 			// get real-time price from oracle
-			T::Source::get(&currency_id)
+			return Self::oracle_price(currency_id);
 		};
 
-		let maybe_adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into());
+		maybe_price.and_then(|price| Self::price_for_basic_unit(currency_id, price))
+	}
+
+	/// Convert the price of 1 unit to the price for 1 basic unit.
+	fn price_for_basic_unit(currency_id: CurrencyId, price: Price) -> Option<Price> {
+		let adjustment_multiplier = 10u128.checked_pow(T::CurrencyIdMapping::decimals(currency_id)?.into())?;
+		Price::checked_from_rational(price.into_inner(), adjustment_multiplier)
+	}
+
+	/// The real-time price from oracle, stale price is unavailable.
+	fn oracle_price(currency_id: CurrencyId) -> Option<Price> {
+		if Self::stale_price_timestamp(currency_id).is_some() {
+			return None;
+		}
+		T::Source::get(&currency_id).and_then(|price| Self::price_for_basic_unit(currency_id, price))
+	}
+
+	/// The multiple of the guarded price of staking currency and the liquid
+	/// staking exchange rate.
+	fn liquid_staking_price() -> Option<Price> {
+		Self::access_guarded_price(T::GetStakingCurrencyId::get())
+			.and_then(|n| n.checked_mul(&T::LiquidStakingExchangeRateProvider::get_exchange_rate()))
+	}
+
+	/// The TWAP of the currency in stable currency on DEX.
+	fn dex_twap_price(currency_id: CurrencyId) -> Option<Price> {
+		let stable_currency_id = T::GetStableCurrencyId::get();
+		T::DEX::get_twap(currency_id, stable_currency_id, T::FallbackTwapWindow::get())
+			.and_then(|twap| twap.checked_mul(&Self::access_price(stable_currency_id)?))
+	}
+
+	fn source_price(currency_id: CurrencyId, source: PriceSourceKind) -> Option<Price> {
+		match source {
+			PriceSourceKind::Oracle => Self::oracle_price(currency_id),
+			PriceSourceKind::DexTwap => Self::dex_twap_price(currency_id),
+			PriceSourceKind::LiquidStaking => Self::liquid_staking_price(),
+		}
+	}
+
+	/// The fresh prices of the currency from the individual oracle instances.
+	fn oracle_instance_prices(currency_id: CurrencyId) -> Vec<Price> {
+		let now: Moment = T::UnixTime::now().as_millis().try_into().unwrap_or(Moment::MAX);
+		let mut prices = Vec::new();
+		T::OracleInstances::collect_prices(currency_id, &mut prices);
+		prices
+			.into_iter()
+			.filter(|price| now.saturating_sub(price.timestamp) <= T::MaxPriceAge::get())
+			.filter_map(|price| Self::price_for_basic_unit(currency_id, price.value))
+			.collect()
+	}
 
-		if let (Some(price), Some(adjustment_multiplier)) = (maybe_price, maybe_adjustment_multiplier) {
-			// return the price for 1 basic unit
-			Price::checked_from_rational(price.into_inner(), adjustment_multiplier)
+	/// Aggregate the available price sources of the currency, returns the
+	/// weighted median price and the deviation between sources. The oracle
+	/// source is medianized, so each oracle instance is compared separately
+	/// for the deviation.
+	pub fn aggregate_price(currency_id: CurrencyId, params: &PriceAggregationParams) -> Option<(Price, Ratio)> {
+		let mut prices: Vec<(Price, u32)> = params
+			.sources
+			.iter()
+			.filter_map(|(source, weight)| Self::source_price(currency_id, *source).map(|price| (price, *weight)))
+			.collect();
+		prices.sort_by(|a, b| a.0.cmp(&b.0));
+
+		let total_weight = prices.iter().fold(0u32, |acc, (_, weight)| acc.saturating_add(*weight));
+		let mut accumulated_weight: u32 = 0;
+		let (median, _) = prices.iter().find(|(_, weight)| {
+			accumulated_weight = accumulated_weight.saturating_add(*weight);
+			accumulated_weight.saturating_mul(2) >= total_weight
+		})?;
+
+		let mut compared_prices: Vec<Price> = prices.iter().map(|(price, _)| *price).collect();
+		if params
+			.sources
+			.iter()
+			.any(|(source, _)| *source == PriceSourceKind::Oracle)
+		{
+			compared_prices.extend(Self::oracle_instance_prices(currency_id));
+		}
+		let min = compared_prices.iter().min().copied()?;
+		let max = compared_prices.iter().max().copied()?;
+		let deviation = max
+			.saturating_sub(min)
+			.checked_div(median)
+			.unwrap_or_else(Ratio::max_value);
+		Some((*median, deviation))
+	}
+
+	fn is_valid_price_aggregation(currency_id: CurrencyId, params: &PriceAggregationParams) -> bool {
+		let mut sources: Vec<PriceSourceKind> = Vec::new();
+		for (source, weight) in params.sources.iter() {
+			if weight.is_zero()
+				|| sources.contains(source)
+				|| (*source == PriceSourceKind::LiquidStaking && currency_id != T::GetLiquidCurrencyId::get())
+			{
+				return false;
+			}
+			sources.push(*source);
+		}
+
+		!sources.is_empty()
+			&& currency_id != T::GetStableCurrencyId::get()
+			&& !matches!(currency_id, CurrencyId::DexShare(_, _))
+	}
+
+	fn check_price_sources(currency_id: CurrencyId, params: &PriceAggregationParams) {
+		if let Some((_, deviation)) = Self::aggregate_price(currency_id, params) {
+			let exceeded = |deviation: Ratio| deviation > params.max_deviation;
+			if exceeded(deviation) && !Self::price_source_deviation(currency_id).map_or(false, exceeded) {
+				Self::deposit_event(Event::PriceSourcesDeviated(currency_id, deviation));
+			}
+			PriceSourceDeviation::<T>::insert(currency_id, deviation);
 		} else {
-			None
+			PriceSourceDeviation::<T>::remove(currency_id);
+		}
+	}
+
+	/// access the price of specific currency with the protections of circuit
+	/// breaker and staleness check. If the currency is frozen, its price moved
+	/// more than the circuit breaker allows or its oracle price is stale, fall
+	/// back to the locked price or the TWAP on DEX.
+	///
+	/// The price is checked against the reference price here rather than only
+	/// in `on_initialize`, so that a spike fed in a block is not used in the
+	/// same block.
+	fn access_guarded_price(currency_id: CurrencyId) -> Option<Price> {
+		if Self::frozen_price(currency_id).is_some() {
+			return Self::fallback_price(currency_id);
+		}
+
+		match Self::access_price(currency_id) {
+			Some(price) if Self::exceeds_max_change(currency_id, price) => Self::fallback_price(currency_id),
+			Some(price) => Some(price),
+			None if Self::is_price_stale(currency_id) => Self::fallback_price(currency_id),
+			None => None,
+		}
+	}
+
+	/// Whether the price moved more than `max_change` of the circuit breaker
+	/// compared to the reference price recorded within `period`.
+	fn exceeds_max_change(currency_id: CurrencyId, price: Price) -> bool {
+		match (Self::circuit_breakers(currency_id), Self::reference_price(currency_id)) {
+			(Some(params), Some((reference_price, recorded_at))) => {
+				let now = <frame_system::Pallet<T>>::block_number();
+				now.saturating_sub(recorded_at) < params.period
+					&& Self::price_change_ratio(reference_price, price) > params.max_change
+			}
+			_ => false,
+		}
+	}
+
+	fn price_change_ratio(reference_price: Price, price: Price) -> Ratio {
+		let change = if price > reference_price {
+			price.saturating_sub(reference_price)
+		} else {
+			reference_price.saturating_sub(price)
+		};
+		change.checked_div(&reference_price).unwrap_or_else(Ratio::max_value)
+	}
+
+	/// The fallback price is the locked price if it exists, otherwise the TWAP
+	/// of the currency in stable currency on DEX.
+	fn fallback_price(currency_id: CurrencyId) -> Option<Price> {
+		Self::locked_price(currency_id).or_else(|| Self::dex_twap_price(currency_id))
+	}
+
+	/// Get the timestamp of the oracle price if it is older than `MaxPriceAge`.
+	fn stale_price_timestamp(currency_id: CurrencyId) -> Option<Moment> {
+		let now: Moment = T::UnixTime::now().as_millis().try_into().unwrap_or(Moment::MAX);
+		T::Source::get_no_op(&currency_id)
+			.map(|timestamped| timestamped.timestamp)
+			.filter(|timestamp| now.saturating_sub(*timestamp) > T::MaxPriceAge::get())
+	}
+
+	/// Whether the price of the currency is stale. The price of liquid
+	/// currency and dex share is derived from other currencies, so it's stale
+	/// if the price of any of them is stale.
+	pub fn is_price_stale(currency_id: CurrencyId) -> bool {
+		if currency_id == T::GetLiquidCurrencyId::get() && Self::price_aggregations(currency_id).is_none() {
+			Self::is_price_stale(T::GetStakingCurrencyId::get())
+		} else if let CurrencyId::DexShare(symbol_0, symbol_1) = currency_id {
+			Self::is_price_stale(symbol_0.into()) || Self::is_price_stale(symbol_1.into())
+		} else {
+			Self::stale_price_timestamp(currency_id).is_some()
+		}
+	}
+
+	fn check_circuit_breaker(
+		currency_id: CurrencyId,
+		params: &CircuitBreakerParams<T::BlockNumber>,
+		now: T::BlockNumber,
+	) {
+		match (Self::stale_price_timestamp(currency_id), Self::stale_price(currency_id)) {
+			(Some(timestamp), None) => {
+				StalePrice::<T>::insert(currency_id, timestamp);
+				Self::deposit_event(Event::PriceStale(currency_id, timestamp));
+			}
+			(None, Some(_)) => {
+				StalePrice::<T>::remove(currency_id);
+				Self::deposit_event(Event::PriceRecovered(currency_id));
+			}
+			_ => {}
+		}
+
+		if Self::frozen_price(currency_id).is_some() {
+			return;
+		}
+
+		if let Some(price) = Self::access_price(currency_id) {
+			match Self::reference_price(currency_id) {
+				Some((reference_price, recorded_at)) if now.saturating_sub(recorded_at) < params.period => {
+					if Self::price_change_ratio(reference_price, price) > params.max_change {
+						FrozenPrice::<T>::insert(currency_id, now);
+						Self::deposit_event(Event::PriceFrozen(currency_id, reference_price, price));
+					}
+				}
+				_ => ReferencePrice::<T>::insert(currency_id, (price, now)),
+			}
 		}
 	}
 }
@@ -212,11 +669,12 @@ impl<T: Config> LockablePrice<CurrencyId> for Pallet<T> {
 	}
 }
 
-/// PriceProvider that always provider real-time prices from oracle
+/// PriceProvider that always provider real-time prices from oracle, unless the
+/// currency is frozen or the price is stale
 pub struct RealTimePriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price> {
-		Pallet::<T>::access_price(currency_id)
+		Pallet::<T>::access_guarded_price(currency_id)
 	}
 }
 
@@ -225,7 +683,7 @@ impl<T: Config> PriceProvider<CurrencyId> for RealTimePriceProvider<T> {
 pub struct PriorityLockedPriceProvider<T>(PhantomData<T>);
 impl<T: Config> PriceProvider<CurrencyId> for PriorityLockedPriceProvider<T> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price> {
-		Pallet::<T>::locked_price(currency_id).or_else(|| Pallet::<T>::access_price(currency_id))
+		Pallet::<T>::locked_price(currency_id).or_else(|| Pallet::<T>::access_guarded_price(currency_id))
 	}
 }
 
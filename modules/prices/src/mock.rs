@@ -85,12 +85,27 @@ impl frame_system::Config for Runtime {
 
 thread_local! {
 	static CHANGED: RefCell<bool> = RefCell::new(false);
+	static PRICE_TIMESTAMP: RefCell<Moment> = RefCell::new(0);
+	static TWAP: RefCell<Option<ExchangeRate>> = RefCell::new(None);
+	static SECOND_ORACLE_PRICE: RefCell<Option<Price>> = RefCell::new(None);
 }
 
 pub fn mock_oracle_update() {
 	CHANGED.with(|v| *v.borrow_mut() = true)
 }
 
+pub fn mock_price_timestamp(timestamp: Moment) {
+	PRICE_TIMESTAMP.with(|v| *v.borrow_mut() = timestamp)
+}
+
+pub fn mock_twap(twap: Option<ExchangeRate>) {
+	TWAP.with(|v| *v.borrow_mut() = twap)
+}
+
+pub fn mock_second_oracle_price(price: Option<Price>) {
+	SECOND_ORACLE_PRICE.with(|v| *v.borrow_mut() = price)
+}
+
 pub struct MockDataProvider;
 impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	fn get(currency_id: &CurrencyId) -> Option<Price> {
@@ -116,6 +131,37 @@ impl DataProvider<CurrencyId, Price> for MockDataProvider {
 	}
 }
 
+impl DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>> for MockDataProvider {
+	fn get_no_op(currency_id: &CurrencyId) -> Option<TimestampedValue<Price, Moment>> {
+		Self::get(currency_id).map(|value| TimestampedValue {
+			value,
+			timestamp: PRICE_TIMESTAMP.with(|v| *v.borrow()),
+		})
+	}
+
+	fn get_all_values() -> Vec<(CurrencyId, Option<TimestampedValue<Price, Moment>>)> {
+		unimplemented!()
+	}
+}
+
+/// The second oracle instance, which only has the price of DOT.
+pub struct MockSecondOracle;
+impl DataProviderExtended<CurrencyId, TimestampedValue<Price, Moment>> for MockSecondOracle {
+	fn get_no_op(currency_id: &CurrencyId) -> Option<TimestampedValue<Price, Moment>> {
+		match *currency_id {
+			DOT => SECOND_ORACLE_PRICE.with(|v| *v.borrow()).map(|value| TimestampedValue {
+				value,
+				timestamp: PRICE_TIMESTAMP.with(|v| *v.borrow()),
+			}),
+			_ => None,
+		}
+	}
+
+	fn get_all_values() -> Vec<(CurrencyId, Option<TimestampedValue<Price, Moment>>)> {
+		unimplemented!()
+	}
+}
+
 impl DataFeeder<CurrencyId, Price, AccountId> for MockDataProvider {
 	fn feed_value(_: AccountId, _: CurrencyId, _: Price) -> sp_runtime::DispatchResult {
 		Ok(())
@@ -154,6 +200,26 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		unimplemented!()
+	}
+
+	fn get_twap(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId, _window: u32) -> Option<ExchangeRate> {
+		TWAP.with(|v| *v.borrow())
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -172,6 +238,18 @@ impl DEXManager<AccountId, CurrencyId, Balance> for MockDEX {
 		unimplemented!()
 	}
 
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		_who: &AccountId,
+		_borrow_currency_id: CurrencyId,
+		_paired_currency_id: CurrencyId,
+		_borrow_amount: Balance,
+		_repay_currency_id: CurrencyId,
+		_max_repay_amount: Balance,
+		_callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		unimplemented!()
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -215,11 +293,24 @@ impl orml_tokens::Config for Runtime {
 	type DustRemovalWhitelist = Nothing;
 }
 
+parameter_types! {
+	pub const MinimumPeriod: Moment = 1000;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = 1;
 }
 
 parameter_types! {
+	pub const MaxPriceAge: Moment = 10_000;
+	pub const FallbackTwapWindow: u32 = 10;
 	pub const GetStableCurrencyId: CurrencyId = AUSD;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
@@ -229,6 +320,7 @@ parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type Source = MockDataProvider;
+	type OracleInstances = (OracleInstance<MockDataProvider>, OracleInstance<MockSecondOracle>);
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -238,6 +330,9 @@ impl Config for Runtime {
 	type DEX = MockDEX;
 	type Currency = Tokens;
 	type CurrencyIdMapping = MockCurrencyIdMapping;
+	type UnixTime = Timestamp;
+	type MaxPriceAge = MaxPriceAge;
+	type FallbackTwapWindow = FallbackTwapWindow;
 	type WeightInfo = ();
 }
 
@@ -253,6 +348,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		PricesModule: prices::{Pallet, Storage, Call, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 	}
 );
 
@@ -508,3 +508,358 @@ fn price_providers_work() {
 		assert_eq!(LockedPriceProvider::<Runtime>::get_relative_price(BTC, KSM), None);
 	});
 }
+
+#[test]
+fn stale_price_fall_back_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		mock_price_timestamp(1_000);
+		Timestamp::set_timestamp(11_000);
+		assert!(!PricesModule::is_price_stale(DOT));
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+
+		// stale price is unavailable without fallback
+		Timestamp::set_timestamp(11_001);
+		assert!(PricesModule::is_price_stale(DOT));
+		assert_eq!(PricesModule::access_price(DOT), None);
+		assert_eq!(PricesModule::access_price(LDOT), None);
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(DOT), None);
+		assert_noop!(
+			PricesModule::lock_price(Origin::signed(1), DOT),
+			Error::<Runtime>::AccessPriceFailed
+		);
+
+		// fall back to the TWAP on DEX
+		mock_twap(Some(ExchangeRate::saturating_from_integer(50)));
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(50000000u128))
+		);
+
+		// the staleness of staking currency passes through to liquid currency and dex share
+		assert!(PricesModule::is_price_stale(LDOT));
+		assert!(PricesModule::is_price_stale(LP_AUSD_DOT));
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(LDOT),
+			Some(Price::saturating_from_integer(25000000u128))
+		); // fallback_dot_price * 1/2
+
+		// fall back to the locked price first
+		Timestamp::set_timestamp(11_000);
+		assert_ok!(PricesModule::lock_price(Origin::signed(1), DOT));
+		Timestamp::set_timestamp(11_001);
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+	});
+}
+
+#[test]
+fn set_circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = CircuitBreakerParams {
+			max_change: Ratio::saturating_from_rational(20, 100),
+			period: 10,
+		};
+
+		assert_noop!(
+			PricesModule::set_circuit_breaker(Origin::signed(2), DOT, Some(params)),
+			BadOrigin
+		);
+		assert_ok!(PricesModule::set_circuit_breaker(Origin::signed(1), DOT, Some(params)));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerUpdated(
+			DOT,
+			Some(params),
+		)));
+		assert_eq!(PricesModule::circuit_breakers(DOT), Some(params));
+
+		assert_ok!(PricesModule::set_circuit_breaker(Origin::signed(1), DOT, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::CircuitBreakerUpdated(DOT, None)));
+		assert_eq!(PricesModule::circuit_breakers(DOT), None);
+	});
+}
+
+#[test]
+fn circuit_breaker_freeze_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DOT,
+			Some(CircuitBreakerParams {
+				max_change: Ratio::saturating_from_rational(20, 100),
+				period: 10,
+			})
+		));
+
+		PricesModule::on_initialize(1);
+		assert_eq!(
+			PricesModule::reference_price(DOT),
+			Some((Price::saturating_from_integer(10000000000u128), 1))
+		);
+
+		// price drops by 90%, the spike is not used in the same block
+		mock_oracle_update();
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(DOT), None);
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(LDOT), None);
+
+		// freeze it
+		System::set_block_number(2);
+		PricesModule::on_initialize(2);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceFrozen(
+			DOT,
+			Price::saturating_from_integer(10000000000u128),
+			Price::saturating_from_integer(1000000000u128),
+		)));
+		assert_eq!(PricesModule::frozen_price(DOT), Some(2));
+		assert_eq!(RealTimePriceProvider::<Runtime>::get_price(DOT), None);
+		assert_eq!(PriorityLockedPriceProvider::<Runtime>::get_price(DOT), None);
+
+		mock_twap(Some(ExchangeRate::saturating_from_integer(20)));
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(20000000u128))
+		);
+
+		assert_noop!(PricesModule::unfreeze_price(Origin::signed(2), DOT), BadOrigin);
+		assert_noop!(
+			PricesModule::unfreeze_price(Origin::signed(1), BTC),
+			Error::<Runtime>::PriceNotFrozen
+		);
+		assert_ok!(PricesModule::unfreeze_price(Origin::signed(1), DOT));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceUnfrozen(DOT)));
+		assert_eq!(PricesModule::frozen_price(DOT), None);
+		assert_eq!(PricesModule::reference_price(DOT), None);
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(1000000000u128))
+		);
+
+		// record the reference price again
+		System::set_block_number(3);
+		PricesModule::on_initialize(3);
+		assert_eq!(
+			PricesModule::reference_price(DOT),
+			Some((Price::saturating_from_integer(1000000000u128), 3))
+		);
+		assert_eq!(PricesModule::frozen_price(DOT), None);
+	});
+}
+
+#[test]
+fn circuit_breaker_report_stale_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PricesModule::set_circuit_breaker(
+			Origin::signed(1),
+			DOT,
+			Some(CircuitBreakerParams {
+				max_change: Ratio::saturating_from_rational(20, 100),
+				period: 10,
+			})
+		));
+		mock_price_timestamp(1_000);
+		Timestamp::set_timestamp(11_001);
+
+		PricesModule::on_initialize(1);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceStale(DOT, 1_000)));
+		assert_eq!(PricesModule::stale_price(DOT), Some(1_000));
+		assert_eq!(PricesModule::reference_price(DOT), None);
+
+		mock_price_timestamp(11_000);
+		System::set_block_number(2);
+		PricesModule::on_initialize(2);
+		System::assert_has_event(Event::PricesModule(crate::Event::PriceRecovered(DOT)));
+		assert_eq!(PricesModule::stale_price(DOT), None);
+		assert_eq!(
+			PricesModule::reference_price(DOT),
+			Some((Price::saturating_from_integer(10000000000u128), 2))
+		);
+	});
+}
+
+#[test]
+fn set_price_aggregation_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = PriceAggregationParams {
+			sources: vec![(PriceSourceKind::Oracle, 2), (PriceSourceKind::DexTwap, 1)],
+			max_deviation: Ratio::saturating_from_rational(5, 100),
+		};
+
+		assert_noop!(
+			PricesModule::set_price_aggregation(Origin::signed(2), DOT, Some(params.clone())),
+			BadOrigin
+		);
+		for invalid_sources in vec![
+			vec![],
+			vec![(PriceSourceKind::Oracle, 0)],
+			vec![(PriceSourceKind::Oracle, 1), (PriceSourceKind::Oracle, 1)],
+			vec![(PriceSourceKind::LiquidStaking, 1)],
+		] {
+			assert_noop!(
+				PricesModule::set_price_aggregation(
+					Origin::signed(1),
+					DOT,
+					Some(PriceAggregationParams {
+						sources: invalid_sources,
+						max_deviation: Ratio::saturating_from_rational(5, 100),
+					})
+				),
+				Error::<Runtime>::InvalidPriceAggregationParams
+			);
+		}
+		assert_noop!(
+			PricesModule::set_price_aggregation(Origin::signed(1), AUSD, Some(params.clone())),
+			Error::<Runtime>::InvalidPriceAggregationParams
+		);
+		assert_noop!(
+			PricesModule::set_price_aggregation(Origin::signed(1), LP_AUSD_DOT, Some(params.clone())),
+			Error::<Runtime>::InvalidPriceAggregationParams
+		);
+
+		assert_ok!(PricesModule::set_price_aggregation(
+			Origin::signed(1),
+			DOT,
+			Some(params.clone())
+		));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceAggregationUpdated(
+			DOT,
+			Some(params.clone()),
+		)));
+		assert_eq!(PricesModule::price_aggregations(DOT), Some(params));
+
+		assert_ok!(PricesModule::set_price_aggregation(
+			Origin::signed(1),
+			LDOT,
+			Some(PriceAggregationParams {
+				sources: vec![(PriceSourceKind::LiquidStaking, 1), (PriceSourceKind::DexTwap, 1)],
+				max_deviation: Ratio::saturating_from_rational(5, 100),
+			})
+		));
+
+		assert_ok!(PricesModule::set_price_aggregation(Origin::signed(1), DOT, None));
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceAggregationUpdated(DOT, None)));
+		assert_eq!(PricesModule::price_aggregations(DOT), None);
+	});
+}
+
+#[test]
+fn aggregate_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = PriceAggregationParams {
+			sources: vec![(PriceSourceKind::Oracle, 2), (PriceSourceKind::DexTwap, 1)],
+			max_deviation: Ratio::saturating_from_rational(5, 100),
+		};
+		assert_ok!(PricesModule::set_price_aggregation(
+			Origin::signed(1),
+			DOT,
+			Some(params.clone())
+		));
+
+		// only the oracle source is available
+		assert_eq!(
+			PricesModule::aggregate_price(DOT, &params),
+			Some((Price::saturating_from_integer(10000000000u128), Ratio::zero()))
+		);
+
+		// the weighted median is the oracle price
+		mock_twap(Some(ExchangeRate::saturating_from_integer(9000)));
+		assert_eq!(
+			PricesModule::aggregate_price(DOT, &params),
+			Some((
+				Price::saturating_from_integer(10000000000u128),
+				Ratio::saturating_from_rational(10, 100)
+			))
+		);
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(10000000000u128))
+		);
+		assert_eq!(
+			PricesModule::access_price(LDOT),
+			Some(Price::saturating_from_integer(5000000000u128))
+		);
+
+		// report the deviation once when it exceeds the max deviation
+		PricesModule::on_initialize(1);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcesDeviated(
+			DOT,
+			Ratio::saturating_from_rational(10, 100),
+		)));
+		assert_eq!(
+			PricesModule::price_source_deviation(DOT),
+			Some(Ratio::saturating_from_rational(10, 100))
+		);
+		System::reset_events();
+		PricesModule::on_initialize(2);
+		assert!(System::events().is_empty());
+
+		// the stale oracle source is excluded
+		mock_price_timestamp(1_000);
+		Timestamp::set_timestamp(11_001);
+		assert_eq!(
+			RealTimePriceProvider::<Runtime>::get_price(DOT),
+			Some(Price::saturating_from_integer(9000000000u128))
+		);
+		PricesModule::on_initialize(3);
+		assert_eq!(PricesModule::price_source_deviation(DOT), Some(Ratio::zero()));
+
+		// no source is available
+		mock_twap(None);
+		assert_eq!(PricesModule::aggregate_price(DOT, &params), None);
+		assert_eq!(PricesModule::access_price(DOT), None);
+		PricesModule::on_initialize(4);
+		assert_eq!(PricesModule::price_source_deviation(DOT), None);
+	});
+}
+
+#[test]
+fn aggregate_price_compare_oracle_instances_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let params = PriceAggregationParams {
+			sources: vec![(PriceSourceKind::Oracle, 1)],
+			max_deviation: Ratio::saturating_from_rational(5, 100),
+		};
+		assert_ok!(PricesModule::set_price_aggregation(
+			Origin::signed(1),
+			DOT,
+			Some(params.clone())
+		));
+		assert_eq!(
+			PricesModule::aggregate_price(DOT, &params),
+			Some((Price::saturating_from_integer(10000000000u128), Ratio::zero()))
+		);
+
+		// the medianized oracle price is unchanged, but the instances deviate
+		mock_second_oracle_price(Some(Price::saturating_from_integer(120)));
+		assert_eq!(
+			PricesModule::aggregate_price(DOT, &params),
+			Some((
+				Price::saturating_from_integer(10000000000u128),
+				Ratio::saturating_from_rational(20, 100)
+			))
+		);
+		PricesModule::on_initialize(1);
+		System::assert_last_event(Event::PricesModule(crate::Event::PriceSourcesDeviated(
+			DOT,
+			Ratio::saturating_from_rational(20, 100),
+		)));
+
+		// the stale price of instance is not compared
+		mock_price_timestamp(1_000);
+		Timestamp::set_timestamp(11_000);
+		assert_eq!(
+			PricesModule::aggregate_price(DOT, &params).map(|(_, deviation)| deviation),
+			Some(Ratio::saturating_from_rational(20, 100))
+		);
+		Timestamp::set_timestamp(11_001);
+		assert_eq!(PricesModule::aggregate_price(DOT, &params), None);
+	});
+}
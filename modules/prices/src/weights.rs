@@ -50,6 +50,11 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn lock_price() -> Weight;
 	fn unlock_price() -> Weight;
+	fn set_circuit_breaker() -> Weight;
+	fn unfreeze_price() -> Weight;
+	fn on_initialize(c: u32) -> Weight;
+	fn set_price_aggregation() -> Weight;
+	fn check_price_sources(c: u32) -> Weight;
 }
 
 /// Weights for module_prices using the Acala node and recommended hardware.
@@ -64,6 +69,33 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(12_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_price_aggregation() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn check_price_sources(c: u32) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -77,4 +109,31 @@ impl WeightInfo for () {
 		(12_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32) -> Weight {
+		(4_000_000 as Weight)
+			.saturating_add((38_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_price_aggregation() -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn check_price_sources(c: u32) -> Weight {
+		(3_000_000 as Weight)
+			.saturating_add((41_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((10 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
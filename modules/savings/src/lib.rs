@@ -0,0 +1,301 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Savings Module
+//!
+//! ## Overview
+//!
+//! Savings module lets stable currency holders lock their stable currency
+//! and earn the savings rate set by governance. Interest is paid from the
+//! surplus pool of cdp treasury, which is funded by the stability fees of
+//! CDPs.
+//!
+//! Depositors receive savings currency as shares of the locked stable
+//! currency. The exchange rate between stable currency and savings currency
+//! increases as interest accrues, so the savings currency is an ordinary
+//! token that can be traded on DEX or used in EVM while still earning.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{log, pallet_prelude::*, traits::UnixTime, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, FixedPointNumber,
+};
+use support::{CDPTreasury, ExchangeRate, ExchangeRateProvider, Rate};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency for transfer stable currency and mint/burn savings currency
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// Stablecoin currency id
+		#[pallet::constant]
+		type GetStableCurrencyId: Get<CurrencyId>;
+
+		/// Savings currency id, the share token of locked stable currency
+		#[pallet::constant]
+		type GetSavingsCurrencyId: Get<CurrencyId>;
+
+		/// The default exchange rate of savings currency to stable currency
+		#[pallet::constant]
+		type DefaultExchangeRate: Get<ExchangeRate>;
+
+		/// The cap of the savings rate per second
+		#[pallet::constant]
+		type MaxSavingsRatePerSec: Get<Rate>;
+
+		/// CDP treasury whose surplus pool pays the interest
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// Time used for computing accrued interest.
+		type UnixTime: UnixTime;
+
+		/// The origin which may update the savings rate.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The savings module id, keep the locked stable currency.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The amount is zero or too small to be converted
+		InvalidAmount,
+		/// The savings rate exceeds the max savings rate
+		ExceedMaxSavingsRate,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Deposit stable currency and mint savings currency. \[who,
+		/// stable_amount, savings_amount\]
+		Deposited(T::AccountId, Balance, Balance),
+		/// Burn savings currency and withdraw stable currency. \[who,
+		/// savings_amount, stable_amount\]
+		Withdrawn(T::AccountId, Balance, Balance),
+		/// Interest paid from the surplus pool has been accrued. \[interest\]
+		InterestAccrued(Balance),
+		/// The savings rate per second has been updated. \[new_rate\]
+		SavingsRateUpdated(Rate),
+	}
+
+	/// The savings rate per second.
+	///
+	/// SavingsRatePerSec: Rate
+	#[pallet::storage]
+	#[pallet::getter(fn savings_rate_per_sec)]
+	pub type SavingsRatePerSec<T: Config> = StorageValue<_, Rate, ValueQuery>;
+
+	/// The total amount of stable currency owned by savings currency holders,
+	/// including the accrued interest.
+	///
+	/// TotalSavings: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_savings)]
+	pub type TotalSavings<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Timestamp in seconds of the last interest accrual
+	///
+	/// LastAccrualSecs: u64
+	#[pallet::storage]
+	#[pallet::getter(fn last_accrual_secs)]
+	pub type LastAccrualSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Accrue interest of savings at the beginning of each block.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// only after the block #1, `T::UnixTime::now()` will not report error.
+			// so accrue interest at the beginning of the block #2
+			if now > One::one() {
+				Self::accrue_interest(T::UnixTime::now().as_secs());
+			}
+			<T as Config>::WeightInfo::on_initialize()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock stable currency and mint savings currency at the current
+		/// exchange rate.
+		///
+		/// - `amount`: the amount of stable currency to lock.
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let savings_amount = Self::convert_stable_to_savings(amount)?;
+			ensure!(!savings_amount.is_zero(), Error::<T>::InvalidAmount);
+
+			T::Currency::transfer(T::GetStableCurrencyId::get(), &who, &Self::account_id(), amount)?;
+			T::Currency::deposit(T::GetSavingsCurrencyId::get(), &who, savings_amount)?;
+			TotalSavings::<T>::try_mutate(|total| -> DispatchResult {
+				*total = total.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Deposited(who, amount, savings_amount));
+			Ok(())
+		}
+
+		/// Burn savings currency and withdraw stable currency at the current
+		/// exchange rate.
+		///
+		/// - `savings_amount`: the amount of savings currency to burn.
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, #[pallet::compact] savings_amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let amount = Self::convert_savings_to_stable(savings_amount)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			T::Currency::withdraw(T::GetSavingsCurrencyId::get(), &who, savings_amount)?;
+			T::Currency::transfer(T::GetStableCurrencyId::get(), &Self::account_id(), &who, amount)?;
+			TotalSavings::<T>::mutate(|total| *total = total.saturating_sub(amount));
+
+			Self::deposit_event(Event::Withdrawn(who, savings_amount, amount));
+			Ok(())
+		}
+
+		/// Update the savings rate per second. Interest is accrued at the old
+		/// rate before the update.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `rate`: the new savings rate per second.
+		#[pallet::weight((<T as Config>::WeightInfo::set_savings_rate(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_savings_rate(origin: OriginFor<T>, rate: Rate) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(rate <= T::MaxSavingsRatePerSec::get(), Error::<T>::ExceedMaxSavingsRate);
+
+			Self::accrue_interest(T::UnixTime::now().as_secs());
+			SavingsRatePerSec::<T>::put(rate);
+			Self::deposit_event(Event::SavingsRateUpdated(rate));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// Calculate the amount of savings currency converted from stable
+	/// currency.
+	/// savings_amount = (savings_total_issuance / total_savings) * amount
+	pub fn convert_stable_to_savings(amount: Balance) -> Result<Balance, DispatchError> {
+		Self::get_exchange_rate()
+			.reciprocal()
+			.unwrap_or_else(|| T::DefaultExchangeRate::get().reciprocal().unwrap_or_default())
+			.checked_mul_int(amount)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Calculate the amount of stable currency converted from savings
+	/// currency.
+	/// amount = (total_savings / savings_total_issuance) * savings_amount
+	pub fn convert_savings_to_stable(savings_amount: Balance) -> Result<Balance, DispatchError> {
+		Self::get_exchange_rate()
+			.checked_mul_int(savings_amount)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Pay the interest since the last accrual from the surplus pool of cdp
+	/// treasury. The interest is limited by the surplus which is neither in
+	/// surplus auction nor needed to offset the debit pool, and the
+	/// exchange rate rises as the paid interest is added to total savings.
+	fn accrue_interest(now_secs: u64) {
+		let interval_secs = now_secs.saturating_sub(Self::last_accrual_secs());
+		let total_savings = Self::total_savings();
+		let rate_to_accrue = Self::savings_rate_per_sec()
+			.saturating_add(Rate::one())
+			.saturating_pow(interval_secs.unique_saturated_into())
+			.saturating_sub(Rate::one());
+
+		if !rate_to_accrue.is_zero() && !total_savings.is_zero() {
+			let interest = rate_to_accrue.saturating_mul_int(total_savings).min(
+				T::CDPTreasury::get_surplus_pool_not_in_auction().saturating_sub(T::CDPTreasury::get_debit_pool()),
+			);
+
+			if !interest.is_zero() {
+				match T::CDPTreasury::withdraw_surplus(&Self::account_id(), interest) {
+					Ok(_) => {
+						TotalSavings::<T>::put(total_savings.saturating_add(interest));
+						Self::deposit_event(Event::InterestAccrued(interest));
+					}
+					Err(e) => {
+						log::warn!(
+							target: "savings",
+							"withdraw_surplus: failed to withdraw surplus {:?}: {:?}. \
+							This is unexpected but should be safe",
+							interest, e
+						);
+					}
+				}
+			}
+		}
+
+		LastAccrualSecs::<T>::put(now_secs);
+	}
+}
+
+impl<T: Config> ExchangeRateProvider for Pallet<T> {
+	/// Calculate the exchange rate between savings currency and stable
+	/// currency.
+	/// returns ExchangeRate(stable : savings) = total_savings /
+	/// savings_total_issuance If the exchange rate cannot be calculated,
+	/// T::DefaultExchangeRate is used
+	fn get_exchange_rate() -> ExchangeRate {
+		let total_savings = Self::total_savings();
+		let savings_total_issuance = T::Currency::total_issuance(T::GetSavingsCurrencyId::get());
+		if total_savings.is_zero() || savings_total_issuance.is_zero() {
+			T::DefaultExchangeRate::get()
+		} else {
+			ExchangeRate::checked_from_rational(total_savings, savings_total_issuance)
+				.unwrap_or_else(T::DefaultExchangeRate::get)
+		}
+	}
+}
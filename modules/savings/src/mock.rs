@@ -0,0 +1,239 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the savings module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{Everything, Nothing},
+};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::{Amount, TokenSymbol};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchResult};
+use support::Ratio;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type Moment = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 10;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+pub const SAUSD: CurrencyId = CurrencyId::Token(TokenSymbol::SAUSD);
+
+mod savings {
+	pub use super::super::*;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+	pub const MinimumPeriod: Moment = 1000;
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = Moment;
+	type OnTimestampSet = ();
+	type MinimumPeriod = MinimumPeriod;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static MockDebitPool: Balance = 0;
+	pub static MockSurplusInAuction: Balance = 0;
+}
+
+pub struct MockCDPTreasury;
+impl CDPTreasury<AccountId> for MockCDPTreasury {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+
+	fn get_surplus_pool() -> Balance {
+		Tokens::free_balance(AUSD, &TREASURY)
+	}
+
+	fn get_surplus_pool_not_in_auction() -> Balance {
+		Self::get_surplus_pool().saturating_sub(MockSurplusInAuction::get())
+	}
+
+	fn get_debit_pool() -> Balance {
+		MockDebitPool::get()
+	}
+
+	fn get_total_collaterals(_: CurrencyId) -> Balance {
+		unimplemented!()
+	}
+
+	fn get_debit_proportion(_: Balance) -> Ratio {
+		unimplemented!()
+	}
+
+	fn on_system_debit(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn on_system_surplus(_: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn issue_debit(_: &AccountId, _: Balance, _: bool) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn burn_debit(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn deposit_surplus(_: &AccountId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_surplus(to: &AccountId, surplus: Balance) -> DispatchResult {
+		Tokens::transfer(AUSD, &TREASURY, to, surplus)
+	}
+
+	fn deposit_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn withdraw_collateral(_: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		unimplemented!()
+	}
+}
+
+ord_parameter_types! {
+	pub const Admin: AccountId = 11;
+}
+
+parameter_types! {
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const GetSavingsCurrencyId: CurrencyId = SAUSD;
+	pub DefaultExchangeRate: ExchangeRate = ExchangeRate::one();
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(1, 100);
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savm");
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type DefaultExchangeRate = DefaultExchangeRate;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type CDPTreasury = MockCDPTreasury;
+	type UnixTime = Timestamp;
+	type UpdateOrigin = EnsureSignedBy<Admin, AccountId>;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		SavingsModule: savings::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![(ALICE, AUSD, 1000), (BOB, AUSD, 1000), (TREASURY, AUSD, 100)],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		t.into()
+	}
+}
@@ -0,0 +1,180 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the savings module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn set_savings_rate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			SavingsModule::set_savings_rate(Origin::signed(ALICE), Rate::saturating_from_rational(1, 1000)),
+			BadOrigin
+		);
+		assert_noop!(
+			SavingsModule::set_savings_rate(Origin::signed(Admin::get()), Rate::saturating_from_rational(2, 100)),
+			Error::<Runtime>::ExceedMaxSavingsRate
+		);
+
+		assert_ok!(SavingsModule::set_savings_rate(
+			Origin::signed(Admin::get()),
+			Rate::saturating_from_rational(1, 1000)
+		));
+		System::assert_last_event(Event::SavingsModule(crate::Event::SavingsRateUpdated(
+			Rate::saturating_from_rational(1, 1000),
+		)));
+		assert_eq!(
+			SavingsModule::savings_rate_per_sec(),
+			Rate::saturating_from_rational(1, 1000)
+		);
+	});
+}
+
+#[test]
+fn deposit_and_withdraw_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			SavingsModule::deposit(Origin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidAmount
+		);
+
+		assert_ok!(SavingsModule::deposit(Origin::signed(ALICE), 100));
+		System::assert_last_event(Event::SavingsModule(crate::Event::Deposited(ALICE, 100, 100)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(SAUSD, &ALICE), 100);
+		assert_eq!(Tokens::free_balance(AUSD, &SavingsModule::account_id()), 100);
+		assert_eq!(SavingsModule::total_savings(), 100);
+
+		assert_noop!(
+			SavingsModule::withdraw(Origin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidAmount
+		);
+		assert_noop!(
+			SavingsModule::withdraw(Origin::signed(ALICE), 101),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(SavingsModule::withdraw(Origin::signed(ALICE), 40));
+		System::assert_last_event(Event::SavingsModule(crate::Event::Withdrawn(ALICE, 40, 40)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 940);
+		assert_eq!(Tokens::free_balance(SAUSD, &ALICE), 60);
+		assert_eq!(Tokens::free_balance(AUSD, &SavingsModule::account_id()), 60);
+		assert_eq!(SavingsModule::total_savings(), 60);
+	});
+}
+
+#[test]
+fn accrue_interest_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000);
+		assert_ok!(SavingsModule::set_savings_rate(
+			Origin::signed(Admin::get()),
+			Rate::saturating_from_rational(1, 100)
+		));
+		assert_eq!(SavingsModule::last_accrual_secs(), 1);
+		assert_ok!(SavingsModule::deposit(Origin::signed(ALICE), 100));
+
+		// the interest is limited by the surplus pool
+		System::set_block_number(2);
+		Timestamp::set_timestamp(101_000);
+		SavingsModule::on_initialize(2);
+		System::assert_last_event(Event::SavingsModule(crate::Event::InterestAccrued(100)));
+		assert_eq!(SavingsModule::last_accrual_secs(), 101);
+		assert_eq!(SavingsModule::total_savings(), 200);
+		assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 0);
+		assert_eq!(
+			SavingsModule::get_exchange_rate(),
+			ExchangeRate::saturating_from_integer(2)
+		);
+
+		// no interest is paid when the surplus pool is empty
+		System::set_block_number(3);
+		Timestamp::set_timestamp(102_000);
+		SavingsModule::on_initialize(3);
+		assert_eq!(SavingsModule::last_accrual_secs(), 102);
+		assert_eq!(SavingsModule::total_savings(), 200);
+
+		// deposit and withdraw at the new exchange rate
+		assert_ok!(SavingsModule::deposit(Origin::signed(BOB), 100));
+		System::assert_last_event(Event::SavingsModule(crate::Event::Deposited(BOB, 100, 50)));
+		assert_eq!(Tokens::free_balance(SAUSD, &BOB), 50);
+		assert_eq!(SavingsModule::total_savings(), 300);
+
+		assert_ok!(SavingsModule::withdraw(Origin::signed(ALICE), 100));
+		System::assert_last_event(Event::SavingsModule(crate::Event::Withdrawn(ALICE, 100, 200)));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1100);
+		assert_eq!(SavingsModule::total_savings(), 100);
+	});
+}
+
+#[test]
+fn accrue_interest_keep_debit_and_surplus_in_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000);
+		assert_ok!(SavingsModule::set_savings_rate(
+			Origin::signed(Admin::get()),
+			Rate::saturating_from_rational(1, 100)
+		));
+		assert_ok!(SavingsModule::deposit(Origin::signed(ALICE), 100));
+		MockDebitPool::set(30);
+		MockSurplusInAuction::set(20);
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(101_000);
+		SavingsModule::on_initialize(2);
+		System::assert_last_event(Event::SavingsModule(crate::Event::InterestAccrued(50)));
+		assert_eq!(SavingsModule::total_savings(), 150);
+		assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 50);
+
+		// no interest is paid when the surplus only covers the debit and the auction
+		System::set_block_number(3);
+		Timestamp::set_timestamp(201_000);
+		SavingsModule::on_initialize(3);
+		assert_eq!(SavingsModule::total_savings(), 150);
+		assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 50);
+	});
+}
+
+#[test]
+fn accrue_interest_without_savings_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000);
+		assert_ok!(SavingsModule::set_savings_rate(
+			Origin::signed(Admin::get()),
+			Rate::saturating_from_rational(1, 100)
+		));
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(101_000);
+		SavingsModule::on_initialize(2);
+		assert_eq!(SavingsModule::last_accrual_secs(), 101);
+		assert_eq!(SavingsModule::total_savings(), 0);
+		assert_eq!(Tokens::free_balance(AUSD, &TREASURY), 100);
+	});
+}
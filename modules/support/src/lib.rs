@@ -61,7 +61,22 @@ pub trait RiskManager<AccountId, CurrencyId, Balance, DebitBalance> {
 		check_required_ratio: bool,
 	) -> DispatchResult;
 
-	fn check_debit_cap(currency_id: CurrencyId, total_debit_balance: DebitBalance) -> DispatchResult;
+	/// Check the debit caps when the debit of the position of `who` is increased by
+	/// `debit_increase`, the issuance is recorded to enforce the issuance rate limits.
+	fn check_debit_cap(
+		who: &AccountId,
+		currency_id: CurrencyId,
+		total_debit_balance: DebitBalance,
+		debit_increase: DebitBalance,
+	) -> DispatchResult;
+
+	/// Check the debit ceiling of the position of `who`, for the debit moved into the position
+	/// without new issuance, e.g. the transferred loan.
+	fn check_account_debit_ceiling(who: &AccountId, currency_id: CurrencyId) -> DispatchResult;
+
+	/// Check the positions of all collateral types of `who` as a whole, for the account which
+	/// uses cross-collateral position.
+	fn check_cross_collateral_position_valid(who: &AccountId, check_required_ratio: bool) -> DispatchResult;
 }
 
 impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountId, CurrencyId, Balance, DebitBalance>
@@ -80,7 +95,20 @@ impl<AccountId, CurrencyId, Balance: Default, DebitBalance> RiskManager<AccountI
 		Ok(())
 	}
 
-	fn check_debit_cap(_currency_id: CurrencyId, _total_debit_balance: DebitBalance) -> DispatchResult {
+	fn check_debit_cap(
+		_who: &AccountId,
+		_currency_id: CurrencyId,
+		_total_debit_balance: DebitBalance,
+		_debit_increase: DebitBalance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_account_debit_ceiling(_who: &AccountId, _currency_id: CurrencyId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_cross_collateral_position_valid(_who: &AccountId, _check_required_ratio: bool) -> DispatchResult {
 		Ok(())
 	}
 }
@@ -96,9 +124,13 @@ pub trait AuctionManager<AccountId> {
 		amount: Self::Balance,
 		target: Self::Balance,
 	) -> DispatchResult;
+	fn new_debit_auction(amount: Self::Balance, fix: Self::Balance) -> DispatchResult;
+	fn new_surplus_auction(amount: Self::Balance) -> DispatchResult;
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult;
 	fn get_total_collateral_in_auction(id: Self::CurrencyId) -> Self::Balance;
 	fn get_total_target_in_auction() -> Self::Balance;
+	fn get_total_debit_in_auction() -> Self::Balance;
+	fn get_total_surplus_in_auction() -> Self::Balance;
 }
 
 pub trait DEXManager<AccountId, CurrencyId, Balance> {
@@ -110,6 +142,22 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 
 	fn get_swap_supply_amount(path: &[CurrencyId], target_amount: Balance) -> Option<Balance>;
 
+	fn get_best_swap_path_with_exact_supply(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>>;
+
+	fn get_best_swap_path_with_exact_target(
+		supply_currency_id: CurrencyId,
+		target_currency_id: CurrencyId,
+		target_amount: Balance,
+	) -> Option<Vec<CurrencyId>>;
+
+	/// Get the time-weighted average price of `currency_id_a` in `currency_id_b` over
+	/// `window` blocks.
+	fn get_twap(currency_id_a: CurrencyId, currency_id_b: CurrencyId, window: u32) -> Option<ExchangeRate>;
+
 	fn swap_with_exact_supply(
 		who: &AccountId,
 		path: &[CurrencyId],
@@ -124,6 +172,20 @@ pub trait DEXManager<AccountId, CurrencyId, Balance> {
 		max_supply_amount: Balance,
 	) -> sp_std::result::Result<Balance, DispatchError>;
 
+	/// Borrow `borrow_amount` of `borrow_currency_id` from the liquidity pool of
+	/// (`borrow_currency_id`, `paired_currency_id`), invoke `callback`, then repay
+	/// in `repay_currency_id` with fee. Returns the actual repay amount.
+	#[allow(clippy::too_many_arguments)]
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		who: &AccountId,
+		borrow_currency_id: CurrencyId,
+		paired_currency_id: CurrencyId,
+		borrow_amount: Balance,
+		repay_currency_id: CurrencyId,
+		max_repay_amount: Balance,
+		callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError>;
+
 	fn add_liquidity(
 		who: &AccountId,
 		currency_id_a: CurrencyId,
@@ -165,6 +227,26 @@ where
 		Some(Default::default())
 	}
 
+	fn get_best_swap_path_with_exact_supply(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_supply_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		Some(Default::default())
+	}
+
+	fn get_best_swap_path_with_exact_target(
+		_supply_currency_id: CurrencyId,
+		_target_currency_id: CurrencyId,
+		_target_amount: Balance,
+	) -> Option<Vec<CurrencyId>> {
+		Some(Default::default())
+	}
+
+	fn get_twap(_currency_id_a: CurrencyId, _currency_id_b: CurrencyId, _window: u32) -> Option<ExchangeRate> {
+		Some(Default::default())
+	}
+
 	fn swap_with_exact_supply(
 		_who: &AccountId,
 		_path: &[CurrencyId],
@@ -183,6 +265,18 @@ where
 		Ok(Default::default())
 	}
 
+	fn flash_swap<F: FnOnce() -> DispatchResult>(
+		_who: &AccountId,
+		_borrow_currency_id: CurrencyId,
+		_paired_currency_id: CurrencyId,
+		_borrow_amount: Balance,
+		_repay_currency_id: CurrencyId,
+		_max_repay_amount: Balance,
+		_callback: F,
+	) -> sp_std::result::Result<Balance, DispatchError> {
+		Ok(Default::default())
+	}
+
 	fn add_liquidity(
 		_who: &AccountId,
 		_currency_id_a: CurrencyId,
@@ -216,6 +310,9 @@ pub trait CDPTreasury<AccountId> {
 	/// get surplus amount of cdp treasury
 	fn get_surplus_pool() -> Self::Balance;
 
+	/// get surplus amount of cdp treasury which is not in surplus auction
+	fn get_surplus_pool_not_in_auction() -> Self::Balance;
+
 	/// get debit amount of cdp treasury
 	fn get_debit_pool() -> Self::Balance;
 
@@ -242,6 +339,9 @@ pub trait CDPTreasury<AccountId> {
 	/// deposit surplus(stable currency) to cdp treasury by `from`
 	fn deposit_surplus(from: &AccountId, surplus: Self::Balance) -> DispatchResult;
 
+	/// withdraw surplus(stable currency) of cdp treasury to `to`
+	fn withdraw_surplus(to: &AccountId, surplus: Self::Balance) -> DispatchResult;
+
 	/// deposit collateral assets to cdp treasury by `who`
 	fn deposit_collateral(from: &AccountId, currency_id: Self::CurrencyId, amount: Self::Balance) -> DispatchResult;
 
@@ -275,6 +375,19 @@ pub trait CDPTreasuryExtended<AccountId>: CDPTreasury<AccountId> {
 	) -> DispatchResult;
 }
 
+/// A strategy to liquidate the collateral confiscated from unsafe CDP.
+pub trait LiquidationStrategy<AccountId, CurrencyId, Balance> {
+	/// Liquidate `amount` of `currency_id` collateral held by cdp treasury to
+	/// get `target_stable_amount` of stable currency for cdp treasury, the
+	/// remaining collateral is refunded to `refund_receiver`.
+	fn liquidate(
+		refund_receiver: &AccountId,
+		currency_id: CurrencyId,
+		amount: Balance,
+		target_stable_amount: Balance,
+	) -> DispatchResult;
+}
+
 pub trait PriceProvider<CurrencyId> {
 	fn get_price(currency_id: CurrencyId) -> Option<Price>;
 	fn get_relative_price(base: CurrencyId, quote: CurrencyId) -> Option<Price> {
@@ -403,6 +516,43 @@ impl<AccountId, Balance: Default> EVMBridge<AccountId, Balance> for () {
 	fn set_origin(_origin: AccountId) {}
 }
 
+/// The gas limit of calling the liquidation contract in EVM
+pub const LIQUIDATION_CONTRACT_GAS_LIMIT: u64 = 2_100_000;
+
+/// An abstraction of the liquidation contract in EVM
+pub trait LiquidationEvmBridge<Balance> {
+	/// Execute liquidate(address, address, uint256, uint256) of the
+	/// liquidation contract to ask it to repay at least `min_repayment` of
+	/// stable currency to `repay_dest` for `amount` of `collateral`
+	fn liquidate(
+		context: InvokeContext,
+		collateral: EvmAddress,
+		repay_dest: EvmAddress,
+		amount: Balance,
+		min_repayment: Balance,
+	) -> DispatchResult;
+	/// Execute onCollateralTransfer(address, uint256) of the liquidation
+	/// contract to notify it that `amount` of `collateral` has been transferred
+	/// to it
+	fn on_collateral_transfer(context: InvokeContext, collateral: EvmAddress, amount: Balance) -> DispatchResult;
+}
+
+#[cfg(feature = "std")]
+impl<Balance> LiquidationEvmBridge<Balance> for () {
+	fn liquidate(
+		_context: InvokeContext,
+		_collateral: EvmAddress,
+		_repay_dest: EvmAddress,
+		_amount: Balance,
+		_min_repayment: Balance,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented liquidation evm bridge"))
+	}
+	fn on_collateral_transfer(_context: InvokeContext, _collateral: EvmAddress, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Other("unimplemented liquidation evm bridge"))
+	}
+}
+
 /// An abstraction of EVMStateRentTrait
 pub trait EVMStateRentTrait<AccountId, Balance> {
 	/// Query the constants `NewContractExtraBytes` value from evm module.
@@ -115,8 +115,9 @@ pub use runtime_common::{
 	FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance, GeneralCouncilMembershipInstance,
 	HomaCouncilInstance, HomaCouncilMembershipInstance, OffchainSolutionWeightLimit, OperatorMembershipInstanceAcala,
 	OperatorMembershipInstanceBand, Price, ProxyType, Rate, Ratio, RelayChainBlockNumberProvider,
-	RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
+	RelayChainStorageRootProvider, RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights,
+	SystemContractsFilter, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA,
+	AUSD, DOT, LDOT, RENBTC, SAUSD,
 };
 
 mod authority;
@@ -157,6 +158,8 @@ parameter_types! {
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savm");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
@@ -172,6 +175,8 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 	vec![
 		LoansPalletId::get().into_account(),
 		CDPTreasuryPalletId::get().into_account(),
+		CDPEnginePalletId::get().into_account(),
+		SavingsPalletId::get().into_account(),
 		CollatorPotId::get().into_account(),
 		DEXPalletId::get().into_account(),
 		HomaTreasuryPalletId::get().into_account(),
@@ -739,11 +744,13 @@ parameter_type_with_key! {
 				TokenSymbol::AUSD => 10 * cent(*currency_id),
 				TokenSymbol::DOT => cent(*currency_id),
 				TokenSymbol::LDOT => 5 * cent(*currency_id),
+				TokenSymbol::SAUSD => cent(*currency_id),
 
 				TokenSymbol::KAR |
 				TokenSymbol::KUSD |
 				TokenSymbol::KSM |
 				TokenSymbol::LKSM |
+				TokenSymbol::SKUSD |
 				TokenSymbol::RENBTC |
 				TokenSymbol::BNC |
 				TokenSymbol::VSKSM |
@@ -797,9 +804,15 @@ parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 }
 
+parameter_types! {
+	pub const MaxPriceAge: Moment = 1000 * 60 * 30; // 30 mins
+	pub const FallbackTwapWindow: u32 = HOURS;
+}
+
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
+	type OracleInstances = module_prices::OracleInstance<AcalaOracle>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -809,6 +822,9 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type UnixTime = Timestamp;
+	type MaxPriceAge = MaxPriceAge;
+	type FallbackTwapWindow = FallbackTwapWindow;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -899,6 +915,9 @@ parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(20, 100);
+	pub DutchAuctionPriceDecayPerBlock: Rate = Rate::saturating_from_rational(5, 1000);
+	pub DutchAuctionMinimumPriceRatio: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![AUSD],
 		vec![DOT, AUSD],
@@ -919,6 +938,11 @@ impl module_auction_manager::Config for Runtime {
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecayPerBlock = DutchAuctionPriceDecayPerBlock;
+	type DutchAuctionMinimumPriceRatio = DutchAuctionMinimumPriceRatio;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -997,11 +1021,18 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(8, 100);
 	pub MinimumDebitValue: Balance = 20 * dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub const StabilityFeeAdjustmentPeriod: BlockNumber = DAYS;
+	pub const IssuanceEpochPeriod: BlockNumber = DAYS;
+	pub LiquidationContractsAccount: AccountId = CDPEnginePalletId::get().into_account();
+	pub const MaxLiquidationContracts: u32 = 10;
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type DEX = Dex;
+	type StabilityFeeAdjustmentPeriod = StabilityFeeAdjustmentPeriod;
+	type IssuanceEpochPeriod = IssuanceEpochPeriod;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1015,6 +1046,12 @@ impl module_cdp_engine::Config for Runtime {
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type LiquidationEvmBridge = EVMBridge;
+	type EvmAddressMapping = EvmAddressMapping<Runtime>;
+	// the dedicated account pays the storage of liquidation contracts, it should be funded with native token
+	type LiquidationContractsAccount = LiquidationContractsAccount;
+	type GasToWeight = GasToWeight;
+	type MaxLiquidationContracts = MaxLiquidationContracts;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
@@ -1041,19 +1078,32 @@ impl module_emergency_shutdown::Config for Runtime {
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
+	pub DEXProtocolFeeShare: Ratio = Ratio::zero();
+	// the protocol fee is sent to the treasury instead of the CDP treasury, where it would be
+	// counted as collateral
+	pub DEXProtocolFeeReceiver: AccountId = AcalaTreasuryAccount::get();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxPriceObservations: u32 = 144;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 7 * DAYS;
 }
 
 impl module_dex::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = DEXProtocolFeeReceiver;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 parameter_types! {
@@ -1071,9 +1121,30 @@ impl module_cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const GetSavingsCurrencyId: CurrencyId = SAUSD;
+	pub DefaultSavingsExchangeRate: ExchangeRate = ExchangeRate::saturating_from_integer(1);
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(5, 1_000_000_000);
+}
+
+impl module_savings::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type DefaultExchangeRate = DefaultSavingsExchangeRate;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type CDPTreasury = CdpTreasury;
+	type UnixTime = Timestamp;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = weights::module_savings::WeightInfo<Runtime>;
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
@@ -1298,7 +1369,7 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	Runtime,
 >;
 pub type DexPrecompile =
-	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex, EVM>;
 
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
@@ -1543,6 +1614,10 @@ parameter_types! {
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
 	pub RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 14;
+	pub const MaxRedeemRequests: u32 = 1_000;
+	pub MaxStakingLedgerDeviation: Permill = Permill::from_percent(2);
+	pub HomaTreasuryAccount: AccountId = HomaTreasuryPalletId::get().into_account();
+	pub const RelayChainEraLength: BlockNumber = 14_400; // 24 hours of 6 seconds relaychain blocks
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
 	pub SubAccountIndex: u16 = RelayChainSubAccountId::HomaLite as u16;
 	pub const XcmUnbondFee: Balance = 600_000_000; // TODO identify unbond fee
@@ -1570,6 +1645,16 @@ impl module_homa_lite::Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type DEX = Dex;
+	type MaxRedeemRequests = MaxRedeemRequests;
+	type SovereignSubAccount = RelayChainSovereignSubAccountId;
+	type RelayChainStorageRoot = RelayChainStorageRootProvider<Runtime>;
+	type MaxStakingLedgerDeviation = MaxStakingLedgerDeviation;
+	type EraLength = RelayChainEraLength;
+	type TreasuryAccount = AcalaTreasuryAccount;
+	type InsuranceAccount = HomaTreasuryAccount;
+	// There is no validator guarantee on Acala, slashes are covered by the insurance account only.
+	type ValidatorGuarantees = ();
 }
 
 pub type LocalAssetTransactor = MultiCurrencyAdapter<
@@ -1690,8 +1775,10 @@ impl orml_xcm::Config for Runtime {
 pub struct OnRuntimeUpgrade;
 impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
 	fn on_runtime_upgrade() -> u64 {
-		// no migration
-		0
+		module_cdp_engine::migrations::v1::migrate::<Runtime>()
+			.saturating_add(module_auction_manager::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_homa_lite::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_dex::migrations::v1::migrate::<Runtime>())
 	}
 }
 
@@ -1795,6 +1882,7 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury::{Pallet, Storage, Call, Config, Event<T>} = 103,
 		CdpEngine: module_cdp_engine::{Pallet, Storage, Call, Event<T>, Config, ValidateUnsigned} = 104,
 		EmergencyShutdown: module_emergency_shutdown::{Pallet, Storage, Call, Event<T>} = 105,
+		Savings: module_savings::{Pallet, Storage, Call, Event<T>} = 106,
 
 		// Homa
 		HomaLite: module_homa_lite::{Pallet, Call, Storage, Event<T>} = 115,
@@ -1986,6 +2074,102 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_swap_quote_with_exact_supply(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapQuote<Balance>> {
+			Dex::get_swap_quote_with_exact_supply(path, supply_amount)
+		}
+
+		fn get_swap_quote_with_exact_target(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapQuote<Balance>> {
+			Dex::get_swap_quote_with_exact_target(path, target_amount)
+		}
+
+		fn get_trading_pairs() -> Vec<module_dex_rpc_runtime_api::TradingPairInfo<Balance>> {
+			Dex::get_trading_pairs()
+		}
+
+		fn get_liquidity_shares(account: AccountId) -> Vec<module_dex_rpc_runtime_api::LiquidityShareInfo<Balance>> {
+			// the dex share deposited to incentives is still owned by the account.
+			Dex::get_liquidity_shares(&account, |lp_currency_id| {
+				orml_rewards::Pallet::<Runtime>::shares_and_withdrawn_rewards(
+					&module_incentives::PoolId::Dex(lp_currency_id),
+					&account,
+				)
+				.0
+			})
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_position_health(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_position_health(account, currency_id)
+		}
+
+		fn get_account_positions_health(
+			account: AccountId,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_account_positions_health(account)
+		}
+
+		fn get_collateral_positions_health(
+			currency_id: CurrencyId,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_collateral_positions_health(currency_id, start, limit)
+		}
+
+		fn get_positions_nearest_to_liquidation(
+			currency_id: CurrencyId,
+			start: u32,
+			limit: u32,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_positions_health_nearest_to_liquidation(currency_id, start, limit)
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+	> for Runtime {
+		fn get_redeem_queue_depth() -> module_homa_lite_rpc_runtime_api::RedeemQueueDepth<Balance> {
+			let (requests, liquid_amount) = HomaLite::redeem_queue_depth();
+			module_homa_lite_rpc_runtime_api::RedeemQueueDepth { requests, liquid_amount }
+		}
+
+		fn get_redeem_request_status(
+			who: AccountId,
+		) -> Option<module_homa_lite_rpc_runtime_api::RedeemRequestStatus<Balance, BlockNumber>> {
+			let (liquid_amount, additional_fee) = HomaLite::redeem_requests(&who)?;
+			let (position, liquid_amount_ahead) = HomaLite::redeem_request_position(&who)?;
+			Some(module_homa_lite_rpc_runtime_api::RedeemRequestStatus {
+				liquid_amount,
+				additional_fee,
+				position,
+				liquid_amount_ahead,
+				expected_wait: HomaLite::estimate_redeem_wait(liquid_amount_ahead.saturating_add(liquid_amount)),
+			})
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -52,4 +52,13 @@ impl<T: frame_system::Config> module_auction_manager::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(11 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn set_collateral_auction_mode() -> Weight {
+		(26_114_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn take_collateral_auction() -> Weight {
+		(182_450_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
 }
@@ -78,4 +78,45 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn set_cross_collateral_liquidation_priority() -> Weight {
+		(22_015_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		(22_781_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn adjust_global_interest_rate() -> Weight {
+		(72_348_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_liquidation_strategies() -> Weight {
+		(24_103_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn register_liquidation_contract() -> Weight {
+		(28_655_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_liquidation_contract() -> Weight {
+		(27_731_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_keeper_reward_params() -> Weight {
+		(22_813_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn liquidate_by_keeper() -> Weight {
+		(398_264_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(28 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	fn set_debit_issuance_limits() -> Weight {
+		(29_142_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -61,4 +61,14 @@ impl<T: frame_system::Config> module_cdp_treasury::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn on_finalize(c: u32) -> Weight {
+		(38_466_000 as Weight)
+			.saturating_add((62_480_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_debit_and_surplus_handle_params() -> Weight {
+		(22_315_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
 }
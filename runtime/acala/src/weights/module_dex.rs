@@ -49,7 +49,7 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	fn enable_trading_pair() -> Weight {
 		(25_348_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	fn disable_trading_pair() -> Weight {
@@ -72,6 +72,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
 			.saturating_add(T::DbWeight::get().writes(6 as Weight))
 	}
+	fn abort_provisioning() -> Weight {
+		(32_846_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn refund_provision() -> Weight {
+		(108_325_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 	fn add_provision() -> Weight {
 		(132_852_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(5 as Weight))
@@ -116,4 +126,35 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	fn swap_with_exact_supply_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(418_936_000 as Weight)
+			.saturating_add((91_027_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((82_304_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn swap_with_exact_target_by_best_paths(u: u32, p: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(431_584_000 as Weight)
+			.saturating_add((92_613_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((84_116_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(u as Weight)))
+	}
+	fn flash_swap() -> Weight {
+		(172_684_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	fn set_exchange_fee() -> Weight {
+		(21_386_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
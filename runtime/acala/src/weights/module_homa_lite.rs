@@ -47,19 +47,21 @@ use sp_std::marker::PhantomData;
 /// Weight functions for module_homa_lite.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
-	fn mint() -> Weight {
+	fn mint(n: u32) -> Weight {
 		(134_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(14 as Weight))
 			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
-	fn mint_for_requests() -> Weight {
+	fn mint_for_requests(n: u32) -> Weight {
 		(342_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(30 as Weight))
 			.saturating_add(T::DbWeight::get().writes(21 as Weight))
 	}
 	fn set_total_staking_currency() -> Weight {
 		(10_000_000 as Weight)
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	fn adjust_total_staking_currency() -> Weight {
 		(12_000_000 as Weight)
@@ -79,8 +81,9 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 		(10_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
-	fn request_redeem() -> Weight {
+	fn request_redeem(n: u32) -> Weight {
 		(74_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
@@ -101,4 +104,35 @@ impl<T: frame_system::Config> module_homa_lite::WeightInfo for WeightInfo<T> {
 		(21_316_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn redeem_by_dex(n: u32) -> Weight {
+		(168_000_000 as Weight)
+			.saturating_add((40_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	fn update_total_staking_currency_from_proof(n: u32) -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_reward_rates() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn report_slash(n: u32, ) -> Weight {
+		// Estimated from the storage accesses, not generated by the benchmark CLI yet.
+		(41_000_000 as Weight)
+			.saturating_add((36_000_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(n as Weight)))
+	}
 }
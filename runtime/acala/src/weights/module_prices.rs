@@ -56,4 +56,31 @@ impl<T: frame_system::Config> module_prices::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(23_417_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn unfreeze_price() -> Weight {
+		(20_985_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn on_initialize(c: u32) -> Weight {
+		(3_912_000 as Weight)
+			.saturating_add((40_265_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((9 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	fn set_price_aggregation() -> Weight {
+		(22_764_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn check_price_sources(c: u32) -> Weight {
+		(3_105_000 as Weight)
+			.saturating_add((42_871_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
 }
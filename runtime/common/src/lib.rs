@@ -23,7 +23,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	parameter_types,
-	traits::Contains,
+	traits::{Contains, Get},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_PER_MILLIS},
 		DispatchClass, Weight,
@@ -38,7 +38,7 @@ use primitives::{
 use scale_info::TypeInfo;
 use sp_core::{
 	u32_trait::{_1, _2, _3, _4},
-	H160,
+	H160, H256,
 };
 use sp_runtime::{
 	traits::{BlockNumberProvider, Convert},
@@ -56,7 +56,7 @@ pub use precompile::{
 	StateRentPrecompile,
 };
 pub use primitives::{
-	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KSM, KUSD, LDOT, LKSM, RENBTC, VSKSM},
+	currency::{TokenInfo, ACA, AUSD, BNC, DOT, KAR, KSM, KUSD, LDOT, LKSM, RENBTC, SAUSD, SKUSD, VSKSM},
 	AccountId,
 };
 
@@ -69,6 +69,7 @@ parameter_types! {
 	pub const CdpEngineUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;      // 50%
 	pub const AuctionManagerUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 5; // 20%
 	pub const RenvmBridgeUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 10;   // 10%
+	pub const DexOrdersUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 20;     // 5%
 }
 
 /// Check if the given `address` is a system contract.
@@ -182,6 +183,14 @@ impl<T: cumulus_pallet_parachain_system::Config> BlockNumberProvider for RelayCh
 	}
 }
 
+pub struct RelayChainStorageRootProvider<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: cumulus_pallet_parachain_system::Config> Get<Option<H256>> for RelayChainStorageRootProvider<T> {
+	fn get() -> Option<H256> {
+		cumulus_pallet_parachain_system::Pallet::<T>::validation_data().map(|d| d.relay_parent_storage_root)
+	}
+}
+
 pub type GeneralCouncilInstance = pallet_collective::Instance1;
 pub type FinancialCouncilInstance = pallet_collective::Instance2;
 pub type HomaCouncilInstance = pallet_collective::Instance3;
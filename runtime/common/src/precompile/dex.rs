@@ -20,10 +20,13 @@ use super::input::{Input, InputT, Output};
 use crate::precompile::PrecompileOutput;
 use frame_support::log;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
-use module_support::{AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager};
+use module_support::{
+	AddressMapping as AddressMappingT, CurrencyIdMapping as CurrencyIdMappingT, DEXManager, ExecutionMode,
+	InvokeContext, EVM as EVMT,
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use primitives::{Balance, CurrencyId};
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{DispatchError, FixedPointNumber, RuntimeDebug};
 use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 
 /// The `DEX` impl precompile.
@@ -35,10 +38,18 @@ use sp_std::{fmt::Debug, marker::PhantomData, prelude::*, result};
 /// - Get liquidity. Rest `input` bytes: `currency_id_a`, `currency_id_b`.
 /// - Swap with exact supply. Rest `input` bytes: `who`, `currency_id_a`, `currency_id_b`,
 ///   `supply_amount`, `min_target_amount`.
-pub struct DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex>(
-	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Dex)>,
+/// - Get TWAP. Rest `input` bytes: `currency_id_a`, `currency_id_b`, `window`.
+/// - Flash swap. Rest `input` bytes: `who`, `borrow_currency_id`, `paired_currency_id`,
+///   `borrow_amount`, `repay_currency_id`, `max_repay_amount`, `data`. The contract `who` is called
+///   with `data` after the borrowed amount is received, the gas used by the call is charged to the
+///   caller and capped by `FLASH_SWAP_CALLBACK_MAX_GAS`.
+pub struct DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM>(
+	PhantomData<(AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM)>,
 );
 
+/// The maximum gas that the contract call of flash swap can use.
+pub const FLASH_SWAP_CALLBACK_MAX_GAS: u64 = 2_100_000;
+
 #[module_evm_utiltity_macro::generate_function_selector]
 #[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
 #[repr(u32)]
@@ -47,24 +58,27 @@ pub enum Action {
 	GetLiquidityTokenAddress = "getLiquidityTokenAddress(address,address)",
 	GetSwapTargetAmount = "getSwapTargetAmount(address[],uint256)",
 	GetSwapSupplyAmount = "getSwapSupplyAmount(address[],uint256)",
+	GetTwap = "getTwap(address,address,uint256)",
 	SwapWithExactSupply = "swapWithExactSupply(address,address[],uint256,uint256)",
 	SwapWithExactTarget = "swapWithExactTarget(address,address[],uint256,uint256)",
 	AddLiquidity = "addLiquidity(address,address,address,uint256,uint256,uint256)",
 	RemoveLiquidity = "removeLiquidity(address,address,address,uint256,uint256,uint256)",
+	FlashSwap = "flashSwap(address,address,address,uint256,address,uint256,bytes)",
 }
 
-impl<AccountId, AddressMapping, CurrencyIdMapping, Dex> Precompile
-	for DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex>
+impl<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM> Precompile
+	for DexPrecompile<AccountId, AddressMapping, CurrencyIdMapping, Dex, EVM>
 where
 	AccountId: Debug + Clone,
 	AddressMapping: AddressMappingT<AccountId>,
 	CurrencyIdMapping: CurrencyIdMappingT,
 	Dex: DEXManager<AccountId, CurrencyId, Balance>,
+	EVM: EVMT<AccountId>,
 {
 	fn execute(
 		input: &[u8],
-		_target_gas: Option<u64>,
-		_context: &Context,
+		target_gas: Option<u64>,
+		context: &Context,
 	) -> result::Result<PrecompileOutput, ExitError> {
 		let input = Input::<Action, AccountId, AddressMapping, CurrencyIdMapping>::new(input);
 
@@ -156,6 +170,26 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::GetTwap => {
+				let currency_id_a = input.currency_id_at(1)?;
+				let currency_id_b = input.currency_id_at(2)?;
+				let window = input.u32_at(3)?;
+				log::debug!(
+					target: "evm",
+					"dex: get_twap currency_id_a: {:?}, currency_id_b: {:?}, window: {:?}",
+					currency_id_a, currency_id_b, window
+				);
+
+				let value = Dex::get_twap(currency_id_a, currency_id_b, window)
+					.ok_or_else(|| ExitError::Other("Dex get_twap failed".into()))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: 0,
+					output: Output::default().encode_u128(value.into_inner()),
+					logs: Default::default(),
+				})
+			}
 			Action::SwapWithExactSupply => {
 				let who = input.account_id_at(1)?;
 				// solidity abi enocde array will add an offset at input[2]
@@ -284,6 +318,69 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::FlashSwap => {
+				let who = input.account_id_at(1)?;
+				let who_address = input.evm_address_at(1)?;
+				let borrow_currency_id = input.currency_id_at(2)?;
+				let paired_currency_id = input.currency_id_at(3)?;
+				let borrow_amount = input.balance_at(4)?;
+				let repay_currency_id = input.currency_id_at(5)?;
+				let max_repay_amount = input.balance_at(6)?;
+				// solidity abi enocde bytes will add an offset at input[7]
+				let data_len = input.u32_at(8)?;
+				let data = input.bytes_at(9, data_len as usize)?;
+
+				log::debug!(
+					target: "evm",
+					"dex: flash_swap who: {:?}, borrow_currency_id: {:?}, paired_currency_id: {:?}, borrow_amount: {:?}, repay_currency_id: {:?}, max_repay_amount: {:?}, data: {:?}",
+					who, borrow_currency_id, paired_currency_id, borrow_amount, repay_currency_id, max_repay_amount, data,
+				);
+
+				// the callback can use at most the gas left to this precompile
+				let gas_limit = target_gas
+					.unwrap_or(FLASH_SWAP_CALLBACK_MAX_GAS)
+					.min(FLASH_SWAP_CALLBACK_MAX_GAS);
+				let mut used_gas: u64 = 0;
+				let value = Dex::flash_swap(
+					&who,
+					borrow_currency_id,
+					paired_currency_id,
+					borrow_amount,
+					repay_currency_id,
+					max_repay_amount,
+					|| {
+						let info = EVM::execute(
+							InvokeContext {
+								contract: who_address,
+								sender: context.caller,
+								origin: who_address,
+							},
+							data,
+							Default::default(),
+							gas_limit,
+							1_000,
+							ExecutionMode::Execute,
+						)?;
+						used_gas = info.used_gas.low_u64();
+						if info.exit_reason.is_succeed() {
+							Ok(())
+						} else {
+							Err(DispatchError::Other("flash swap callback failed"))
+						}
+					},
+				)
+				.map_err(|e| {
+					let err_msg: &str = e.into();
+					ExitError::Other(err_msg.into())
+				})?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: used_gas,
+					output: Output::default().encode_u128(value),
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
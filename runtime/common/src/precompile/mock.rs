@@ -326,7 +326,11 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 100);
+	pub DEXProtocolFeeShare: Ratio = Default::default();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10;
+	pub const MaxPriceObservations: u32 = 10;
+	pub const ExtendedProvisioningBlocks: BlockNumber = 10;
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 }
 
@@ -334,12 +338,18 @@ impl module_dex::Config for Test {
 	type Event = Event;
 	type Currency = Tokens;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = TreasuryAccount;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
 	type WeightInfo = ();
 	type DEXIncentives = MockDEXIncentives;
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
 }
 
 pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Test, Balances, Amount, BlockNumber>;
@@ -368,7 +378,8 @@ pub type ScheduleCallPrecompile = crate::ScheduleCallPrecompile<
 	OriginCaller,
 	Test,
 >;
-pub type DexPrecompile = crate::DexPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, DexModule>;
+pub type DexPrecompile =
+	crate::DexPrecompile<AccountId, MockAddressMapping, EvmCurrencyIdMapping, DexModule, ModuleEVM>;
 
 parameter_types! {
 	pub NetworkContractSource: H160 = alice_evm_addr();
@@ -433,6 +444,8 @@ parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
+	pub const MaxPriceAge: u64 = 3_600_000;
+	pub const FallbackTwapWindow: u32 = 10;
 }
 
 ord_parameter_types! {
@@ -442,6 +455,7 @@ ord_parameter_types! {
 impl module_prices::Config for Test {
 	type Event = Event;
 	type Source = Oracle;
+	type OracleInstances = module_prices::OracleInstance<Oracle>;
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -451,6 +465,9 @@ impl module_prices::Config for Test {
 	type DEX = DexModule;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping;
+	type UnixTime = Timestamp;
+	type MaxPriceAge = MaxPriceAge;
+	type FallbackTwapWindow = FallbackTwapWindow;
 	type WeightInfo = ();
 }
 
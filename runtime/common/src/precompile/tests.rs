@@ -31,6 +31,7 @@ use crate::precompile::{
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
+use module_dex::SwapCurve;
 use module_evm::{Context, ExitError, ExitSucceed, Precompile};
 use module_support::AddressMapping;
 use orml_traits::DataFeeder;
@@ -599,7 +600,12 @@ fn schedule_call_precompile_should_handle_invalid_input() {
 fn dex_precompile_get_liquidity_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -641,7 +647,12 @@ fn dex_precompile_get_liquidity_should_work() {
 fn dex_precompile_get_liquidity_token_address_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -692,7 +703,12 @@ fn dex_precompile_get_liquidity_token_address_should_work() {
 fn dex_precompile_get_swap_target_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -739,7 +755,12 @@ fn dex_precompile_get_swap_target_amount_should_work() {
 fn dex_precompile_get_swap_supply_amount_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -782,11 +803,72 @@ fn dex_precompile_get_swap_supply_amount_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_get_twap_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+		System::set_block_number(11);
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + currency_id_a + currency_id_b + window
+		let mut input = [0u8; 4 + 3 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(dex::Action::GetTwap).to_be_bytes());
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// window
+		U256::from(10).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(1_000_000_000_000_000_000_000u128).to_big_endian(&mut expected_output[..32]);
+
+		let resp = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		assert_eq!(resp.cost, 0);
+
+		// there's no price observation before the window
+		U256::from(11).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		assert_eq!(
+			DexPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("Dex get_twap failed".into()))
+		);
+	});
+}
+
 #[test]
 fn dex_precompile_swap_with_exact_supply_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -837,7 +919,12 @@ fn dex_precompile_swap_with_exact_supply_should_work() {
 fn dex_precompile_swap_with_exact_target_should_work() {
 	new_test_ext().execute_with(|| {
 		// enable RENBTC/AUSD
-		assert_ok!(DexModule::enable_trading_pair(Origin::signed(ALICE), RENBTC, AUSD,));
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
 
 		assert_ok!(DexModule::add_liquidity(
 			Origin::signed(ALICE),
@@ -884,6 +971,83 @@ fn dex_precompile_swap_with_exact_target_should_work() {
 	});
 }
 
+#[test]
+fn dex_precompile_flash_swap_should_work() {
+	new_test_ext().execute_with(|| {
+		// enable RENBTC/AUSD
+		assert_ok!(DexModule::enable_trading_pair(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			SwapCurve::ConstantProduct
+		));
+
+		assert_ok!(DexModule::add_liquidity(
+			Origin::signed(ALICE),
+			RENBTC,
+			AUSD,
+			1_000,
+			1_000_000,
+			0,
+			true
+		));
+
+		let context = Context {
+			address: Default::default(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		};
+
+		// action + who + borrow_currency_id + paired_currency_id + borrow_amount +
+		// repay_currency_id + max_repay_amount + data_offset + data_len
+		let mut input = [0u8; 9 * 32];
+		// action
+		input[0..4].copy_from_slice(&Into::<u32>::into(dex::Action::FlashSwap).to_be_bytes());
+		// who
+		U256::from(alice_evm_addr().as_bytes()).to_big_endian(&mut input[4 + 0 * 32..4 + 1 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 1 * 32..4 + 2 * 32]);
+		// AUSD
+		U256::from_big_endian(ausd_evm_address().as_bytes()).to_big_endian(&mut input[4 + 2 * 32..4 + 3 * 32]);
+		// borrow_amount
+		U256::from(100).to_big_endian(&mut input[4 + 3 * 32..4 + 4 * 32]);
+		// RENBTC
+		U256::from_big_endian(renbtc_evm_address().as_bytes()).to_big_endian(&mut input[4 + 4 * 32..4 + 5 * 32]);
+		// max_repay_amount
+		U256::from(101).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+		// data_offset
+		U256::from(7 * 32).to_big_endian(&mut input[4 + 6 * 32..4 + 7 * 32]);
+		// data_len
+		U256::from(0).to_big_endian(&mut input[4 + 7 * 32..4 + 8 * 32]);
+
+		assert_eq!(
+			DexPrecompile::execute(&input, None, &context),
+			Err(ExitError::Other("ExcessiveSupplyAmount".into()))
+		);
+
+		// max_repay_amount
+		U256::from(102).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+
+		let mut expected_output = [0u8; 32];
+		U256::from(102).to_big_endian(&mut expected_output[..32]);
+
+		let resp = DexPrecompile::execute(&input, None, &context).unwrap();
+		assert_eq!(resp.exit_status, ExitSucceed::Returned);
+		assert_eq!(resp.output, expected_output);
+		// the intrinsic gas of the contract call is charged
+		assert_eq!(resp.cost, 21_000);
+		assert_eq!(DexModule::get_liquidity_pool(RENBTC, AUSD), (1_002, 1_000_000));
+
+		// not enough gas left for the contract call
+		U256::from(103).to_big_endian(&mut input[4 + 5 * 32..4 + 6 * 32]);
+		assert_eq!(
+			DexPrecompile::execute(&input, Some(20_000), &context),
+			Err(ExitError::Other("flash swap callback failed".into()))
+		);
+		assert_eq!(DexModule::get_liquidity_pool(RENBTC, AUSD), (1_002, 1_000_000));
+	});
+}
+
 #[test]
 fn task_id_max_and_min() {
 	let task_id = TaskInfo {
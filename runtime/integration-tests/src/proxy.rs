@@ -121,6 +121,7 @@ fn proxy_permissions_correct() {
 				Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(200, 100))),
 				Change::NewValue(1_000_000 * dollar(USD_CURRENCY)),
+				Change::NoChange,
 			));
 			assert_ok!(Dex::add_liquidity(
 				Origin::signed(AccountId::from(BOB)),
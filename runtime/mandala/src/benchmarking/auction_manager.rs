@@ -24,6 +24,7 @@ use crate::{
 use super::utils::feed_price;
 use frame_benchmarking::account;
 use frame_system::RawOrigin;
+use module_auction_manager::CollateralAuctionMode;
 use module_support::{AuctionManager as AuctionManagerTrait, CDPTreasury};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
@@ -62,6 +63,29 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: cancel(RawOrigin::None, auction_id)
+
+	set_collateral_auction_mode {
+	}: _(RawOrigin::Root, STAKING, CollateralAuctionMode::Dutch)
+
+	// `take` a Dutch collateral auction, worst case:
+	// the take reaches the target and finishes the auction
+	take_collateral_auction {
+		let taker: AccountId = account("taker", 0, SEED);
+		let funder: AccountId = account("funder", 0, SEED);
+
+		// set balance
+		Currencies::deposit(STABLECOIN, &taker, 200 * dollar(STABLECOIN))?;
+		Currencies::deposit(STAKING, &funder, dollar(STAKING))?;
+		CdpTreasury::deposit_collateral(&funder, STAKING, dollar(STAKING))?;
+
+		// feed price
+		feed_price(vec![(STAKING, Price::saturating_from_integer(120))])?;
+
+		// create Dutch collateral auction
+		AuctionManager::set_collateral_auction_mode(RawOrigin::Root.into(), STAKING, CollateralAuctionMode::Dutch)?;
+		AuctionManager::new_collateral_auction(&funder, STAKING, dollar(STAKING), 100 * dollar(STABLECOIN))?;
+		let auction_id: AuctionId = Default::default();
+	}: _(RawOrigin::Signed(taker), auction_id, dollar(STAKING), 200 * dollar(STABLECOIN))
 }
 
 #[cfg(test)]
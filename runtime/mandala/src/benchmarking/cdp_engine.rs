@@ -17,9 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Address, Amount, Balance, CdpEngine, CollateralCurrencyIds, CurrencyId,
+	dollar, AccountId, Address, Amount, Balance, CdpEngine, CollateralCurrencyIds, Currencies, CurrencyId,
 	DefaultDebitExchangeRate, Dex, EmergencyShutdown, ExistentialDeposits, GetStableCurrencyId, GetStakingCurrencyId,
-	MaxSwapSlippageCompareToOracle, MinimumDebitValue, Price, Rate, Ratio, Runtime, MILLISECS_PER_BLOCK,
+	MaxLiquidationContracts, MaxSwapSlippageCompareToOracle, MinimumDebitValue, Price, Rate, Ratio, Runtime, System,
+	MILLISECS_PER_BLOCK,
 };
 
 use super::utils::{feed_price, set_balance};
@@ -27,11 +28,14 @@ use core::convert::TryInto;
 use frame_benchmarking::account;
 use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_cdp_engine::{KeeperRewardParams, LiquidationStrategy, StabilityFeeControllerParams};
+use module_dex::SwapCurve;
 use module_support::DEXManager;
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::{Change, GetByKey};
+use orml_traits::{Change, GetByKey, MultiCurrency};
+use primitives::evm::EvmAddress;
 use sp_runtime::{
-	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto},
+	traits::{AccountIdLookup, One, StaticLookup, UniqueSaturatedInto, Zero},
 	FixedPointNumber,
 };
 use sp_std::prelude::*;
@@ -41,6 +45,26 @@ const SEED: u32 = 0;
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
 
+fn stability_fee_controller_params() -> StabilityFeeControllerParams {
+	StabilityFeeControllerParams {
+		reference_currency_id: STAKING,
+		twap_window: 10,
+		tolerance: Ratio::saturating_from_rational(1, 100),
+		sensitivity: Rate::saturating_from_rational(1, 1_000_000),
+		max_adjustment: Rate::saturating_from_rational(1, 100_000_000),
+		min_rate: Rate::zero(),
+		max_rate: Rate::saturating_from_rational(1, 10_000_000),
+	}
+}
+
+fn keeper_reward_params() -> KeeperRewardParams {
+	KeeperRewardParams {
+		fixed: dollar(STABLECOIN),
+		percentage: Rate::saturating_from_rational(1, 100),
+		max_penalty_share: Ratio::saturating_from_rational(1, 2),
+	}
+}
+
 fn inject_liquidity(
 	maker: AccountId,
 	currency_id: CurrencyId,
@@ -53,7 +77,12 @@ fn inject_liquidity(
 	set_balance(currency_id, &maker, max_other_currency_amount.unique_saturated_into());
 	set_balance(base_currency_id, &maker, max_amount.unique_saturated_into());
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, base_currency_id);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id,
+		base_currency_id,
+		SwapCurve::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -106,6 +135,7 @@ runtime_benchmarks! {
 				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 				Change::NewValue(Some(Ratio::saturating_from_rational(0, 100))),
 				Change::NewValue(min_debit_value * 100),
+				Change::NoChange,
 			)?;
 
 			// adjust position
@@ -135,7 +165,8 @@ runtime_benchmarks! {
 		Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 		Change::NewValue(Some(Rate::saturating_from_rational(20, 100))),
 		Change::NewValue(Some(Ratio::saturating_from_rational(180, 100))),
-		Change::NewValue(100_000 * dollar(STABLECOIN))
+		Change::NewValue(100_000 * dollar(STABLECOIN)),
+		Change::NewValue(Some(Ratio::saturating_from_rational(200, 100)))
 	)
 
 	set_global_params {
@@ -168,6 +199,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -182,6 +214,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 
@@ -219,6 +252,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -233,6 +267,7 @@ runtime_benchmarks! {
 			Change::NoChange,
 			Change::NoChange,
 			Change::NoChange,
+			Change::NoChange,
 		)?;
 	}: liquidate(RawOrigin::None, STAKING, owner_lookup)
 	verify {
@@ -241,6 +276,68 @@ runtime_benchmarks! {
 		assert!(base_currency_amount < base_amount_in_dex);
 	}
 
+	liquidate_by_keeper {
+		let keeper: AccountId = account("keeper", 0, SEED);
+		let owner: AccountId = account("owner", 0, SEED);
+		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
+		let funder: AccountId = account("funder", 0, SEED);
+
+		let debit_value = 100 * dollar(STABLECOIN);
+		let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(STAKING);
+		let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+		let debit_amount: Amount = debit_amount.unique_saturated_into();
+		let collateral_value = 2 * debit_value;
+		let collateral_amount = Price::saturating_from_rational(dollar(STAKING), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+		let collateral_price = Price::one();		// 1 USD
+		let max_slippage_swap_with_dex = MaxSwapSlippageCompareToOracle::get();
+		let collateral_amount_in_dex = max_slippage_swap_with_dex.reciprocal().unwrap().saturating_mul_int(collateral_amount);
+		let base_amount_in_dex = max_slippage_swap_with_dex.reciprocal().unwrap().saturating_mul_int(debit_value * 2);
+
+		inject_liquidity(funder.clone(), STAKING, base_amount_in_dex, collateral_amount_in_dex)?;
+
+		// set balance
+		set_balance(STAKING, &owner, collateral_amount + ExistentialDeposits::get(&STAKING));
+
+		// feed price
+		feed_price(vec![(STAKING, collateral_price)])?;
+
+		// set risk params
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+			Change::NewValue(debit_value * 100),
+			Change::NoChange,
+		)?;
+
+		// adjust position
+		CdpEngine::adjust_position(&owner, STAKING, collateral_amount.try_into().unwrap(), debit_amount)?;
+
+		// modify liquidation rate to make the cdp unsafe
+		CdpEngine::set_collateral_params(
+			RawOrigin::Root.into(),
+			STAKING,
+			Change::NoChange,
+			Change::NewValue(Some(Ratio::saturating_from_rational(1000, 100))),
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+			Change::NoChange,
+		)?;
+
+		// enable liquidation by keeper
+		CdpEngine::set_keeper_reward_params(RawOrigin::Root.into(), Some(keeper_reward_params()))?;
+	}: _(RawOrigin::Signed(keeper.clone()), STAKING, owner_lookup)
+	verify {
+		let (other_currency_amount, base_currency_amount) = Dex::get_liquidity_pool(STAKING, STABLECOIN);
+		assert!(other_currency_amount > collateral_amount_in_dex);
+		assert!(base_currency_amount < base_amount_in_dex);
+		assert!(!Currencies::free_balance(STABLECOIN, &keeper).is_zero());
+	}
+
 	settle {
 		let owner: AccountId = account("owner", 0, SEED);
 		let owner_lookup = AccountIdLookup::unlookup(owner.clone());
@@ -267,6 +364,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(min_debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// adjust position
@@ -275,6 +373,59 @@ runtime_benchmarks! {
 		// shutdown
 		EmergencyShutdown::emergency_shutdown(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, STAKING, owner_lookup)
+
+	set_cross_collateral_liquidation_priority {
+	}: _(RawOrigin::Root, CollateralCurrencyIds::get())
+
+	set_stability_fee_controller {
+	}: _(RawOrigin::Root, Some(stability_fee_controller_params()))
+
+	adjust_global_interest_rate {
+		let funder: AccountId = account("funder", 0, SEED);
+		let params = stability_fee_controller_params();
+
+		// the price of stable currency in DEX is below the peg
+		System::set_block_number(1);
+		inject_liquidity(funder, STAKING, 100 * dollar(STABLECOIN), 10 * dollar(STAKING))?;
+		feed_price(vec![(STAKING, Price::saturating_from_integer(8))])?;
+		System::set_block_number(params.twap_window + 1);
+	}: {
+		CdpEngine::adjust_global_interest_rate(&params);
+	}
+	verify {
+		assert!(!CdpEngine::global_interest_rate_per_sec().is_zero());
+	}
+
+	set_liquidation_strategies {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		vec![LiquidationStrategy::Contract, LiquidationStrategy::Exchange, LiquidationStrategy::Auction]
+	)
+
+	register_liquidation_contract {
+		for i in 1..MaxLiquidationContracts::get() {
+			CdpEngine::register_liquidation_contract(RawOrigin::Root.into(), EvmAddress::from_low_u64_be(i.into()))?;
+		}
+	}: _(RawOrigin::Root, EvmAddress::from_low_u64_be(0))
+
+	deregister_liquidation_contract {
+		for i in 0..MaxLiquidationContracts::get() {
+			CdpEngine::register_liquidation_contract(RawOrigin::Root.into(), EvmAddress::from_low_u64_be(i.into()))?;
+		}
+	}: _(RawOrigin::Root, EvmAddress::from_low_u64_be((MaxLiquidationContracts::get() - 1).into()))
+
+	set_keeper_reward_params {
+	}: _(RawOrigin::Root, Some(keeper_reward_params()))
+
+	set_debit_issuance_limits {
+	}: _(
+		RawOrigin::Root,
+		STAKING,
+		Change::NewValue(Some(100 * dollar(STABLECOIN))),
+		Change::NewValue(Some(1000 * dollar(STABLECOIN))),
+		Change::NewValue(Some(10 * dollar(STABLECOIN)))
+	)
 }
 
 #[cfg(test)]
@@ -16,15 +16,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{dollar, CdpTreasury, Currencies, CurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Runtime};
+use crate::{
+	dollar, CdpTreasury, Currencies, CurrencyId, GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId,
+	MaxAuctionsCount, Runtime,
+};
 
+use frame_support::traits::OnFinalize;
 use frame_system::RawOrigin;
 use module_support::CDPTreasury;
 use orml_benchmarking::runtime_benchmarks;
-use orml_traits::MultiCurrency;
+use orml_traits::{Change, MultiCurrency};
 
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
 const STAKING: CurrencyId = GetStakingCurrencyId::get();
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 
 runtime_benchmarks! {
 	{ Runtime, module_cdp_treasury }
@@ -39,6 +44,31 @@ runtime_benchmarks! {
 	extract_surplus_to_treasury {
 		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
 	}: _(RawOrigin::Root, 200 * dollar(STABLECOIN))
+
+	set_debit_and_surplus_handle_params {
+	}: _(
+		RawOrigin::Root,
+		Change::NewValue(1_000 * dollar(STABLECOIN)),
+		Change::NewValue(100 * dollar(STABLECOIN)),
+		Change::NewValue(100 * dollar(STABLECOIN)),
+		Change::NewValue(100 * dollar(NATIVE))
+	)
+
+	// worst case: create `c` surplus auctions
+	on_finalize {
+		let c in 0 .. MaxAuctionsCount::get();
+
+		CdpTreasury::set_debit_and_surplus_handle_params(
+			RawOrigin::Root.into(),
+			Change::NewValue(0),
+			Change::NewValue(100 * dollar(STABLECOIN)),
+			Change::NoChange,
+			Change::NoChange,
+		)?;
+		CdpTreasury::on_system_surplus(c as u128 * 100 * dollar(STABLECOIN))?;
+	}: {
+		CdpTreasury::on_finalize(1);
+	}
 }
 
 #[cfg(test)]
@@ -17,16 +17,16 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, GetNativeCurrencyId, GetStableCurrencyId, Runtime,
-	TradingPathLimit,
+	dollar, AccountId, Balance, Currencies, CurrencyId, Dex, ExtendedProvisioningBlocks, GetLiquidCurrencyId,
+	GetNativeCurrencyId, GetStableCurrencyId, GetStakingCurrencyId, Runtime, System, TradingPathLimit,
 };
 
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{SwapCurve, TradingPairStatus, MAX_SEARCH_TRADING_PATHS, MAX_SWAP_SPLIT_PATHS};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrencyExtended;
-use primitives::TradingPair;
+use primitives::{TokenSymbol, TradingPair};
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::prelude::*;
 
@@ -34,6 +34,42 @@ const SEED: u32 = 0;
 
 const NATIVE: CurrencyId = GetNativeCurrencyId::get();
 const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+const STAKING: CurrencyId = GetStakingCurrencyId::get();
+const LIQUID: CurrencyId = GetLiquidCurrencyId::get();
+
+// the intermediate currencies of the routing benchmarks
+const ROUTING_CURRENCIES: [CurrencyId; MAX_SEARCH_TRADING_PATHS as usize - 1] = [
+	STAKING,
+	LIQUID,
+	CurrencyId::Token(TokenSymbol::RENBTC),
+	CurrencyId::Token(TokenSymbol::KAR),
+	CurrencyId::Token(TokenSymbol::KSM),
+	CurrencyId::Token(TokenSymbol::LKSM),
+	CurrencyId::Token(TokenSymbol::BNC),
+];
+
+// inject the liquidity of `paths` trading paths from STABLECOIN to NATIVE:
+// STABLECOIN => NATIVE, STABLECOIN => X => NATIVE for the other currencies X
+fn inject_routing_liquidity(maker: AccountId, paths: u32) -> Result<(), &'static str> {
+	let mut trading_pairs = vec![(STABLECOIN, NATIVE)];
+	for currency_id in ROUTING_CURRENCIES.iter().take(paths.saturating_sub(1) as usize) {
+		trading_pairs.push((STABLECOIN, *currency_id));
+		trading_pairs.push((*currency_id, NATIVE));
+	}
+
+	for (currency_id_a, currency_id_b) in trading_pairs {
+		inject_liquidity(
+			maker.clone(),
+			currency_id_a,
+			currency_id_b,
+			10_000 * dollar(currency_id_a),
+			10_000 * dollar(currency_id_b),
+			false,
+		)?;
+	}
+
+	Ok(())
+}
 
 fn inject_liquidity(
 	maker: AccountId,
@@ -55,7 +91,12 @@ fn inject_liquidity(
 		max_amount_b.unique_saturated_into(),
 	)?;
 
-	let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id_a, currency_id_b);
+	let _ = Dex::enable_trading_pair(
+		RawOrigin::Root.into(),
+		currency_id_a,
+		currency_id_b,
+		SwapCurve::ConstantProduct,
+	);
 
 	Dex::add_liquidity(
 		RawOrigin::Signed(maker.clone()).into(),
@@ -79,13 +120,13 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), SwapCurve::ConstantProduct)
 
 	// disable a Enabled trading pair
 	disable_trading_pair {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
 		if let TradingPairStatus::Disabled = Dex::trading_pair_statuses(trading_pair) {
-			Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+			Dex::enable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second(), SwapCurve::ConstantProduct)?;
 		}
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second())
 
@@ -95,7 +136,7 @@ runtime_benchmarks! {
 		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
 		}
-	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10)
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), dollar(trading_pair.first()), dollar(trading_pair.second()), dollar(trading_pair.first()), dollar(trading_pair.second()), 10, SwapCurve::ConstantProduct)
 
 	// update parameters of a Provisioning trading pair
 	update_provisioning_parameters {
@@ -111,7 +152,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			100
+			100,
+			SwapCurve::ConstantProduct,
 		)?;
 	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), 2 * dollar(trading_pair.first()), 2 * dollar(trading_pair.second()), 10 * dollar(trading_pair.first()), 100 * dollar(trading_pair.second()), 200)
 
@@ -130,7 +172,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			100 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -147,6 +190,72 @@ runtime_benchmarks! {
 		)?;
 	}: _(RawOrigin::Signed(founder), trading_pair.first(), trading_pair.second())
 
+	abort_provisioning {
+		let founder: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			100 * dollar(trading_pair.second()),
+			0,
+			SwapCurve::ConstantProduct,
+		)?;
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &founder, (100 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &founder, (100 * dollar(trading_pair.second())).unique_saturated_into())?;
+
+		// add provision which doesn't meet the target
+		Dex::add_provision(
+			RawOrigin::Signed(founder.clone()).into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			10 * dollar(trading_pair.first()),
+			10 * dollar(trading_pair.second()),
+		)?;
+		System::set_block_number(ExtendedProvisioningBlocks::get() + 1);
+	}: _(RawOrigin::Signed(founder), trading_pair.first(), trading_pair.second())
+
+	refund_provision {
+		let founder: AccountId = whitelisted_caller();
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		if let TradingPairStatus::Enabled = Dex::trading_pair_statuses(trading_pair) {
+			Dex::disable_trading_pair(RawOrigin::Root.into(), trading_pair.first(), trading_pair.second())?;
+		}
+		Dex::list_provisioning(
+			RawOrigin::Root.into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			dollar(trading_pair.first()),
+			dollar(trading_pair.second()),
+			100 * dollar(trading_pair.first()),
+			100 * dollar(trading_pair.second()),
+			0,
+			SwapCurve::ConstantProduct,
+		)?;
+
+		// set balance
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.first(), &founder, (100 * dollar(trading_pair.first())).unique_saturated_into())?;
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(trading_pair.second(), &founder, (100 * dollar(trading_pair.second())).unique_saturated_into())?;
+
+		Dex::add_provision(
+			RawOrigin::Signed(founder.clone()).into(),
+			trading_pair.first(),
+			trading_pair.second(),
+			10 * dollar(trading_pair.first()),
+			10 * dollar(trading_pair.second()),
+		)?;
+		System::set_block_number(ExtendedProvisioningBlocks::get() + 1);
+		Dex::abort_provisioning(RawOrigin::Signed(founder.clone()).into(), trading_pair.first(), trading_pair.second())?;
+	}: _(RawOrigin::Signed(founder.clone()), founder.clone(), trading_pair.first(), trading_pair.second())
+
 	add_provision {
 		let founder: AccountId = whitelisted_caller();
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -161,7 +270,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			100 * dollar(trading_pair.first()),
 			1000 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -183,7 +293,8 @@ runtime_benchmarks! {
 			dollar(trading_pair.second()),
 			10 * dollar(trading_pair.first()),
 			10 * dollar(trading_pair.second()),
-			0
+			0,
+			SwapCurve::ConstantProduct,
 		)?;
 
 		// set balance
@@ -299,6 +410,41 @@ runtime_benchmarks! {
 
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
 	}: swap_with_exact_target(RawOrigin::Signed(taker), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]))
+
+	swap_with_exact_supply_by_best_paths {
+		let u in 1 .. MAX_SWAP_SPLIT_PATHS;
+		let p in 1 .. MAX_SEARCH_TRADING_PATHS;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_routing_liquidity(maker, p)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: swap_with_exact_supply_by_best_paths(RawOrigin::Signed(taker), STABLECOIN, NATIVE, 1_000 * dollar(STABLECOIN), 0, u)
+
+	swap_with_exact_target_by_best_paths {
+		let u in 1 .. MAX_SWAP_SPLIT_PATHS;
+		let p in 1 .. MAX_SEARCH_TRADING_PATHS;
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_routing_liquidity(maker, p)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+	}: swap_with_exact_target_by_best_paths(RawOrigin::Signed(taker), STABLECOIN, NATIVE, 1_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), u)
+
+	flash_swap {
+		let maker: AccountId = account("maker", 0, SEED);
+		let taker: AccountId = whitelisted_caller();
+		inject_liquidity(maker, STABLECOIN, NATIVE, 10_000 * dollar(STABLECOIN), 10_000 * dollar(NATIVE), false)?;
+
+		<Currencies as MultiCurrencyExtended<_>>::update_balance(STABLECOIN, &taker, (10_000 * dollar(STABLECOIN)).unique_saturated_into())?;
+		let callback = Box::new(crate::Call::System(frame_system::Call::remark { remark: vec![] }));
+	}: flash_swap(RawOrigin::Signed(taker), NATIVE, STABLECOIN, 1_000 * dollar(NATIVE), STABLECOIN, 10_000 * dollar(STABLECOIN), callback)
+
+	set_exchange_fee {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some((3, 1000)))
 }
 
 #[cfg(test)]
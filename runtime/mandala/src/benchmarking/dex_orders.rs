@@ -0,0 +1,89 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{dollar, AccountId, CurrencyId, Dex, DexOrders, GetNativeCurrencyId, GetStableCurrencyId, Price, Runtime};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use module_dex::SwapCurve;
+use module_dex_orders::OrderKind;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+
+const SEED: u32 = 0;
+
+const NATIVE: CurrencyId = GetNativeCurrencyId::get();
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+
+fn place_limit_order(owner: AccountId, trigger_price: Price) -> Result<(), &'static str> {
+	set_balance(NATIVE, &owner, 1_000 * dollar(NATIVE));
+	DexOrders::place_order(
+		RawOrigin::Signed(owner).into(),
+		OrderKind::Limit,
+		NATIVE,
+		STABLECOIN,
+		100 * dollar(NATIVE),
+		trigger_price,
+		1,
+		1_000,
+	)?;
+
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_dex_orders }
+
+	place_order {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller), OrderKind::Limit, NATIVE, STABLECOIN, 100 * dollar(NATIVE), Price::saturating_from_integer(2), 1, 1_000)
+
+	cancel_order {
+		let caller: AccountId = whitelisted_caller();
+		place_limit_order(caller.clone(), Price::saturating_from_integer(2))?;
+	}: _(RawOrigin::Signed(caller), 0)
+
+	// worst case: the order is matched and swapped through DEX
+	execute_order {
+		let maker: AccountId = account("maker", 0, SEED);
+		let owner: AccountId = account("owner", 0, SEED);
+		set_balance(NATIVE, &maker, 10_000 * dollar(NATIVE));
+		set_balance(STABLECOIN, &maker, 10_000 * dollar(STABLECOIN));
+		let _ = Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN, SwapCurve::ConstantProduct);
+		Dex::add_liquidity(
+			RawOrigin::Signed(maker).into(),
+			NATIVE,
+			STABLECOIN,
+			10_000 * dollar(NATIVE),
+			10_000 * dollar(STABLECOIN),
+			Default::default(),
+			false,
+		)?;
+		place_limit_order(owner, Price::saturating_from_rational(1, 10))?;
+	}: _(RawOrigin::None, 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
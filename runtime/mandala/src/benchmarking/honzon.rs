@@ -26,7 +26,7 @@ use super::utils::{feed_price, set_balance};
 use core::convert::TryInto;
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{SwapCurve, TradingPairStatus};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{Change, GetByKey};
 use primitives::TradingPair;
@@ -115,6 +115,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 	}: _(RawOrigin::Signed(caller), currency_id, collateral_amount.try_into().unwrap(), debit_amount)
 
@@ -148,6 +149,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -195,9 +197,9 @@ runtime_benchmarks! {
 			Dex::disable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN)?;
 		}
 		// inject liquidity
-		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, STABLECOIN)?;
-		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, NATIVE)?;
-		Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, STABLECOIN, SwapCurve::ConstantProduct)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), currency_id, NATIVE, SwapCurve::ConstantProduct)?;
+		Dex::enable_trading_pair(RawOrigin::Root.into(), NATIVE, STABLECOIN, SwapCurve::ConstantProduct)?;
 		Dex::add_liquidity(
 			RawOrigin::Signed(maker.clone()).into(),
 			currency_id,
@@ -248,6 +250,7 @@ runtime_benchmarks! {
 			Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
 			Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
 			Change::NewValue(debit_value * 100),
+			Change::NoChange,
 		)?;
 
 		// initialize sender's loan
@@ -258,6 +261,52 @@ runtime_benchmarks! {
 			debit_amount,
 		)?;
 	}: _(RawOrigin::Signed(sender), currency_id, collateral_amount, Some(path))
+
+	set_cross_collateral {
+		let c in 1 .. CollateralCurrencyIds::get().len() as u32;
+		let caller: AccountId = whitelisted_caller();
+		let currency_ids = CollateralCurrencyIds::get();
+		let debit_value = 100 * dollar(STABLECOIN);
+		let collateral_value = 10 * debit_value;
+
+		// feed price
+		let mut feed_data: Vec<(CurrencyId, Price)> = vec![];
+		for i in 0 .. c {
+			feed_data.push((currency_ids[i as usize], Price::one()));
+		}
+		feed_price(feed_data)?;
+
+		for i in 0 .. c {
+			let currency_id = currency_ids[i as usize];
+			let debit_exchange_rate = CdpEngine::get_debit_exchange_rate(currency_id);
+			let debit_amount = debit_exchange_rate.reciprocal().unwrap().saturating_mul_int(debit_value);
+			let debit_amount: Amount = debit_amount.unique_saturated_into();
+			let collateral_amount = Price::saturating_from_rational(dollar(currency_id), dollar(STABLECOIN)).saturating_mul_int(collateral_value);
+
+			// set balance
+			set_balance(currency_id, &caller, collateral_amount + ExistentialDeposits::get(&currency_id));
+
+			// set risk params
+			CdpEngine::set_collateral_params(
+				RawOrigin::Root.into(),
+				currency_id,
+				Change::NoChange,
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(Some(Rate::saturating_from_rational(10, 100))),
+				Change::NewValue(Some(Ratio::saturating_from_rational(150, 100))),
+				Change::NewValue(debit_value * 100),
+				Change::NoChange,
+			)?;
+
+			// initialize caller's loan
+			Honzon::adjust_loan(
+				RawOrigin::Signed(caller.clone()).into(),
+				currency_id,
+				collateral_amount.try_into().unwrap(),
+				debit_amount,
+			)?;
+		}
+	}: _(RawOrigin::Signed(caller), true)
 }
 
 #[cfg(test)]
@@ -29,6 +29,7 @@ pub mod cdp_treasury;
 pub mod collator_selection;
 pub mod currencies;
 pub mod dex;
+pub mod dex_orders;
 pub mod emergency_shutdown;
 pub mod evm;
 pub mod evm_accounts;
@@ -38,6 +39,7 @@ pub mod incentives;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
 pub mod prices;
+pub mod savings;
 pub mod session_manager;
 pub mod transaction_pause;
 pub mod transaction_payment;
@@ -16,13 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Runtime};
+use crate::{
+	AcalaOracle, CollateralCurrencyIds, CurrencyId, GetStableCurrencyId, Origin, Price, Prices, Ratio, Runtime,
+};
 
 use super::utils::feed_price;
+use frame_support::traits::{OnFinalize, OnInitialize};
 use frame_system::RawOrigin;
+use module_prices::{CircuitBreakerParams, PriceAggregationParams, PriceSourceKind};
 use orml_benchmarking::runtime_benchmarks;
-use sp_runtime::traits::One;
-use sp_std::vec;
+use sp_runtime::{traits::One, FixedPointNumber};
+use sp_std::{prelude::*, vec};
 
 const STAKING: CurrencyId = GetStableCurrencyId::get();
 
@@ -39,6 +43,66 @@ runtime_benchmarks! {
 		feed_price(vec![(STAKING, Price::one())])?;
 		Prices::lock_price(Origin::root(), STAKING)?;
 	}: _(RawOrigin::Root, STAKING)
+
+	set_circuit_breaker {
+	}: _(RawOrigin::Root, STAKING, Some(circuit_breaker_params()))
+
+	unfreeze_price {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+		feed_price(vec![(currency_id, Price::one())])?;
+		Prices::set_circuit_breaker(Origin::root(), currency_id, Some(circuit_breaker_params()))?;
+		Prices::on_initialize(1);
+		AcalaOracle::on_finalize(1);
+		feed_price(vec![(currency_id, Price::saturating_from_integer(2))])?;
+		Prices::on_initialize(2);
+	}: _(RawOrigin::Root, currency_id)
+
+	// worst case: freeze `c` currencies
+	on_initialize {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids: Vec<CurrencyId> = CollateralCurrencyIds::get().into_iter().take(c as usize).collect();
+
+		feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::one())).collect())?;
+		for currency_id in currency_ids.iter() {
+			Prices::set_circuit_breaker(Origin::root(), *currency_id, Some(circuit_breaker_params()))?;
+		}
+		Prices::on_initialize(1);
+		AcalaOracle::on_finalize(1);
+		feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::saturating_from_integer(2))).collect())?;
+	}: {
+		Prices::on_initialize(2);
+	}
+
+	set_price_aggregation {
+		let currency_id: CurrencyId = CollateralCurrencyIds::get()[0];
+	}: _(RawOrigin::Root, currency_id, Some(price_aggregation_params()))
+
+	// worst case: aggregate `c` currencies
+	check_price_sources {
+		let c in 0 .. CollateralCurrencyIds::get().len() as u32;
+		let currency_ids: Vec<CurrencyId> = CollateralCurrencyIds::get().into_iter().take(c as usize).collect();
+
+		feed_price(currency_ids.iter().map(|currency_id| (*currency_id, Price::one())).collect())?;
+		for currency_id in currency_ids.iter() {
+			Prices::set_price_aggregation(Origin::root(), *currency_id, Some(price_aggregation_params()))?;
+		}
+	}: {
+		Prices::on_initialize(1);
+	}
+}
+
+fn price_aggregation_params() -> PriceAggregationParams {
+	PriceAggregationParams {
+		sources: vec![(PriceSourceKind::Oracle, 2), (PriceSourceKind::DexTwap, 1)],
+		max_deviation: Ratio::saturating_from_rational(5, 100),
+	}
+}
+
+fn circuit_breaker_params() -> CircuitBreakerParams<u32> {
+	CircuitBreakerParams {
+		max_change: Ratio::saturating_from_rational(20, 100),
+		period: 100,
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,76 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2021 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	dollar, AccountId, CdpTreasury, CurrencyId, GetStableCurrencyId, Rate, Runtime, Savings, MILLISECS_PER_BLOCK,
+};
+
+use super::utils::set_balance;
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::OnInitialize;
+use frame_system::RawOrigin;
+use module_support::CDPTreasury;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::FixedPointNumber;
+
+const SEED: u32 = 0;
+
+const STABLECOIN: CurrencyId = GetStableCurrencyId::get();
+
+runtime_benchmarks! {
+	{ Runtime, module_savings }
+
+	deposit {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(caller), 1_000 * dollar(STABLECOIN))
+
+	withdraw {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(STABLECOIN, &caller, 10_000 * dollar(STABLECOIN));
+		Savings::deposit(RawOrigin::Signed(caller.clone()).into(), 1_000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), 1_000 * dollar(STABLECOIN))
+
+	set_savings_rate {
+	}: _(RawOrigin::Root, Rate::saturating_from_rational(1, 1_000_000_000))
+
+	// worst case: pay interest from the surplus pool
+	on_initialize {
+		let depositor: AccountId = account("depositor", 0, SEED);
+		set_balance(STABLECOIN, &depositor, 10_000 * dollar(STABLECOIN));
+		Savings::deposit(RawOrigin::Signed(depositor).into(), 1_000 * dollar(STABLECOIN))?;
+		Savings::set_savings_rate(RawOrigin::Root.into(), Rate::saturating_from_rational(1, 1_000_000_000))?;
+		CdpTreasury::on_system_surplus(1_000 * dollar(STABLECOIN))?;
+
+		// set timestamp by set storage, this is deprecated,
+		// replace it by following after https://github.com/paritytech/substrate/pull/8601 is available:
+		// Timestamp::set_timestamp(MILLISECS_PER_BLOCK);
+		pallet_timestamp::Now::<Runtime>::put(MILLISECS_PER_BLOCK);
+	}: {
+		Savings::on_initialize(2);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
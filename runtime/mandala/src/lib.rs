@@ -126,8 +126,9 @@ pub use runtime_common::{
 	FinancialCouncilMembershipInstance, GasToWeight, GeneralCouncilInstance, GeneralCouncilMembershipInstance,
 	HomaCouncilInstance, HomaCouncilMembershipInstance, OffchainSolutionWeightLimit, OperatorMembershipInstanceAcala,
 	OperatorMembershipInstanceBand, Price, ProxyType, Rate, Ratio, RelayChainBlockNumberProvider,
-	RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights, SystemContractsFilter, TechnicalCommitteeInstance,
-	TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA, AUSD, DOT, LDOT, RENBTC,
+	RelayChainStorageRootProvider, RelayChainSubAccountId, RuntimeBlockLength, RuntimeBlockWeights,
+	SystemContractsFilter, TechnicalCommitteeInstance, TechnicalCommitteeMembershipInstance, TimeStampedPrice, ACA,
+	AUSD, DOT, LDOT, RENBTC, SAUSD,
 };
 
 /// Import the stable_asset pallet.
@@ -171,6 +172,8 @@ parameter_types! {
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
+	pub const SavingsPalletId: PalletId = PalletId(*b"aca/savm");
 	pub const StakingPoolPalletId: PalletId = PalletId(*b"aca/stkp");
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
@@ -193,6 +196,8 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		LoansPalletId::get().into_account(),
 		DEXPalletId::get().into_account(),
 		CDPTreasuryPalletId::get().into_account(),
+		CDPEnginePalletId::get().into_account(),
+		SavingsPalletId::get().into_account(),
 		StakingPoolPalletId::get().into_account(),
 		HonzonTreasuryPalletId::get().into_account(),
 		HomaTreasuryPalletId::get().into_account(),
@@ -804,6 +809,7 @@ parameter_type_with_key! {
 				TokenSymbol::AUSD => cent(*currency_id),
 				TokenSymbol::DOT => 10 * millicent(*currency_id),
 				TokenSymbol::LDOT => 50 * millicent(*currency_id),
+				TokenSymbol::SAUSD => cent(*currency_id),
 				TokenSymbol::BNC => 800 * millicent(*currency_id),  // 80BNC = 1KSM
 				TokenSymbol::VSKSM => 10 * millicent(*currency_id),  // 1VSKSM = 1KSM
 
@@ -811,6 +817,7 @@ parameter_type_with_key! {
 				TokenSymbol::KUSD |
 				TokenSymbol::KSM |
 				TokenSymbol::LKSM |
+				TokenSymbol::SKUSD |
 				TokenSymbol::RENBTC |
 				TokenSymbol::ACA |
 				TokenSymbol::CASH => Balance::max_value() // unsupported
@@ -855,9 +862,18 @@ parameter_types! {
 	pub StableCurrencyFixedPrice: Price = Price::saturating_from_rational(1, 1);
 }
 
+parameter_types! {
+	pub const MaxPriceAge: Moment = 1000 * 60 * 30; // 30 mins
+	pub const FallbackTwapWindow: u32 = HOURS;
+}
+
 impl module_prices::Config for Runtime {
 	type Event = Event;
 	type Source = AggregatedDataProvider;
+	type OracleInstances = (
+		module_prices::OracleInstance<AcalaOracle>,
+		module_prices::OracleInstance<BandOracle>,
+	);
 	type GetStableCurrencyId = GetStableCurrencyId;
 	type StableCurrencyFixedPrice = StableCurrencyFixedPrice;
 	type GetStakingCurrencyId = GetStakingCurrencyId;
@@ -867,6 +883,9 @@ impl module_prices::Config for Runtime {
 	type DEX = Dex;
 	type Currency = Currencies;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
+	type UnixTime = Timestamp;
+	type MaxPriceAge = MaxPriceAge;
+	type FallbackTwapWindow = FallbackTwapWindow;
 	type WeightInfo = weights::module_prices::WeightInfo<Runtime>;
 }
 
@@ -953,6 +972,9 @@ parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(2, 100);
 	pub const AuctionTimeToClose: BlockNumber = 15 * MINUTES;
 	pub const AuctionDurationSoftCap: BlockNumber = 2 * HOURS;
+	pub DutchAuctionStartingPremium: Rate = Rate::saturating_from_rational(20, 100);
+	pub DutchAuctionPriceDecayPerBlock: Rate = Rate::saturating_from_rational(5, 1000);
+	pub DutchAuctionMinimumPriceRatio: Ratio = Ratio::saturating_from_rational(50, 100);
 	pub DefaultSwapParitalPathList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStableCurrencyId::get()],
 	];
@@ -972,6 +994,11 @@ impl module_auction_manager::Config for Runtime {
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type DutchAuctionStartingPremium = DutchAuctionStartingPremium;
+	type DutchAuctionPriceDecayPerBlock = DutchAuctionPriceDecayPerBlock;
+	type DutchAuctionMinimumPriceRatio = DutchAuctionMinimumPriceRatio;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1051,11 +1078,18 @@ parameter_types! {
 	pub DefaultLiquidationPenalty: Rate = Rate::saturating_from_rational(5, 100);
 	pub MinimumDebitValue: Balance = dollar(AUSD);
 	pub MaxSwapSlippageCompareToOracle: Ratio = Ratio::saturating_from_rational(15, 100);
+	pub const StabilityFeeAdjustmentPeriod: BlockNumber = HOURS;
+	pub const IssuanceEpochPeriod: BlockNumber = DAYS;
+	pub LiquidationContractsAccount: AccountId = CDPEnginePalletId::get().into_account();
+	pub const MaxLiquidationContracts: u32 = 10;
 }
 
 impl module_cdp_engine::Config for Runtime {
 	type Event = Event;
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
+	type DEX = Dex;
+	type StabilityFeeAdjustmentPeriod = StabilityFeeAdjustmentPeriod;
+	type IssuanceEpochPeriod = IssuanceEpochPeriod;
 	type CollateralCurrencyIds = CollateralCurrencyIds;
 	type DefaultLiquidationRatio = DefaultLiquidationRatio;
 	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
@@ -1069,6 +1103,12 @@ impl module_cdp_engine::Config for Runtime {
 	type EmergencyShutdown = EmergencyShutdown;
 	type UnixTime = Timestamp;
 	type DefaultSwapParitalPathList = DefaultSwapParitalPathList;
+	type LiquidationEvmBridge = EVMBridge;
+	type EvmAddressMapping = EvmAddressMapping<Runtime>;
+	// the dedicated account pays the storage of liquidation contracts, it should be funded with native token
+	type LiquidationContractsAccount = LiquidationContractsAccount;
+	type GasToWeight = GasToWeight;
+	type MaxLiquidationContracts = MaxLiquidationContracts;
 	type WeightInfo = weights::module_cdp_engine::WeightInfo<Runtime>;
 }
 
@@ -1095,7 +1135,14 @@ impl module_emergency_shutdown::Config for Runtime {
 
 parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (1, 1000);	// 0.1%
+	pub DEXProtocolFeeShare: Ratio = Ratio::saturating_from_rational(20, 100);
+	// the protocol fee is sent to the treasury instead of the CDP treasury, where it would be
+	// counted as collateral
+	pub DEXProtocolFeeReceiver: AccountId = TreasuryAccount::get();
 	pub const TradingPathLimit: u32 = 4;
+	pub const PriceObservationPeriod: BlockNumber = 10 * MINUTES;
+	pub const MaxPriceObservations: u32 = 144;
+	pub const ExtendedProvisioningBlocks: BlockNumber = DAYS;
 	pub EnabledTradingPairs: Vec<TradingPair> = vec![
 		TradingPair::from_currency_ids(AUSD, ACA).unwrap(),
 		TradingPair::from_currency_ids(AUSD, DOT).unwrap(),
@@ -1108,12 +1155,36 @@ impl module_dex::Config for Runtime {
 	type Event = Event;
 	type Currency = Currencies;
 	type GetExchangeFee = GetExchangeFee;
+	type ProtocolFeeShare = DEXProtocolFeeShare;
+	type ProtocolFeeReceiver = DEXProtocolFeeReceiver;
 	type TradingPathLimit = TradingPathLimit;
 	type PalletId = DEXPalletId;
 	type CurrencyIdMapping = EvmCurrencyIdMapping<Runtime>;
 	type DEXIncentives = Incentives;
 	type WeightInfo = weights::module_dex::WeightInfo<Runtime>;
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
+	type PriceObservationPeriod = PriceObservationPeriod;
+	type MaxPriceObservations = MaxPriceObservations;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type Call = Call;
+}
+
+parameter_types! {
+	pub DexOrderDeposit: Balance = dollar(ACA);
+	pub const MaxDexOrdersPerAccount: u32 = 20;
+}
+
+impl module_dex_orders::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type DEX = Dex;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type OrderDeposit = DexOrderDeposit;
+	type MaxOrdersPerAccount = MaxDexOrdersPerAccount;
+	type UnsignedPriority = runtime_common::DexOrdersUnsignedPriority;
+	type WeightInfo = weights::module_dex_orders::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -1131,9 +1202,30 @@ impl module_cdp_treasury::Config for Runtime {
 	type MaxAuctionsCount = MaxAuctionsCount;
 	type PalletId = CDPTreasuryPalletId;
 	type TreasuryAccount = HonzonTreasuryAccount;
+	type EmergencyShutdown = EmergencyShutdown;
 	type WeightInfo = weights::module_cdp_treasury::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const GetSavingsCurrencyId: CurrencyId = SAUSD;
+	pub DefaultSavingsExchangeRate: ExchangeRate = ExchangeRate::saturating_from_integer(1);
+	pub MaxSavingsRatePerSec: Rate = Rate::saturating_from_rational(5, 1_000_000_000);
+}
+
+impl module_savings::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type GetSavingsCurrencyId = GetSavingsCurrencyId;
+	type DefaultExchangeRate = DefaultSavingsExchangeRate;
+	type MaxSavingsRatePerSec = MaxSavingsRatePerSec;
+	type CDPTreasury = CdpTreasury;
+	type UnixTime = Timestamp;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type PalletId = SavingsPalletId;
+	type WeightInfo = weights::module_savings::WeightInfo<Runtime>;
+}
+
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
@@ -1290,6 +1382,10 @@ parameter_types! {
 	pub MaximumRedeemRequestMatchesForMint: u32 = 20;
 	pub RelayChainUnbondingSlashingSpans: u32 = 5;
 	pub MaxScheduledUnbonds: u32 = 35;
+	pub const MaxRedeemRequests: u32 = 1_000;
+	pub MaxStakingLedgerDeviation: Permill = Permill::from_percent(2);
+	pub HomaTreasuryAccount: AccountId = HomaTreasuryPalletId::get().into_account();
+	pub const RelayChainEraLength: BlockNumber = 14_400; // 24 hours of 6 seconds relaychain blocks
 	pub ParachainAccount: AccountId = ParachainInfo::get().into_account();
 	pub SubAccountIndex: u16 = RelayChainSubAccountId::HomaLite as u16;
 	// Calculated from polkadot/xcm/xcm-builder: fn buy_weight
@@ -1319,6 +1415,15 @@ impl module_homa_lite::Config for Runtime {
 	type MaximumRedeemRequestMatchesForMint = MaximumRedeemRequestMatchesForMint;
 	type RelayChainUnbondingSlashingSpans = RelayChainUnbondingSlashingSpans;
 	type MaxScheduledUnbonds = MaxScheduledUnbonds;
+	type DEX = Dex;
+	type MaxRedeemRequests = MaxRedeemRequests;
+	type SovereignSubAccount = RelayChainSovereignSubAccountId;
+	type RelayChainStorageRoot = RelayChainStorageRootProvider<Runtime>;
+	type MaxStakingLedgerDeviation = MaxStakingLedgerDeviation;
+	type EraLength = RelayChainEraLength;
+	type TreasuryAccount = TreasuryAccount;
+	type InsuranceAccount = HomaTreasuryAccount;
+	type ValidatorGuarantees = HomaValidatorListModule;
 }
 
 parameter_types! {
@@ -1557,7 +1662,7 @@ pub type ScheduleCallPrecompile = runtime_common::ScheduleCallPrecompile<
 	Runtime,
 >;
 pub type DexPrecompile =
-	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex>;
+	runtime_common::DexPrecompile<AccountId, EvmAddressMapping<Runtime>, EvmCurrencyIdMapping<Runtime>, Dex, EVM>;
 
 #[cfg(feature = "with-ethereum-compatibility")]
 static ISTANBUL_CONFIG: module_evm_utiltity::evm::Config = module_evm_utiltity::evm::Config::istanbul();
@@ -1946,8 +2051,11 @@ impl nutsfinance_stable_asset::Config for Runtime {
 	type EnsurePoolAssetId = EnsurePoolAssetId;
 }
 
+pub type DexOrdersTask = module_dex_orders::DexOrdersTask<Runtime>;
+
 define_combined_task! {
 	pub enum ScheduledTasks {
+		DexOrdersTask,
 	}
 }
 
@@ -2038,9 +2146,26 @@ pub type UncheckedExtrinsic = AcalaUncheckedExtrinsic<Call, SignedExtra, Convert
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
+
+pub struct OnRuntimeUpgrade;
+impl frame_support::traits::OnRuntimeUpgrade for OnRuntimeUpgrade {
+	fn on_runtime_upgrade() -> u64 {
+		module_cdp_engine::migrations::v1::migrate::<Runtime>()
+			.saturating_add(module_auction_manager::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_homa_lite::migrations::v1::migrate::<Runtime>())
+			.saturating_add(module_dex::migrations::v1::migrate::<Runtime>())
+	}
+}
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPallets, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPallets,
+	OnRuntimeUpgrade,
+>;
 
 #[allow(clippy::large_enum_variant)]
 construct_runtime! {
@@ -2106,6 +2231,7 @@ construct_runtime! {
 		// Acala Core
 		Prices: module_prices::{Pallet, Storage, Call, Event<T>} = 110,
 		Dex: module_dex::{Pallet, Storage, Call, Event<T>, Config<T>} = 111,
+		DexOrders: module_dex_orders::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 112,
 
 		// Honzon
 		AuctionManager: module_auction_manager::{Pallet, Storage, Call, Event<T>, ValidateUnsigned} = 120,
@@ -2114,6 +2240,7 @@ construct_runtime! {
 		CdpTreasury: module_cdp_treasury::{Pallet, Storage, Call, Config, Event<T>} = 123,
 		CdpEngine: module_cdp_engine::{Pallet, Storage, Call, Event<T>, Config, ValidateUnsigned} = 124,
 		EmergencyShutdown: module_emergency_shutdown::{Pallet, Storage, Call, Event<T>} = 125,
+		Savings: module_savings::{Pallet, Storage, Call, Event<T>} = 126,
 
 		// Homa
 		Homa: module_homa::{Pallet, Call} = 130,
@@ -2304,6 +2431,102 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_dex_rpc_runtime_api::DexApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_swap_quote_with_exact_supply(
+			path: Vec<CurrencyId>,
+			supply_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapQuote<Balance>> {
+			Dex::get_swap_quote_with_exact_supply(path, supply_amount)
+		}
+
+		fn get_swap_quote_with_exact_target(
+			path: Vec<CurrencyId>,
+			target_amount: Balance,
+		) -> Option<module_dex_rpc_runtime_api::SwapQuote<Balance>> {
+			Dex::get_swap_quote_with_exact_target(path, target_amount)
+		}
+
+		fn get_trading_pairs() -> Vec<module_dex_rpc_runtime_api::TradingPairInfo<Balance>> {
+			Dex::get_trading_pairs()
+		}
+
+		fn get_liquidity_shares(account: AccountId) -> Vec<module_dex_rpc_runtime_api::LiquidityShareInfo<Balance>> {
+			// the dex share deposited to incentives is still owned by the account.
+			Dex::get_liquidity_shares(&account, |lp_currency_id| {
+				orml_rewards::Pallet::<Runtime>::shares_and_withdrawn_rewards(
+					&module_incentives::PoolId::Dex(lp_currency_id),
+					&account,
+				)
+				.0
+			})
+		}
+	}
+
+	impl module_cdp_engine_rpc_runtime_api::CdpEngineApi<
+		Block,
+		AccountId,
+		Balance,
+	> for Runtime {
+		fn get_position_health(
+			account: AccountId,
+			currency_id: CurrencyId,
+		) -> Option<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_position_health(account, currency_id)
+		}
+
+		fn get_account_positions_health(
+			account: AccountId,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_account_positions_health(account)
+		}
+
+		fn get_collateral_positions_health(
+			currency_id: CurrencyId,
+			start: Option<AccountId>,
+			limit: u32,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_collateral_positions_health(currency_id, start, limit)
+		}
+
+		fn get_positions_nearest_to_liquidation(
+			currency_id: CurrencyId,
+			start: u32,
+			limit: u32,
+		) -> Vec<module_cdp_engine_rpc_runtime_api::PositionHealth<AccountId, Balance>> {
+			CdpEngine::get_positions_health_nearest_to_liquidation(currency_id, start, limit)
+		}
+	}
+
+	impl module_homa_lite_rpc_runtime_api::HomaLiteApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+	> for Runtime {
+		fn get_redeem_queue_depth() -> module_homa_lite_rpc_runtime_api::RedeemQueueDepth<Balance> {
+			let (requests, liquid_amount) = HomaLite::redeem_queue_depth();
+			module_homa_lite_rpc_runtime_api::RedeemQueueDepth { requests, liquid_amount }
+		}
+
+		fn get_redeem_request_status(
+			who: AccountId,
+		) -> Option<module_homa_lite_rpc_runtime_api::RedeemRequestStatus<Balance, BlockNumber>> {
+			let (liquid_amount, additional_fee) = HomaLite::redeem_requests(&who)?;
+			let (position, liquid_amount_ahead) = HomaLite::redeem_request_position(&who)?;
+			Some(module_homa_lite_rpc_runtime_api::RedeemRequestStatus {
+				liquid_amount,
+				additional_fee,
+				position,
+				liquid_amount_ahead,
+				expected_wait: HomaLite::estimate_redeem_wait(liquid_amount_ahead.saturating_add(liquid_amount)),
+			})
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn call(
 			from: H160,
@@ -2439,6 +2662,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, module_homa_lite, HomaLiteBench::<Runtime>);
 
 			orml_list_benchmark!(list, extra, module_dex, benchmarking::dex);
+			orml_list_benchmark!(list, extra, module_dex_orders, benchmarking::dex_orders);
 			orml_list_benchmark!(list, extra, module_auction_manager, benchmarking::auction_manager);
 			orml_list_benchmark!(list, extra, module_cdp_engine, benchmarking::cdp_engine);
 			orml_list_benchmark!(list, extra, module_collator_selection, benchmarking::collator_selection);
@@ -2447,6 +2671,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, module_evm, benchmarking::evm);
 			orml_list_benchmark!(list, extra, module_honzon, benchmarking::honzon);
 			orml_list_benchmark!(list, extra, module_cdp_treasury, benchmarking::cdp_treasury);
+			orml_list_benchmark!(list, extra, module_savings, benchmarking::savings);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
 			orml_list_benchmark!(list, extra, module_transaction_payment, benchmarking::transaction_payment);
 			orml_list_benchmark!(list, extra, module_incentives, benchmarking::incentives);
@@ -2501,6 +2726,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, module_nft, NftBench::<Runtime>);
 			add_benchmark!(params, batches, module_homa_lite, HomaLiteBench::<Runtime>);
 			orml_add_benchmark!(params, batches, module_dex, benchmarking::dex);
+			orml_add_benchmark!(params, batches, module_dex_orders, benchmarking::dex_orders);
 			orml_add_benchmark!(params, batches, module_auction_manager, benchmarking::auction_manager);
 			orml_add_benchmark!(params, batches, module_cdp_engine, benchmarking::cdp_engine);
 			orml_add_benchmark!(params, batches, module_collator_selection, benchmarking::collator_selection);
@@ -2509,6 +2735,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, module_evm, benchmarking::evm);
 			orml_add_benchmark!(params, batches, module_honzon, benchmarking::honzon);
 			orml_add_benchmark!(params, batches, module_cdp_treasury, benchmarking::cdp_treasury);
+			orml_add_benchmark!(params, batches, module_savings, benchmarking::savings);
 			orml_add_benchmark!(params, batches, module_transaction_pause, benchmarking::transaction_pause);
 			orml_add_benchmark!(params, batches, module_transaction_payment, benchmarking::transaction_payment);
 			orml_add_benchmark!(params, batches, module_incentives, benchmarking::incentives);
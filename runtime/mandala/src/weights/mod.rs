@@ -25,6 +25,7 @@ pub mod module_cdp_treasury;
 pub mod module_collator_selection;
 pub mod module_currencies;
 pub mod module_dex;
+pub mod module_dex_orders;
 pub mod module_emergency_shutdown;
 pub mod module_evm;
 pub mod module_evm_accounts;
@@ -35,6 +36,7 @@ pub mod module_incentives;
 pub mod module_nft;
 pub mod module_nominees_election;
 pub mod module_prices;
+pub mod module_savings;
 pub mod module_session_manager;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
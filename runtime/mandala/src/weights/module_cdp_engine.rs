@@ -80,4 +80,45 @@ impl<T: frame_system::Config> module_cdp_engine::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	fn set_cross_collateral_liquidation_priority() -> Weight {
+		(23_468_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_stability_fee_controller() -> Weight {
+		(24_135_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn adjust_global_interest_rate() -> Weight {
+		(78_214_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn set_liquidation_strategies() -> Weight {
+		(25_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn register_liquidation_contract() -> Weight {
+		(29_874_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn deregister_liquidation_contract() -> Weight {
+		(28_940_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn set_keeper_reward_params() -> Weight {
+		(23_978_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn liquidate_by_keeper() -> Weight {
+		(481_736_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(33 as Weight))
+			.saturating_add(T::DbWeight::get().writes(17 as Weight))
+	}
+	fn set_debit_issuance_limits() -> Weight {
+		(30_615_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }